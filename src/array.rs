@@ -8,8 +8,9 @@
 /// * You can get a shared or mutable slice to the elements.
 ///
 /// You are generally note expected to need to implement this yourself. It is
-/// already implemented for all the major array lengths. Additional lengths can
-/// probably be added upon request.
+/// already implemented for all the major array lengths (0 through 33, and the
+/// powers of two from 64 to 4096). With the `const_generics` feature enabled
+/// (Rust 1.55 or later) it's implemented for arrays of every length instead.
 ///
 /// ## Safety Reminder
 ///
@@ -33,29 +34,54 @@ pub trait Array {
   /// A correct implementation will return a slice with a length equal to the
   /// `CAPACITY` value.
   fn slice_mut(&mut self) -> &mut [Self::Item];
+
+  /// Makes a new array with every element set to its default value.
+  ///
+  /// This is what lets an empty [`ArrayVec`] be made for any array type,
+  /// even the lengths that don't have a `Default` impl of their own.
+  fn default_array() -> Self;
 }
 
-macro_rules! impl_array_for_len {
-  ($($len:expr),+ $(,)?) => {
-    $(impl<T: Default> Array for [T; $len] {
-      type Item = T;
-      const CAPACITY: usize = $len;
-      #[inline(always)]
-      fn slice(&self) -> &[T] {
-        &*self
-      }
-      #[inline(always)]
-      fn slice_mut(&mut self) -> &mut [T] {
-        &mut *self
+/// A type with a default value that's usable in a const context.
+///
+/// * Requires the `rustc_1_61` feature
+///
+/// `Default::default` can't be called in a `const fn`, so this is what
+/// [`ArrayVec::from_array_prefix`] fills the spare capacity with. It's
+/// implemented for the primitive number types, `bool`, `char`, `()` and
+/// `Option`. If you implement it yourself, `DEFAULT` should be the same value
+/// that `Default::default` gives.
+#[cfg(feature = "rustc_1_61")]
+pub trait ConstDefault: Default {
+  /// The same value as `Default::default()`, but as a constant.
+  const DEFAULT: Self;
+}
+
+#[cfg(feature = "rustc_1_61")]
+macro_rules! impl_const_default {
+  ($($t:ty => $v:expr),* $(,)?) => {
+    $(
+      impl ConstDefault for $t {
+        const DEFAULT: Self = $v;
       }
-    })+
-  }
+    )*
+  };
+}
+
+#[cfg(feature = "rustc_1_61")]
+impl_const_default! {
+  u8 => 0, u16 => 0, u32 => 0, u64 => 0, u128 => 0, usize => 0,
+  i8 => 0, i16 => 0, i32 => 0, i64 => 0, i128 => 0, isize => 0,
+  f32 => 0.0, f64 => 0.0, bool => false, char => '\0', () => (),
 }
 
-impl_array_for_len! {
-  0, /* The oft-forgotten 0-length array! */
-  1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
-  17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
-  33, /* for luck */
-  64, 128, 256, 512, 1024, 2048, 4096,
+#[cfg(feature = "rustc_1_61")]
+impl<T> ConstDefault for Option<T> {
+  const DEFAULT: Self = None;
 }
+
+#[cfg(feature = "const_generics")]
+mod const_generic_impl;
+
+#[cfg(not(feature = "const_generics"))]
+mod generated_impl;
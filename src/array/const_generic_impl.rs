@@ -0,0 +1,18 @@
+use super::Array;
+
+impl<T: Default, const N: usize> Array for [T; N] {
+  type Item = T;
+  const CAPACITY: usize = N;
+  #[inline(always)]
+  fn slice(&self) -> &[T] {
+    self
+  }
+  #[inline(always)]
+  fn slice_mut(&mut self) -> &mut [T] {
+    self
+  }
+  #[inline]
+  fn default_array() -> Self {
+    [(); N].map(|()| T::default())
+  }
+}
@@ -0,0 +1,64 @@
+use super::Array;
+
+// Arrays past length 32 don't implement `Default`, so `default_array` writes
+// out one `T::default()` per element. The element lists are built up one `_`
+// token at a time for the small lengths, and by doubling for the big ones.
+
+macro_rules! default_for {
+  ($_t:tt) => {
+    T::default()
+  };
+}
+
+macro_rules! impl_array_for_len {
+  ($len:expr => [$($t:tt)*]) => {
+    impl<T: Default> Array for [T; $len] {
+      type Item = T;
+      const CAPACITY: usize = $len;
+      #[inline(always)]
+      fn slice(&self) -> &[T] {
+        &*self
+      }
+      #[inline(always)]
+      fn slice_mut(&mut self) -> &mut [T] {
+        &mut *self
+      }
+      #[inline]
+      fn default_array() -> Self {
+        [$(default_for!($t)),*]
+      }
+    }
+  };
+}
+
+macro_rules! impl_counting_up {
+  ([$($t:tt)*]) => {};
+  ([$($t:tt)*] $len:tt $($rest:tt)*) => {
+    impl_array_for_len!($len => [$($t)*]);
+    impl_counting_up!([$($t)* _] $($rest)*);
+  };
+}
+
+macro_rules! impl_doubling {
+  ([$($t:tt)*]) => {};
+  ([$($t:tt)*] $len:tt $($rest:tt)*) => {
+    impl_array_for_len!($len => [$($t)* $($t)*]);
+    impl_doubling!([$($t)* $($t)*] $($rest)*);
+  };
+}
+
+impl_counting_up! {
+  []
+  0 /* The oft-forgotten 0-length array! */
+  1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16
+  17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
+  33 /* for luck */
+}
+
+impl_doubling! {
+  [
+    _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _
+    _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _
+  ]
+  64 128 256 512 1024 2048 4096
+}
@@ -0,0 +1,363 @@
+use super::*;
+
+/// A priority queue stored in an array, as a binary max-heap.
+///
+/// * Fixed capacity (based on array size)
+/// * `push` and `pop` are `O(log n)`, and `peek` is `O(1)`.
+/// * Building a heap from an [`ArrayVec`] is `O(n)`.
+///
+/// Like `BinaryHeap`, `pop` gives the greatest element first. For a min-heap,
+/// wrap the elements in [`Reverse`](core::cmp::Reverse).
+///
+/// Because the elements are stored in an [`ArrayVec`], the element type has
+/// to be `Default`.
+///
+/// ```rust
+/// use core::cmp::Reverse;
+/// use tinyvec::*;
+///
+/// let mut heap: ArrayBinaryHeap<[u32; 8]> = ArrayBinaryHeap::new();
+/// heap.push(3);
+/// heap.push(7);
+/// heap.push(5);
+/// assert_eq!(heap.peek(), Some(&7));
+/// assert_eq!(heap.pop(), Some(7));
+///
+/// // A min-heap, such as for the next timer to fire.
+/// let mut timers: ArrayBinaryHeap<[Reverse<u64>; 8]> = ArrayBinaryHeap::new();
+/// timers.push(Reverse(300));
+/// timers.push(Reverse(100));
+/// timers.push(Reverse(200));
+/// assert_eq!(timers.pop(), Some(Reverse(100)));
+/// ```
+#[derive(Clone, Copy)]
+pub struct ArrayBinaryHeap<A: Array> {
+  vec: ArrayVec<A>,
+}
+
+impl<A: Array> Default for ArrayBinaryHeap<A> {
+  #[inline]
+  fn default() -> Self {
+    Self { vec: ArrayVec::default() }
+  }
+}
+
+impl<T: Ord, A: Array<Item = T>> ArrayBinaryHeap<A> {
+  /// The elements of the heap, in heap order (which isn't sorted).
+  #[inline(always)]
+  #[must_use]
+  pub fn as_slice(&self) -> &[T] {
+    self.vec.as_slice()
+  }
+
+  /// The capacity of the heap.
+  #[inline(always)]
+  #[must_use]
+  pub fn capacity(&self) -> usize {
+    self.vec.capacity()
+  }
+
+  /// Removes all elements from the heap.
+  #[inline(always)]
+  pub fn clear(&mut self) {
+    self.vec.clear()
+  }
+
+  /// Removes all elements from the heap, as an iterator in heap order.
+  ///
+  /// The heap is empty afterwards, even if the iterator isn't used up.
+  #[inline]
+  pub fn drain(&mut self) -> ArrayVecDrain<'_, A> {
+    self.vec.drain(..)
+  }
+
+  /// Turns the heap into a vec, sorted in ascending order.
+  ///
+  /// This is an in-place heap sort, so it's `O(n log n)`.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let heap: ArrayBinaryHeap<[i32; 8]> =
+  ///   [4, 1, 8, 2].iter().copied().collect();
+  /// assert_eq!(heap.into_sorted_vec().as_slice(), &[1, 2, 4, 8]);
+  /// ```
+  #[inline]
+  #[must_use]
+  pub fn into_sorted_vec(mut self) -> ArrayVec<A> {
+    let data = self.vec.as_mut_slice();
+    let mut end = data.len();
+    while end > 1 {
+      end -= 1;
+      data.swap(0, end);
+      sift_down(&mut data[..end], 0);
+    }
+    self.vec
+  }
+
+  /// Turns the heap into a vec, in heap order (which isn't sorted).
+  #[inline(always)]
+  #[must_use]
+  pub fn into_vec(self) -> ArrayVec<A> {
+    self.vec
+  }
+
+  /// If the heap is empty.
+  #[inline(always)]
+  #[must_use]
+  pub fn is_empty(&self) -> bool {
+    self.vec.is_empty()
+  }
+
+  /// Iterates over the elements, in heap order (which isn't sorted).
+  #[inline]
+  pub fn iter(&self) -> core::slice::Iter<'_, T> {
+    self.vec.iter()
+  }
+
+  /// The number of elements in the heap.
+  #[inline(always)]
+  #[must_use]
+  pub fn len(&self) -> usize {
+    self.vec.len()
+  }
+
+  /// Makes a new, empty heap.
+  #[inline]
+  #[must_use]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// The greatest element, if any.
+  #[inline]
+  #[must_use]
+  pub fn peek(&self) -> Option<&T> {
+    self.vec.first()
+  }
+
+  /// Unique access to the greatest element, if any.
+  ///
+  /// The heap is fixed up when the returned guard is dropped, so the element
+  /// can be changed freely.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut heap: ArrayBinaryHeap<[i32; 8]> =
+  ///   [4, 1, 8, 2].iter().copied().collect();
+  /// if let Some(mut top) = heap.peek_mut() {
+  ///   *top = 0;
+  /// }
+  /// assert_eq!(heap.peek(), Some(&4));
+  /// ```
+  #[inline]
+  pub fn peek_mut(&mut self) -> Option<ArrayBinaryHeapPeekMut<'_, A>> {
+    if self.is_empty() {
+      None
+    } else {
+      Some(ArrayBinaryHeapPeekMut { heap: self })
+    }
+  }
+
+  /// Removes the greatest element, if any.
+  #[inline]
+  pub fn pop(&mut self) -> Option<T> {
+    let len = self.vec.len();
+    if len == 0 {
+      return None;
+    }
+    self.vec.swap(0, len - 1);
+    let out = self.vec.pop();
+    sift_down(self.vec.as_mut_slice(), 0);
+    out
+  }
+
+  /// Pushes an element into the heap.
+  ///
+  /// ## Panics
+  /// * If the heap is already full.
+  #[inline]
+  pub fn push(&mut self, val: T) {
+    if self.try_push(val).is_err() {
+      panic!(
+        "ArrayBinaryHeap::push> capacity overflow! Capacity: {}",
+        self.capacity()
+      )
+    }
+  }
+
+  /// Pushes an element into the heap, if there's room.
+  ///
+  /// ## Failure
+  /// * If the heap is full you get the element back and the heap is
+  ///   unchanged.
+  #[inline]
+  pub fn try_push(&mut self, val: T) -> Result<(), T> {
+    self.vec.try_push(val)?;
+    let last = self.vec.len() - 1;
+    sift_up(self.vec.as_mut_slice(), last);
+    Ok(())
+  }
+}
+
+/// Moves the element at `index` up towards the root until its parent is at
+/// least as great.
+#[inline]
+pub(crate) fn sift_up<T: Ord>(data: &mut [T], mut index: usize) {
+  while index > 0 {
+    let parent = (index - 1) / 2;
+    if data[index] <= data[parent] {
+      break;
+    }
+    data.swap(index, parent);
+    index = parent;
+  }
+}
+
+/// Moves the element at `index` down until both of its children are no
+/// greater.
+#[inline]
+pub(crate) fn sift_down<T: Ord>(data: &mut [T], mut index: usize) {
+  loop {
+    let left = 2 * index + 1;
+    if left >= data.len() {
+      return;
+    }
+    let right = left + 1;
+    let child =
+      if right < data.len() && data[right] > data[left] { right } else { left };
+    if data[index] >= data[child] {
+      return;
+    }
+    data.swap(index, child);
+    index = child;
+  }
+}
+
+/// Unique access to the greatest element of an [`ArrayBinaryHeap`].
+///
+/// See [`ArrayBinaryHeap::peek_mut`]
+pub struct ArrayBinaryHeapPeekMut<'a, A: Array>
+where
+  A::Item: Ord,
+{
+  heap: &'a mut ArrayBinaryHeap<A>,
+}
+
+impl<'a, A: Array> ArrayBinaryHeapPeekMut<'a, A>
+where
+  A::Item: Ord,
+{
+  /// Removes the peeked element from the heap and returns it.
+  #[inline]
+  #[allow(clippy::must_use_candidate)]
+  pub fn pop(this: Self) -> A::Item {
+    match this.heap.pop() {
+      Some(val) => val,
+      None => unreachable!("ArrayBinaryHeapPeekMut::pop> empty heap"),
+    }
+  }
+}
+
+impl<'a, A: Array> Deref for ArrayBinaryHeapPeekMut<'a, A>
+where
+  A::Item: Ord,
+{
+  type Target = A::Item;
+  #[inline(always)]
+  fn deref(&self) -> &A::Item {
+    &self.heap.vec[0]
+  }
+}
+
+impl<'a, A: Array> DerefMut for ArrayBinaryHeapPeekMut<'a, A>
+where
+  A::Item: Ord,
+{
+  #[inline(always)]
+  fn deref_mut(&mut self) -> &mut A::Item {
+    &mut self.heap.vec[0]
+  }
+}
+
+impl<'a, A: Array> Drop for ArrayBinaryHeapPeekMut<'a, A>
+where
+  A::Item: Ord,
+{
+  #[inline]
+  fn drop(&mut self) {
+    sift_down(self.heap.vec.as_mut_slice(), 0);
+  }
+}
+
+impl<T: Ord, A: Array<Item = T>> Extend<T> for ArrayBinaryHeap<A> {
+  #[inline]
+  fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+    for t in iter {
+      self.push(t);
+    }
+  }
+}
+
+impl<T: Ord, A: Array<Item = T>> From<ArrayVec<A>> for ArrayBinaryHeap<A> {
+  /// Rearranges the vec into a heap, in `O(n)`.
+  #[inline]
+  fn from(mut vec: ArrayVec<A>) -> Self {
+    let data = vec.as_mut_slice();
+    let mut index = data.len() / 2;
+    while index > 0 {
+      index -= 1;
+      sift_down(data, index);
+    }
+    Self { vec }
+  }
+}
+
+impl<T: Ord, A: Array<Item = T>> From<ArrayBinaryHeap<A>> for ArrayVec<A> {
+  /// The elements in heap order, which isn't sorted.
+  #[inline(always)]
+  fn from(heap: ArrayBinaryHeap<A>) -> Self {
+    heap.into_vec()
+  }
+}
+
+impl<T: Ord, A: Array<Item = T>> FromIterator<T> for ArrayBinaryHeap<A> {
+  #[inline]
+  fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+    let mut heap = Self::default();
+    heap.extend(iter);
+    heap
+  }
+}
+
+impl<T: Ord, A: Array<Item = T>> IntoIterator for ArrayBinaryHeap<A> {
+  type Item = T;
+  type IntoIter = ArrayVecIterator<A>;
+  /// The elements in heap order, which isn't sorted.
+  #[inline(always)]
+  fn into_iter(self) -> Self::IntoIter {
+    self.vec.into_iter()
+  }
+}
+
+impl<'a, T: 'a + Ord, A: Array<Item = T>> IntoIterator
+  for &'a ArrayBinaryHeap<A>
+{
+  type Item = &'a T;
+  type IntoIter = core::slice::Iter<'a, T>;
+  #[inline(always)]
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+
+impl<T: Ord, A: Array<Item = T>> Debug for ArrayBinaryHeap<A>
+where
+  T: Debug,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    f.debug_list().entries(self.iter()).finish()
+  }
+}
@@ -0,0 +1,973 @@
+use super::*;
+
+/// An array-backed double-ended queue (a ring buffer).
+///
+/// * Fixed capacity (based on array size)
+/// * Variable length
+/// * `O(1)` push and pop at both ends.
+/// * All of the array memory is always initialized.
+///
+/// Because the live elements can wrap around the end of the backing array,
+/// the contents are exposed as a pair of slices (see
+/// [`as_slices`](ArrayDeque::as_slices)) rather than by `Deref`. Call
+/// [`make_contiguous`](ArrayDeque::make_contiguous) if you need a single
+/// slice.
+///
+/// ```rust
+/// use tinyvec::*;
+///
+/// let mut q: ArrayDeque<[i32; 4]> = ArrayDeque::new();
+/// q.push_back(2);
+/// q.push_back(3);
+/// q.push_front(1);
+/// assert_eq!(q.pop_front(), Some(1));
+/// assert_eq!(q.pop_back(), Some(3));
+/// assert_eq!(q.len(), 1);
+/// ```
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ArrayDeque<A: Array> {
+  head: usize,
+  len: usize,
+  data: A,
+}
+
+impl<A: Array> Default for ArrayDeque<A> {
+  #[inline]
+  fn default() -> Self {
+    Self { head: 0, len: 0, data: A::default_array() }
+  }
+}
+
+impl<A: Array> ArrayDeque<A> {
+  /// Converts a logical index into an index of the backing array.
+  #[inline(always)]
+  fn physical(&self, index: usize) -> usize {
+    let i = self.head + index;
+    if i >= A::CAPACITY {
+      i - A::CAPACITY
+    } else {
+      i
+    }
+  }
+
+  /// Move all values from `other` onto the back of this deque.
+  ///
+  /// ## Panics
+  /// * If the combined length would overflow the capacity.
+  #[inline]
+  pub fn append(&mut self, other: &mut Self) {
+    for item in other.drain(..) {
+      self.push_back(item)
+    }
+  }
+
+  /// Gives the contents of the deque as two slices, front part first.
+  ///
+  /// The second slice is empty unless the contents wrap around the end of
+  /// the backing array.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut q: ArrayDeque<[i32; 4]> = ArrayDeque::new();
+  /// q.push_back(2);
+  /// q.push_back(3);
+  /// q.push_front(1);
+  /// assert_eq!(q.as_slices(), (&[1][..], &[2, 3][..]));
+  /// ```
+  #[inline]
+  #[must_use]
+  pub fn as_slices(&self) -> (&[A::Item], &[A::Item]) {
+    let slice = self.data.slice();
+    if self.head + self.len <= A::CAPACITY {
+      (&slice[self.head..self.head + self.len], &[])
+    } else {
+      let wrap = self.head + self.len - A::CAPACITY;
+      let (lo, hi) = slice.split_at(self.head);
+      (hi, &lo[..wrap])
+    }
+  }
+
+  /// Gives the contents of the deque as two mutable slices, front part
+  /// first.
+  ///
+  /// The second slice is empty unless the contents wrap around the end of
+  /// the backing array.
+  #[inline]
+  #[must_use]
+  pub fn as_mut_slices(&mut self) -> (&mut [A::Item], &mut [A::Item]) {
+    let head = self.head;
+    let len = self.len;
+    let slice = self.data.slice_mut();
+    if head + len <= A::CAPACITY {
+      (&mut slice[head..head + len], &mut [])
+    } else {
+      let wrap = head + len - A::CAPACITY;
+      let (lo, hi) = slice.split_at_mut(head);
+      (hi, &mut lo[..wrap])
+    }
+  }
+
+  /// Shared reference to the last element, if any.
+  #[inline]
+  #[must_use]
+  pub fn back(&self) -> Option<&A::Item> {
+    if self.len > 0 {
+      self.get(self.len - 1)
+    } else {
+      None
+    }
+  }
+
+  /// Unique reference to the last element, if any.
+  #[inline]
+  #[must_use]
+  pub fn back_mut(&mut self) -> Option<&mut A::Item> {
+    if self.len > 0 {
+      let i = self.len - 1;
+      self.get_mut(i)
+    } else {
+      None
+    }
+  }
+
+  /// The capacity of the `ArrayDeque`.
+  ///
+  /// This is fixed based on the array type.
+  #[inline(always)]
+  #[must_use]
+  pub fn capacity(&self) -> usize {
+    A::CAPACITY
+  }
+
+  /// Removes all elements from the deque.
+  #[inline(always)]
+  pub fn clear(&mut self) {
+    self.truncate(0);
+    self.head = 0;
+  }
+
+  /// If the deque contains an element equal to the given value.
+  #[inline]
+  #[must_use]
+  pub fn contains(&self, x: &A::Item) -> bool
+  where
+    A::Item: PartialEq,
+  {
+    let (a, b) = self.as_slices();
+    a.contains(x) || b.contains(x)
+  }
+
+  /// Creates a draining iterator that removes the specified range in the
+  /// deque and yields the removed items.
+  ///
+  /// When the iterator is dropped the gap is closed by moving whichever side
+  /// of the range is shorter.
+  ///
+  /// ## Panics
+  /// * If the start is greater than the end
+  /// * If the end is past the edge of the deque.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut q: ArrayDeque<[i32; 4]> = [1, 2, 3, 4].into();
+  /// let drained: ArrayDeque<[i32; 4]> = q.drain(1..3).collect();
+  /// assert_eq!(drained.as_slices(), (&[2, 3][..], &[][..]));
+  /// assert_eq!(q.iter().copied().collect::<Vec<_>>(), vec![1, 4]);
+  /// ```
+  #[inline]
+  pub fn drain<R: RangeBounds<usize>>(
+    &mut self,
+    range: R,
+  ) -> ArrayDequeDrain<'_, A> {
+    use core::ops::Bound;
+    let start = match range.start_bound() {
+      Bound::Included(x) => *x,
+      Bound::Excluded(x) => x + 1,
+      Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+      Bound::Included(x) => x + 1,
+      Bound::Excluded(x) => *x,
+      Bound::Unbounded => self.len,
+    };
+    assert!(
+      start <= end,
+      "ArrayDeque::drain> Illegal range, {} to {}",
+      start,
+      end
+    );
+    assert!(
+      end <= self.len,
+      "ArrayDeque::drain> Range ends at {} but length is only {}!",
+      end,
+      self.len
+    );
+    ArrayDequeDrain { parent: self, start, front: start, back: end, end }
+  }
+
+  /// Shared reference to the first element, if any.
+  #[inline]
+  #[must_use]
+  pub fn front(&self) -> Option<&A::Item> {
+    self.get(0)
+  }
+
+  /// Unique reference to the first element, if any.
+  #[inline]
+  #[must_use]
+  pub fn front_mut(&mut self) -> Option<&mut A::Item> {
+    self.get_mut(0)
+  }
+
+  /// Shared reference to the element at the given logical index, if any.
+  #[inline]
+  #[must_use]
+  pub fn get(&self, index: usize) -> Option<&A::Item> {
+    if index < self.len {
+      Some(&self.data.slice()[self.physical(index)])
+    } else {
+      None
+    }
+  }
+
+  /// Unique reference to the element at the given logical index, if any.
+  #[inline]
+  #[must_use]
+  pub fn get_mut(&mut self, index: usize) -> Option<&mut A::Item> {
+    if index < self.len {
+      let i = self.physical(index);
+      Some(&mut self.data.slice_mut()[i])
+    } else {
+      None
+    }
+  }
+
+  /// If the deque is empty.
+  #[inline(always)]
+  #[must_use]
+  pub fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  /// If the deque is at capacity.
+  #[inline(always)]
+  #[must_use]
+  pub fn is_full(&self) -> bool {
+    self.len == A::CAPACITY
+  }
+
+  /// Iterates the deque by shared reference, front to back.
+  #[inline]
+  #[must_use]
+  pub fn iter(&self) -> ArrayDequeIter<'_, A::Item> {
+    let (a, b) = self.as_slices();
+    ArrayDequeIter { front: a.iter(), back: b.iter() }
+  }
+
+  /// Iterates the deque by unique reference, front to back.
+  #[inline]
+  #[must_use]
+  pub fn iter_mut(&mut self) -> ArrayDequeIterMut<'_, A::Item> {
+    let (a, b) = self.as_mut_slices();
+    ArrayDequeIterMut { front: a.iter_mut(), back: b.iter_mut() }
+  }
+
+  /// The length of the deque (in elements).
+  #[inline(always)]
+  #[must_use]
+  pub fn len(&self) -> usize {
+    self.len
+  }
+
+  /// Rearranges the backing array so that the contents are one slice, and
+  /// returns that slice.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut q: ArrayDeque<[i32; 4]> = ArrayDeque::new();
+  /// q.push_back(2);
+  /// q.push_front(1);
+  /// assert_eq!(q.make_contiguous(), &[1, 2]);
+  /// assert_eq!(q.as_slices(), (&[1, 2][..], &[][..]));
+  /// ```
+  #[inline]
+  pub fn make_contiguous(&mut self) -> &mut [A::Item] {
+    if self.head + self.len > A::CAPACITY {
+      let head = self.head;
+      self.data.slice_mut().rotate_left(head);
+      self.head = 0;
+    }
+    let head = self.head;
+    let len = self.len;
+    &mut self.data.slice_mut()[head..head + len]
+  }
+
+  /// Makes a new, empty deque.
+  #[inline(always)]
+  #[must_use]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Remove and return the last element of the deque, if there is one.
+  ///
+  /// ## Failure
+  /// * If the deque is empty you get `None`.
+  #[inline]
+  pub fn pop_back(&mut self) -> Option<A::Item> {
+    if self.len > 0 {
+      self.len -= 1;
+      let i = self.physical(self.len);
+      // `mem::take` needs Rust 1.40.
+      #[allow(clippy::mem_replace_with_default)]
+      let out = replace(&mut self.data.slice_mut()[i], A::Item::default());
+      Some(out)
+    } else {
+      None
+    }
+  }
+
+  /// Remove and return the first element of the deque, if there is one.
+  ///
+  /// ## Failure
+  /// * If the deque is empty you get `None`.
+  #[inline]
+  pub fn pop_front(&mut self) -> Option<A::Item> {
+    if self.len > 0 {
+      let i = self.head;
+      self.head = self.physical(1);
+      self.len -= 1;
+      // `mem::take` needs Rust 1.40.
+      #[allow(clippy::mem_replace_with_default)]
+      let out = replace(&mut self.data.slice_mut()[i], A::Item::default());
+      Some(out)
+    } else {
+      None
+    }
+  }
+
+  /// Place an element onto the back of the deque.
+  ///
+  /// See also, [`try_push_back`](ArrayDeque::try_push_back)
+  /// ## Panics
+  /// * If the length of the deque would overflow the capacity.
+  #[inline(always)]
+  pub fn push_back(&mut self, val: A::Item) {
+    if self.try_push_back(val).is_err() {
+      panic!("ArrayDeque: overflow!")
+    }
+  }
+
+  /// Place an element onto the front of the deque.
+  ///
+  /// See also, [`try_push_front`](ArrayDeque::try_push_front)
+  /// ## Panics
+  /// * If the length of the deque would overflow the capacity.
+  #[inline(always)]
+  pub fn push_front(&mut self, val: A::Item) {
+    if self.try_push_front(val).is_err() {
+      panic!("ArrayDeque: overflow!")
+    }
+  }
+
+  /// Rotates the deque `n` places to the left.
+  ///
+  /// The element at index `n` becomes the first element.
+  ///
+  /// ## Panics
+  /// * If `n` > `len`
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut q: ArrayDeque<[i32; 8]> = (0..5).collect();
+  /// q.rotate_left(2);
+  /// assert_eq!(q.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4, 0, 1]);
+  /// ```
+  #[inline]
+  pub fn rotate_left(&mut self, n: usize) {
+    assert!(
+      n <= self.len,
+      "ArrayDeque::rotate_left> n {} exceeds length of {}",
+      n,
+      self.len
+    );
+    if self.len == A::CAPACITY {
+      self.head = self.physical(n % A::CAPACITY.max(1));
+    } else if n <= self.len / 2 {
+      for _ in 0..n {
+        let x = self.pop_front().unwrap();
+        self.push_back(x);
+      }
+    } else {
+      for _ in 0..(self.len - n) {
+        let x = self.pop_back().unwrap();
+        self.push_front(x);
+      }
+    }
+  }
+
+  /// Rotates the deque `n` places to the right.
+  ///
+  /// The first element ends up at index `n`.
+  ///
+  /// ## Panics
+  /// * If `n` > `len`
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut q: ArrayDeque<[i32; 8]> = (0..5).collect();
+  /// q.rotate_right(2);
+  /// assert_eq!(q.iter().copied().collect::<Vec<_>>(), vec![3, 4, 0, 1, 2]);
+  /// ```
+  #[inline]
+  pub fn rotate_right(&mut self, n: usize) {
+    assert!(
+      n <= self.len,
+      "ArrayDeque::rotate_right> n {} exceeds length of {}",
+      n,
+      self.len
+    );
+    self.rotate_left(self.len - n)
+  }
+
+  /// Swaps the elements at the two logical indexes given.
+  ///
+  /// ## Panics
+  /// * If either index is out of bounds.
+  #[inline]
+  pub fn swap(&mut self, a: usize, b: usize) {
+    assert!(
+      a < self.len && b < self.len,
+      "ArrayDeque::swap> indexes {} and {} must be less than length {}",
+      a,
+      b,
+      self.len
+    );
+    let pa = self.physical(a);
+    let pb = self.physical(b);
+    self.data.slice_mut().swap(pa, pb)
+  }
+
+  /// Reduces the deque's length to the given value, dropping from the back.
+  ///
+  /// If the deque is already shorter than the input, nothing happens.
+  #[inline]
+  pub fn truncate(&mut self, new_len: usize) {
+    if needs_drop::<A::Item>() || cfg!(feature = "wipe_vacated") {
+      while self.len > new_len {
+        self.pop_back();
+      }
+    } else {
+      self.len = self.len.min(new_len);
+    }
+  }
+
+  /// Pushes an item onto the back if there's room.
+  ///
+  /// ## Failure
+  ///
+  /// If there's no more capacity the deque is unchanged, and you get the item
+  /// back in the `Err`.
+  #[inline]
+  pub fn try_push_back(&mut self, val: A::Item) -> Result<(), A::Item> {
+    if self.len < A::CAPACITY {
+      let i = self.physical(self.len);
+      self.data.slice_mut()[i] = val;
+      self.len += 1;
+      Ok(())
+    } else {
+      Err(val)
+    }
+  }
+
+  /// Pushes an item onto the front if there's room.
+  ///
+  /// ## Failure
+  ///
+  /// If there's no more capacity the deque is unchanged, and you get the item
+  /// back in the `Err`.
+  #[inline]
+  pub fn try_push_front(&mut self, val: A::Item) -> Result<(), A::Item> {
+    if self.len < A::CAPACITY {
+      self.head = if self.head == 0 { A::CAPACITY - 1 } else { self.head - 1 };
+      let i = self.head;
+      self.data.slice_mut()[i] = val;
+      self.len += 1;
+      Ok(())
+    } else {
+      Err(val)
+    }
+  }
+}
+
+/// Draining iterator for `ArrayDeque`
+///
+/// See [`ArrayDeque::drain`](ArrayDeque::<A>::drain)
+pub struct ArrayDequeDrain<'p, A: Array> {
+  parent: &'p mut ArrayDeque<A>,
+  start: usize,
+  front: usize,
+  back: usize,
+  end: usize,
+}
+impl<'p, A: Array> Iterator for ArrayDequeDrain<'p, A> {
+  type Item = A::Item;
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.front < self.back {
+      let i = self.parent.physical(self.front);
+      self.front += 1;
+      // `mem::take` needs Rust 1.40.
+      #[allow(clippy::mem_replace_with_default)]
+      let out =
+        replace(&mut self.parent.data.slice_mut()[i], A::Item::default());
+      Some(out)
+    } else {
+      None
+    }
+  }
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let s = self.back - self.front;
+    (s, Some(s))
+  }
+}
+impl<'p, A: Array> DoubleEndedIterator for ArrayDequeDrain<'p, A> {
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    if self.front < self.back {
+      self.back -= 1;
+      let i = self.parent.physical(self.back);
+      // `mem::take` needs Rust 1.40.
+      #[allow(clippy::mem_replace_with_default)]
+      let out =
+        replace(&mut self.parent.data.slice_mut()[i], A::Item::default());
+      Some(out)
+    } else {
+      None
+    }
+  }
+}
+impl<'p, A: Array> ExactSizeIterator for ArrayDequeDrain<'p, A> {}
+impl<'p, A: Array> FusedIterator for ArrayDequeDrain<'p, A> {}
+impl<'p, A: Array> Drop for ArrayDequeDrain<'p, A> {
+  #[inline]
+  fn drop(&mut self) {
+    // drop anything the user didn't take
+    for _ in self.by_ref() {}
+    let count = self.end - self.start;
+    if count == 0 {
+      return;
+    }
+    let parent = &mut *self.parent;
+    let tail = parent.len - self.end;
+    if self.start < tail {
+      // shift the front part towards the back
+      for i in (0..self.start).rev() {
+        let a = parent.physical(i);
+        let b = parent.physical(i + count);
+        parent.data.slice_mut().swap(a, b);
+      }
+      parent.head = parent.physical(count);
+    } else {
+      // shift the back part towards the front
+      for i in self.end..parent.len {
+        let a = parent.physical(i);
+        let b = parent.physical(i - count);
+        parent.data.slice_mut().swap(a, b);
+      }
+    }
+    parent.len -= count;
+  }
+}
+
+/// Iterator for consuming an `ArrayDeque` and returning owned elements.
+pub struct ArrayDequeIterator<A: Array> {
+  deque: ArrayDeque<A>,
+}
+impl<A: Array> Iterator for ArrayDequeIterator<A> {
+  type Item = A::Item;
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    self.deque.pop_front()
+  }
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (self.deque.len, Some(self.deque.len))
+  }
+  #[inline(always)]
+  fn count(self) -> usize {
+    self.deque.len
+  }
+  #[inline]
+  fn last(mut self) -> Option<Self::Item> {
+    self.deque.pop_back()
+  }
+}
+impl<A: Array> DoubleEndedIterator for ArrayDequeIterator<A> {
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    self.deque.pop_back()
+  }
+}
+impl<A: Array> ExactSizeIterator for ArrayDequeIterator<A> {}
+impl<A: Array> FusedIterator for ArrayDequeIterator<A> {}
+
+/// Iterator over shared references to the elements of an `ArrayDeque`.
+pub struct ArrayDequeIter<'a, T> {
+  front: core::slice::Iter<'a, T>,
+  back: core::slice::Iter<'a, T>,
+}
+impl<'a, T> Iterator for ArrayDequeIter<'a, T> {
+  type Item = &'a T;
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    self.front.next().or_else(|| self.back.next())
+  }
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let s = self.front.len() + self.back.len();
+    (s, Some(s))
+  }
+}
+impl<'a, T> DoubleEndedIterator for ArrayDequeIter<'a, T> {
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    self.back.next_back().or_else(|| self.front.next_back())
+  }
+}
+impl<'a, T> ExactSizeIterator for ArrayDequeIter<'a, T> {}
+impl<'a, T> FusedIterator for ArrayDequeIter<'a, T> {}
+impl<'a, T> Clone for ArrayDequeIter<'a, T> {
+  #[inline]
+  fn clone(&self) -> Self {
+    Self { front: self.front.clone(), back: self.back.clone() }
+  }
+}
+
+/// Iterator over unique references to the elements of an `ArrayDeque`.
+pub struct ArrayDequeIterMut<'a, T> {
+  front: core::slice::IterMut<'a, T>,
+  back: core::slice::IterMut<'a, T>,
+}
+impl<'a, T> Iterator for ArrayDequeIterMut<'a, T> {
+  type Item = &'a mut T;
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    match self.front.next() {
+      Some(x) => Some(x),
+      None => self.back.next(),
+    }
+  }
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let s = self.front.len() + self.back.len();
+    (s, Some(s))
+  }
+}
+impl<'a, T> DoubleEndedIterator for ArrayDequeIterMut<'a, T> {
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    match self.back.next_back() {
+      Some(x) => Some(x),
+      None => self.front.next_back(),
+    }
+  }
+}
+impl<'a, T> ExactSizeIterator for ArrayDequeIterMut<'a, T> {}
+impl<'a, T> FusedIterator for ArrayDequeIterMut<'a, T> {}
+
+impl<A: Array> Index<usize> for ArrayDeque<A> {
+  type Output = A::Item;
+  #[inline]
+  fn index(&self, index: usize) -> &Self::Output {
+    match self.get(index) {
+      Some(x) => x,
+      None => panic!(
+        "ArrayDeque::index> index {} is out of bounds {}",
+        index, self.len
+      ),
+    }
+  }
+}
+
+impl<A: Array> IndexMut<usize> for ArrayDeque<A> {
+  #[inline]
+  fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+    let len = self.len;
+    match self.get_mut(index) {
+      Some(x) => x,
+      None => {
+        panic!(
+          "ArrayDeque::index_mut> index {} is out of bounds {}",
+          index, len
+        )
+      }
+    }
+  }
+}
+
+impl<A: Array> Extend<A::Item> for ArrayDeque<A> {
+  #[inline]
+  fn extend<T: IntoIterator<Item = A::Item>>(&mut self, iter: T) {
+    for t in iter {
+      self.push_back(t)
+    }
+  }
+}
+
+impl<A: Array> From<A> for ArrayDeque<A> {
+  #[inline(always)]
+  /// The output has a length equal to the full array.
+  fn from(data: A) -> Self {
+    Self { head: 0, len: data.slice().len(), data }
+  }
+}
+
+impl<A: Array> From<ArrayVec<A>> for ArrayDeque<A> {
+  #[inline]
+  fn from(av: ArrayVec<A>) -> Self {
+    av.into_iter().collect()
+  }
+}
+
+impl<A: Array> FromIterator<A::Item> for ArrayDeque<A> {
+  #[inline]
+  fn from_iter<T: IntoIterator<Item = A::Item>>(iter: T) -> Self {
+    let mut q = Self::default();
+    for i in iter {
+      q.push_back(i)
+    }
+    q
+  }
+}
+
+impl<A: Array> IntoIterator for ArrayDeque<A> {
+  type Item = A::Item;
+  type IntoIter = ArrayDequeIterator<A>;
+  #[inline(always)]
+  fn into_iter(self) -> Self::IntoIter {
+    ArrayDequeIterator { deque: self }
+  }
+}
+
+impl<'a, A: Array> IntoIterator for &'a ArrayDeque<A> {
+  type Item = &'a A::Item;
+  type IntoIter = ArrayDequeIter<'a, A::Item>;
+  #[inline(always)]
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+
+impl<'a, A: Array> IntoIterator for &'a mut ArrayDeque<A> {
+  type Item = &'a mut A::Item;
+  type IntoIter = ArrayDequeIterMut<'a, A::Item>;
+  #[inline(always)]
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter_mut()
+  }
+}
+
+impl<A: Array> PartialEq for ArrayDeque<A>
+where
+  A::Item: PartialEq,
+{
+  #[inline]
+  fn eq(&self, other: &Self) -> bool {
+    self.len == other.len && self.iter().eq(other.iter())
+  }
+}
+impl<A: Array> Eq for ArrayDeque<A> where A::Item: Eq {}
+
+impl<A: Array> PartialOrd for ArrayDeque<A>
+where
+  A::Item: PartialOrd,
+{
+  #[inline]
+  fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+    self.iter().partial_cmp(other.iter())
+  }
+}
+impl<A: Array> Ord for ArrayDeque<A>
+where
+  A::Item: Ord,
+{
+  #[inline]
+  fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+    self.iter().cmp(other.iter())
+  }
+}
+
+impl<A: Array> PartialEq<&[A::Item]> for ArrayDeque<A>
+where
+  A::Item: PartialEq,
+{
+  #[inline]
+  fn eq(&self, other: &&[A::Item]) -> bool {
+    self.len == other.len() && self.iter().eq(other.iter())
+  }
+}
+
+// //
+// Formatting impls
+// //
+
+impl<A: Array> Binary for ArrayDeque<A>
+where
+  A::Item: Binary,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      Binary::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<A: Array> Debug for ArrayDeque<A>
+where
+  A::Item: Debug,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      Debug::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<A: Array> Display for ArrayDeque<A>
+where
+  A::Item: Display,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      Display::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<A: Array> LowerExp for ArrayDeque<A>
+where
+  A::Item: LowerExp,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      LowerExp::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<A: Array> LowerHex for ArrayDeque<A>
+where
+  A::Item: LowerHex,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      LowerHex::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<A: Array> Octal for ArrayDeque<A>
+where
+  A::Item: Octal,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      Octal::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<A: Array> Pointer for ArrayDeque<A>
+where
+  A::Item: Pointer,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      Pointer::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<A: Array> UpperExp for ArrayDeque<A>
+where
+  A::Item: UpperExp,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      UpperExp::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<A: Array> UpperHex for ArrayDeque<A>
+where
+  A::Item: UpperHex,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      UpperHex::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
@@ -0,0 +1,906 @@
+use super::*;
+
+use core::hash::{BuildHasher, BuildHasherDefault, Hash, Hasher};
+
+/// The built-in hasher for [`ArrayHashMap`]: 64-bit FNV-1a.
+///
+/// It's small, needs no allocator or randomness, and is quick for the short
+/// keys that lookup tables usually have. It's **not** resistant to hash
+/// flooding, so if an attacker picks the keys you should plug in a keyed
+/// hasher through the map's `BuildHasher` parameter instead.
+#[derive(Debug, Clone, Copy)]
+pub struct SimpleHasher(u64);
+
+impl Default for SimpleHasher {
+  #[inline]
+  fn default() -> Self {
+    SimpleHasher(0xcbf2_9ce4_8422_2325)
+  }
+}
+
+impl Hasher for SimpleHasher {
+  #[inline]
+  fn finish(&self) -> u64 {
+    self.0
+  }
+  #[inline]
+  fn write(&mut self, bytes: &[u8]) {
+    for byte in bytes {
+      self.0 ^= u64::from(*byte);
+      self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+  }
+}
+
+/// The default `BuildHasher` of an [`ArrayHashMap`], which makes
+/// [`SimpleHasher`]s.
+pub type BuildSimpleHasher = BuildHasherDefault<SimpleHasher>;
+
+/// One slot of the array backing an [`ArrayHashMap`].
+///
+/// The array type of an `ArrayHashMap` is an array of these, such as
+/// `[HashSlot<u32, &str>; 256]`. The key and value types don't need to be
+/// `Default`, since the default slot is just `Empty`.
+#[derive(Debug, Clone, Copy)]
+pub enum HashSlot<K, V> {
+  /// A slot that's never been used (since the last `clear`).
+  Empty,
+  /// A slot that used to hold a pair. Lookups keep probing past it.
+  Tombstone,
+  /// A slot holding a key and its value.
+  Full(K, V),
+}
+
+impl<K, V> Default for HashSlot<K, V> {
+  #[inline(always)]
+  fn default() -> Self {
+    HashSlot::Empty
+  }
+}
+
+/// A hash map stored in an array, using open addressing.
+///
+/// * Fixed capacity (based on array size)
+/// * Lookups, inserts and removals are `O(1)` on average: the key's hash
+///   picks a slot and then the slots are probed in order (linear probing)
+///   until the key or an empty slot turns up.
+/// * Removals leave a [`Tombstone`](HashSlot::Tombstone) behind, so the
+///   probing for other keys isn't cut short. A tombstone right before an empty
+///   slot isn't needed, so those are cleaned up at once, and inserts reuse
+///   tombstones.
+/// * Iteration is in slot order, which isn't meaningful.
+///
+/// The array type is an array of [`HashSlot`]s, so neither the key nor the
+/// value have to be `Default`. The hashing is done by `S`, a
+/// [`BuildHasher`](core::hash::BuildHasher), which defaults to the built-in
+/// [`SimpleHasher`].
+///
+/// The map can be filled all the way up, but lookups of missing keys get
+/// slower as it does. For lookup tables, pick a capacity with some room to
+/// spare (a load of 3/4 or less works well).
+///
+/// ```rust
+/// use tinyvec::*;
+///
+/// let mut m: ArrayHashMap<[HashSlot<&str, u32>; 64]> = ArrayHashMap::new();
+/// m.insert("one", 1);
+/// m.insert("two", 2);
+/// assert_eq!(m.get("two"), Some(&2));
+/// assert_eq!(m.remove("one"), Some(1));
+/// assert_eq!(m.len(), 1);
+/// ```
+#[derive(Clone, Copy)]
+pub struct ArrayHashMap<A: Array, S = BuildSimpleHasher> {
+  slots: A,
+  len: usize,
+  hash_builder: S,
+}
+
+impl<A: Array, S: Default> Default for ArrayHashMap<A, S> {
+  #[inline]
+  fn default() -> Self {
+    Self::with_hasher(S::default())
+  }
+}
+
+impl<A: Array, S> ArrayHashMap<A, S> {
+  /// Makes a new, empty map which will use the given hash builder.
+  #[inline]
+  #[must_use]
+  pub fn with_hasher(hash_builder: S) -> Self {
+    Self { slots: A::default_array(), len: 0, hash_builder }
+  }
+}
+
+impl<K, V, A: Array<Item = HashSlot<K, V>>> ArrayHashMap<A> {
+  /// Makes a new, empty map with the built-in hasher.
+  #[inline]
+  #[must_use]
+  pub fn new() -> Self {
+    Self::default()
+  }
+}
+
+impl<K, V, A, S> ArrayHashMap<A, S>
+where
+  K: Hash + Eq,
+  A: Array<Item = HashSlot<K, V>>,
+  S: BuildHasher,
+{
+  /// The capacity of the map.
+  #[inline(always)]
+  #[must_use]
+  pub fn capacity(&self) -> usize {
+    A::CAPACITY
+  }
+
+  /// Removes all pairs from the map, and all the tombstones too.
+  #[inline]
+  pub fn clear(&mut self) {
+    for slot in self.slots.slice_mut() {
+      *slot = HashSlot::Empty;
+    }
+    self.len = 0;
+  }
+
+  /// If the map has a value for the key.
+  #[inline]
+  #[must_use]
+  pub fn contains_key<Q>(&self, key: &Q) -> bool
+  where
+    K: Borrow<Q>,
+    Q: Hash + Eq + ?Sized,
+  {
+    self.find(key).is_ok()
+  }
+
+  /// Gets the entry for the key, to inspect or change it in place.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut counts: ArrayHashMap<[HashSlot<char, u32>; 16]> =
+  ///   ArrayHashMap::new();
+  /// for c in "hello".chars() {
+  ///   *counts.entry(c).or_insert(0) += 1;
+  /// }
+  /// assert_eq!(counts.get(&'l'), Some(&2));
+  /// assert_eq!(counts.len(), 4);
+  /// ```
+  #[inline]
+  pub fn entry(&mut self, key: K) -> ArrayHashMapEntry<'_, A, S> {
+    match self.find(&key) {
+      Ok(index) => ArrayHashMapEntry::Occupied(ArrayHashMapOccupiedEntry {
+        map: self,
+        index,
+      }),
+      Err(index) => ArrayHashMapEntry::Vacant(ArrayHashMapVacantEntry {
+        map: self,
+        index,
+        key,
+      }),
+    }
+  }
+
+  /// The value for the key, if any.
+  #[inline]
+  #[must_use]
+  pub fn get<'a, Q>(&'a self, key: &Q) -> Option<&'a V>
+  where
+    K: 'a + Borrow<Q>,
+    Q: Hash + Eq + ?Sized,
+  {
+    self.get_key_value(key).map(|(_, v)| v)
+  }
+
+  /// The stored key and the value for the key, if any.
+  #[inline]
+  #[must_use]
+  pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+  where
+    K: Borrow<Q>,
+    Q: Hash + Eq + ?Sized,
+  {
+    match self.find(key) {
+      Ok(index) => match &self.slots.slice()[index] {
+        HashSlot::Full(k, v) => Some((k, v)),
+        _ => None,
+      },
+      Err(_) => None,
+    }
+  }
+
+  /// The value for the key, if any.
+  #[inline]
+  #[must_use]
+  pub fn get_mut<'a, Q>(&'a mut self, key: &Q) -> Option<&'a mut V>
+  where
+    K: 'a + Borrow<Q>,
+    Q: Hash + Eq + ?Sized,
+  {
+    match self.find(key) {
+      Ok(index) => match &mut self.slots.slice_mut()[index] {
+        HashSlot::Full(_, v) => Some(v),
+        _ => None,
+      },
+      Err(_) => None,
+    }
+  }
+
+  /// The map's hash builder.
+  #[inline(always)]
+  #[must_use]
+  pub fn hasher(&self) -> &S {
+    &self.hash_builder
+  }
+
+  /// Inserts a pair into the map.
+  ///
+  /// If the key was already present the old value is replaced (the old key is
+  /// kept) and returned.
+  ///
+  /// ## Panics
+  /// * If the key is new and the map is already full.
+  #[inline]
+  pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+    match self.try_insert(key, value) {
+      Ok(old) => old,
+      Err(_) => panic!(
+        "ArrayHashMap::insert> capacity overflow! Capacity: {}",
+        self.capacity()
+      ),
+    }
+  }
+
+  /// If the map is empty.
+  #[inline(always)]
+  #[must_use]
+  pub fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  /// Iterates over the pairs, in slot order.
+  #[inline]
+  #[must_use]
+  pub fn iter(&self) -> ArrayHashMapIter<'_, K, V> {
+    ArrayHashMapIter { inner: self.slots.slice().iter(), remaining: self.len }
+  }
+
+  /// Iterates over the pairs in slot order, with unique access to the values.
+  #[inline]
+  #[must_use]
+  pub fn iter_mut(&mut self) -> ArrayHashMapIterMut<'_, K, V> {
+    let remaining = self.len;
+    ArrayHashMapIterMut { inner: self.slots.slice_mut().iter_mut(), remaining }
+  }
+
+  /// Iterates over the keys, in slot order.
+  #[inline]
+  pub fn keys(&self) -> MapKeys<ArrayHashMapIter<'_, K, V>> {
+    MapKeys::new(self.iter())
+  }
+
+  /// The number of pairs in the map.
+  #[inline(always)]
+  #[must_use]
+  pub fn len(&self) -> usize {
+    self.len
+  }
+
+  /// Removes the key from the map, returning its value if it was present.
+  #[inline]
+  pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+  where
+    K: Borrow<Q>,
+    Q: Hash + Eq + ?Sized,
+  {
+    self.remove_entry(key).map(|(_, v)| v)
+  }
+
+  /// Removes the key from the map, returning the stored pair if it was
+  /// present.
+  #[inline]
+  pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+  where
+    K: Borrow<Q>,
+    Q: Hash + Eq + ?Sized,
+  {
+    match self.find(key) {
+      Ok(index) => Some(self.remove_at(index)),
+      Err(_) => None,
+    }
+  }
+
+  /// Keeps only the pairs that pass the predicate given.
+  #[inline]
+  pub fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut acceptable: F) {
+    for index in 0..self.capacity() {
+      let keep = match &mut self.slots.slice_mut()[index] {
+        HashSlot::Full(k, v) => acceptable(k, v),
+        _ => true,
+      };
+      if !keep {
+        self.remove_at(index);
+      }
+    }
+  }
+
+  /// Inserts a pair into the map, if there's room.
+  ///
+  /// If the key was already present the old value is replaced (the old key is
+  /// kept) and returned. That always works, even when the map is full.
+  ///
+  /// ## Failure
+  /// * If the key is new and the map is full you get the pair back in the
+  ///   error and the map is unchanged.
+  #[inline]
+  pub fn try_insert(
+    &mut self,
+    key: K,
+    value: V,
+  ) -> Result<Option<V>, CapacityError<(K, V)>> {
+    match self.find(&key) {
+      Ok(index) => match &mut self.slots.slice_mut()[index] {
+        HashSlot::Full(_, v) => Ok(Some(replace(v, value))),
+        _ => unreachable!(),
+      },
+      Err(Some(index)) => {
+        self.slots.slice_mut()[index] = HashSlot::Full(key, value);
+        self.len += 1;
+        Ok(None)
+      }
+      Err(None) => Err(CapacityError::new((key, value))),
+    }
+  }
+
+  /// Iterates over the values, in slot order.
+  #[inline]
+  pub fn values(&self) -> MapValues<ArrayHashMapIter<'_, K, V>> {
+    MapValues::new(self.iter())
+  }
+
+  /// Iterates over unique references to the values, in slot order.
+  #[inline]
+  pub fn values_mut(&mut self) -> MapValues<ArrayHashMapIterMut<'_, K, V>> {
+    MapValues::new(self.iter_mut())
+  }
+
+  /// Probes for the key.
+  ///
+  /// * `Ok(index)` is the slot holding the key.
+  /// * `Err(Some(index))` is the slot to insert the key into: the first
+  ///   tombstone on the probe path, or else the empty slot that ended it.
+  /// * `Err(None)` means the key isn't there and there's no room for it.
+  #[inline]
+  #[allow(clippy::manual_hash_one)] // `BuildHasher::hash_one` needs Rust 1.71.
+  fn find<Q>(&self, key: &Q) -> Result<usize, Option<usize>>
+  where
+    K: Borrow<Q>,
+    Q: Hash + Eq + ?Sized,
+  {
+    let slots = self.slots.slice();
+    if slots.is_empty() {
+      return Err(None);
+    }
+    let mut hasher = self.hash_builder.build_hasher();
+    key.hash(&mut hasher);
+    let mut index = (hasher.finish() % slots.len() as u64) as usize;
+    let mut first_tombstone = None;
+    for _ in 0..slots.len() {
+      match &slots[index] {
+        HashSlot::Empty => return Err(first_tombstone.or(Some(index))),
+        HashSlot::Tombstone => {
+          if first_tombstone.is_none() {
+            first_tombstone = Some(index);
+          }
+        }
+        HashSlot::Full(k, _) => {
+          if k.borrow() == key {
+            return Ok(index);
+          }
+        }
+      }
+      index += 1;
+      if index == slots.len() {
+        index = 0;
+      }
+    }
+    Err(first_tombstone)
+  }
+
+  /// Takes the pair out of a full slot, leaving a tombstone (or clearing
+  /// tombstones that aren't needed any more).
+  #[inline]
+  fn remove_at(&mut self, index: usize) -> (K, V) {
+    let slots = self.slots.slice_mut();
+    let n = slots.len();
+    let pair = if let HashSlot::Empty = slots[(index + 1) % n] {
+      // Nothing probes past an empty slot, so this slot and any tombstones
+      // right before it can be empty too.
+      let pair = replace(&mut slots[index], HashSlot::Empty);
+      let mut i = index;
+      loop {
+        i = if i == 0 { n - 1 } else { i - 1 };
+        match slots[i] {
+          HashSlot::Tombstone => slots[i] = HashSlot::Empty,
+          _ => break,
+        }
+      }
+      pair
+    } else {
+      replace(&mut slots[index], HashSlot::Tombstone)
+    };
+    self.len -= 1;
+    match pair {
+      HashSlot::Full(k, v) => (k, v),
+      _ => unreachable!("ArrayHashMap::remove_at> slot wasn't full"),
+    }
+  }
+}
+
+/// A view into a single entry of an [`ArrayHashMap`], which is either
+/// occupied or vacant.
+///
+/// See [`ArrayHashMap::entry`]
+pub enum ArrayHashMapEntry<'a, A: Array, S>
+where
+  A::Item: HashSlotPair,
+{
+  #[allow(missing_docs)]
+  Occupied(ArrayHashMapOccupiedEntry<'a, A, S>),
+  #[allow(missing_docs)]
+  Vacant(ArrayHashMapVacantEntry<'a, A, S>),
+}
+
+impl<'a, K, V, A, S> ArrayHashMapEntry<'a, A, S>
+where
+  K: 'a + Hash + Eq,
+  V: 'a,
+  A: Array<Item = HashSlot<K, V>>,
+  S: BuildHasher,
+{
+  /// Calls the function on the value if the entry is occupied.
+  #[inline]
+  pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+    if let ArrayHashMapEntry::Occupied(o) = &mut self {
+      f(o.get_mut())
+    }
+    self
+  }
+
+  /// The key of the entry.
+  #[inline]
+  #[must_use]
+  pub fn key(&self) -> &K {
+    match self {
+      ArrayHashMapEntry::Occupied(o) => o.key(),
+      ArrayHashMapEntry::Vacant(v) => v.key(),
+    }
+  }
+
+  /// The value of the entry, after inserting `V::default()` if it's vacant.
+  ///
+  /// ## Panics
+  /// * If the entry is vacant and the map is full.
+  #[inline]
+  pub fn or_default(self) -> &'a mut V
+  where
+    V: Default,
+  {
+    self.or_insert_with(V::default)
+  }
+
+  /// The value of the entry, after inserting `default` if it's vacant.
+  ///
+  /// ## Panics
+  /// * If the entry is vacant and the map is full.
+  #[inline]
+  pub fn or_insert(self, default: V) -> &'a mut V {
+    self.or_insert_with(|| default)
+  }
+
+  /// The value of the entry, after inserting the output of the function if
+  /// it's vacant.
+  ///
+  /// ## Panics
+  /// * If the entry is vacant and the map is full.
+  #[inline]
+  pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+    match self {
+      ArrayHashMapEntry::Occupied(o) => o.into_mut(),
+      ArrayHashMapEntry::Vacant(v) => v.insert(default()),
+    }
+  }
+}
+
+/// Lets [`ArrayHashMapVacantEntry`] name the key type of its array.
+///
+/// This is implemented for every [`HashSlot`], and you shouldn't need to
+/// implement it yourself.
+pub trait HashSlotPair {
+  /// The type of the key.
+  type Key;
+}
+impl<K, V> HashSlotPair for HashSlot<K, V> {
+  type Key = K;
+}
+
+/// An occupied entry of an [`ArrayHashMap`].
+pub struct ArrayHashMapOccupiedEntry<'a, A: Array, S> {
+  map: &'a mut ArrayHashMap<A, S>,
+  index: usize,
+}
+
+impl<'a, K, V, A, S> ArrayHashMapOccupiedEntry<'a, A, S>
+where
+  K: 'a + Hash + Eq,
+  V: 'a,
+  A: Array<Item = HashSlot<K, V>>,
+  S: BuildHasher,
+{
+  /// The value of the entry.
+  #[inline]
+  #[must_use]
+  pub fn get(&self) -> &V {
+    match &self.map.slots.slice()[self.index] {
+      HashSlot::Full(_, v) => v,
+      _ => unreachable!(),
+    }
+  }
+
+  /// The value of the entry.
+  #[inline]
+  #[must_use]
+  pub fn get_mut(&mut self) -> &mut V {
+    match &mut self.map.slots.slice_mut()[self.index] {
+      HashSlot::Full(_, v) => v,
+      _ => unreachable!(),
+    }
+  }
+
+  /// Replaces the value of the entry, returning the old value.
+  #[inline]
+  pub fn insert(&mut self, value: V) -> V {
+    replace(self.get_mut(), value)
+  }
+
+  /// The value of the entry, borrowed for as long as the map was.
+  #[inline]
+  #[must_use]
+  pub fn into_mut(self) -> &'a mut V {
+    match &mut self.map.slots.slice_mut()[self.index] {
+      HashSlot::Full(_, v) => v,
+      _ => unreachable!(),
+    }
+  }
+
+  /// The key of the entry.
+  #[inline]
+  #[must_use]
+  pub fn key(&self) -> &K {
+    match &self.map.slots.slice()[self.index] {
+      HashSlot::Full(k, _) => k,
+      _ => unreachable!(),
+    }
+  }
+
+  /// Removes the entry from the map, returning the value.
+  #[inline]
+  #[allow(clippy::must_use_candidate)]
+  pub fn remove(self) -> V {
+    self.remove_entry().1
+  }
+
+  /// Removes the entry from the map, returning the stored pair.
+  #[inline]
+  #[allow(clippy::must_use_candidate)]
+  pub fn remove_entry(self) -> (K, V) {
+    self.map.remove_at(self.index)
+  }
+}
+
+/// A vacant entry of an [`ArrayHashMap`].
+pub struct ArrayHashMapVacantEntry<'a, A: Array, S>
+where
+  A::Item: HashSlotPair,
+{
+  map: &'a mut ArrayHashMap<A, S>,
+  index: Option<usize>,
+  key: <A::Item as HashSlotPair>::Key,
+}
+
+impl<'a, K, V, A, S> ArrayHashMapVacantEntry<'a, A, S>
+where
+  K: 'a + Hash + Eq,
+  V: 'a,
+  A: Array<Item = HashSlot<K, V>>,
+  S: BuildHasher,
+{
+  /// Inserts the value for the entry's key.
+  ///
+  /// ## Panics
+  /// * If the map is full.
+  #[inline]
+  pub fn insert(self, value: V) -> &'a mut V {
+    match self.try_insert(value) {
+      Ok(v) => v,
+      Err(_) => panic!("ArrayHashMapVacantEntry::insert> capacity overflow!"),
+    }
+  }
+
+  /// Takes back ownership of the key.
+  #[inline]
+  #[must_use]
+  pub fn into_key(self) -> K {
+    self.key
+  }
+
+  /// The key of the entry.
+  #[inline]
+  #[must_use]
+  pub fn key(&self) -> &K {
+    &self.key
+  }
+
+  /// Inserts the value for the entry's key, if there's room.
+  ///
+  /// ## Failure
+  /// * If the map is full you get the pair back in the error and the map is
+  ///   unchanged.
+  #[inline]
+  pub fn try_insert(
+    self,
+    value: V,
+  ) -> Result<&'a mut V, CapacityError<(K, V)>> {
+    let index = match self.index {
+      Some(index) => index,
+      None => return Err(CapacityError::new((self.key, value))),
+    };
+    let map = self.map;
+    map.len += 1;
+    let slot = &mut map.slots.slice_mut()[index];
+    *slot = HashSlot::Full(self.key, value);
+    match slot {
+      HashSlot::Full(_, v) => Ok(v),
+      _ => unreachable!(),
+    }
+  }
+}
+
+/// Iterator over the pairs of an [`ArrayHashMap`], in slot order.
+pub struct ArrayHashMapIter<'a, K, V> {
+  inner: core::slice::Iter<'a, HashSlot<K, V>>,
+  remaining: usize,
+}
+impl<'a, K, V> Iterator for ArrayHashMapIter<'a, K, V> {
+  type Item = (&'a K, &'a V);
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.remaining == 0 {
+      return None;
+    }
+    for slot in &mut self.inner {
+      if let HashSlot::Full(k, v) = slot {
+        self.remaining -= 1;
+        return Some((k, v));
+      }
+    }
+    None
+  }
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (self.remaining, Some(self.remaining))
+  }
+}
+impl<'a, K, V> DoubleEndedIterator for ArrayHashMapIter<'a, K, V> {
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    if self.remaining == 0 {
+      return None;
+    }
+    while let Some(slot) = self.inner.next_back() {
+      if let HashSlot::Full(k, v) = slot {
+        self.remaining -= 1;
+        return Some((k, v));
+      }
+    }
+    None
+  }
+}
+impl<'a, K, V> ExactSizeIterator for ArrayHashMapIter<'a, K, V> {}
+impl<'a, K, V> FusedIterator for ArrayHashMapIter<'a, K, V> {}
+impl<'a, K, V> Clone for ArrayHashMapIter<'a, K, V> {
+  #[inline]
+  fn clone(&self) -> Self {
+    Self { inner: self.inner.clone(), remaining: self.remaining }
+  }
+}
+
+/// Iterator over the pairs of an [`ArrayHashMap`] in slot order, with unique
+/// access to the values.
+pub struct ArrayHashMapIterMut<'a, K, V> {
+  inner: core::slice::IterMut<'a, HashSlot<K, V>>,
+  remaining: usize,
+}
+impl<'a, K, V> Iterator for ArrayHashMapIterMut<'a, K, V> {
+  type Item = (&'a K, &'a mut V);
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.remaining == 0 {
+      return None;
+    }
+    for slot in &mut self.inner {
+      if let HashSlot::Full(k, v) = slot {
+        self.remaining -= 1;
+        return Some((&*k, v));
+      }
+    }
+    None
+  }
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (self.remaining, Some(self.remaining))
+  }
+}
+impl<'a, K, V> DoubleEndedIterator for ArrayHashMapIterMut<'a, K, V> {
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    if self.remaining == 0 {
+      return None;
+    }
+    while let Some(slot) = self.inner.next_back() {
+      if let HashSlot::Full(k, v) = slot {
+        self.remaining -= 1;
+        return Some((&*k, v));
+      }
+    }
+    None
+  }
+}
+impl<'a, K, V> ExactSizeIterator for ArrayHashMapIterMut<'a, K, V> {}
+impl<'a, K, V> FusedIterator for ArrayHashMapIterMut<'a, K, V> {}
+
+/// Iterator for consuming an `ArrayHashMap` and returning owned pairs, in slot
+/// order.
+pub struct ArrayHashMapIterator<A: Array> {
+  inner: ArrayVecIterator<A>,
+  remaining: usize,
+}
+impl<K, V, A: Array<Item = HashSlot<K, V>>> Iterator
+  for ArrayHashMapIterator<A>
+{
+  type Item = (K, V);
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.remaining == 0 {
+      return None;
+    }
+    for slot in &mut self.inner {
+      if let HashSlot::Full(k, v) = slot {
+        self.remaining -= 1;
+        return Some((k, v));
+      }
+    }
+    None
+  }
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (self.remaining, Some(self.remaining))
+  }
+}
+impl<K, V, A: Array<Item = HashSlot<K, V>>> ExactSizeIterator
+  for ArrayHashMapIterator<A>
+{
+}
+impl<K, V, A: Array<Item = HashSlot<K, V>>> FusedIterator
+  for ArrayHashMapIterator<A>
+{
+}
+
+impl<K, V, A, S> Extend<(K, V)> for ArrayHashMap<A, S>
+where
+  K: Hash + Eq,
+  A: Array<Item = HashSlot<K, V>>,
+  S: BuildHasher,
+{
+  #[inline]
+  fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+    for (k, v) in iter {
+      self.insert(k, v);
+    }
+  }
+}
+
+impl<K, V, A, S> FromIterator<(K, V)> for ArrayHashMap<A, S>
+where
+  K: Hash + Eq,
+  A: Array<Item = HashSlot<K, V>>,
+  S: BuildHasher + Default,
+{
+  #[inline]
+  fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+    let mut map = Self::default();
+    map.extend(iter);
+    map
+  }
+}
+
+impl<K, V, A, S> IntoIterator for ArrayHashMap<A, S>
+where
+  A: Array<Item = HashSlot<K, V>>,
+{
+  type Item = (K, V);
+  type IntoIter = ArrayHashMapIterator<A>;
+  #[inline]
+  fn into_iter(self) -> Self::IntoIter {
+    ArrayHashMapIterator {
+      inner: ArrayVec::from(self.slots).into_iter(),
+      remaining: self.len,
+    }
+  }
+}
+
+impl<'a, K, V, A, S> IntoIterator for &'a ArrayHashMap<A, S>
+where
+  K: 'a + Hash + Eq,
+  V: 'a,
+  A: Array<Item = HashSlot<K, V>>,
+  S: BuildHasher,
+{
+  type Item = (&'a K, &'a V);
+  type IntoIter = ArrayHashMapIter<'a, K, V>;
+  #[inline(always)]
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+
+impl<'a, K, V, A, S> IntoIterator for &'a mut ArrayHashMap<A, S>
+where
+  K: 'a + Hash + Eq,
+  V: 'a,
+  A: Array<Item = HashSlot<K, V>>,
+  S: BuildHasher,
+{
+  type Item = (&'a K, &'a mut V);
+  type IntoIter = ArrayHashMapIterMut<'a, K, V>;
+  #[inline(always)]
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter_mut()
+  }
+}
+
+impl<K, V, A, S> PartialEq for ArrayHashMap<A, S>
+where
+  K: Hash + Eq,
+  V: PartialEq,
+  A: Array<Item = HashSlot<K, V>>,
+  S: BuildHasher,
+{
+  #[inline]
+  fn eq(&self, other: &Self) -> bool {
+    self.len() == other.len()
+      && self.iter().all(|(k, v)| other.get(k) == Some(v))
+  }
+}
+impl<K, V, A, S> Eq for ArrayHashMap<A, S>
+where
+  K: Hash + Eq,
+  V: Eq,
+  A: Array<Item = HashSlot<K, V>>,
+  S: BuildHasher,
+{
+}
+
+impl<K, V, A, S> Debug for ArrayHashMap<A, S>
+where
+  K: Hash + Eq + Debug,
+  V: Debug,
+  A: Array<Item = HashSlot<K, V>>,
+  S: BuildHasher,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    f.debug_map().entries(self.iter()).finish()
+  }
+}
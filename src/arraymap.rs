@@ -0,0 +1,849 @@
+use super::*;
+
+use core::{cmp::Ordering, ops::Bound};
+
+/// A key-value pair type, which lets [`TinyMap`] name the key and value types
+/// of its array.
+///
+/// This is implemented for every `(K, V)` tuple, and you shouldn't need to
+/// implement it yourself.
+pub trait KeyValue {
+  /// The type of the key.
+  type Key;
+  /// The type of the value.
+  type Value;
+}
+impl<K, V> KeyValue for (K, V) {
+  type Key = K;
+  type Value = V;
+}
+
+/// A map stored in an array of `(K, V)` pairs, sorted by key.
+///
+/// * Fixed capacity (based on array size)
+/// * Lookups are a binary search, and inserts and removals shift the pairs
+///   after that point, just like `ArrayVec::insert` and `ArrayVec::remove`.
+/// * Iteration is always in key order.
+///
+/// For a handful of entries this is smaller and faster than a `BTreeMap`, and
+/// it doesn't need an allocator. Because the pairs are stored in an
+/// [`ArrayVec`], both `K` and `V` have to be `Default`.
+///
+/// ```rust
+/// use tinyvec::*;
+///
+/// let mut m: ArrayMap<[(u8, &str); 4]> = ArrayMap::new();
+/// m.insert(3, "c");
+/// m.insert(1, "a");
+/// assert_eq!(m.insert(3, "C"), Some("c"));
+/// assert_eq!(m.get(&3), Some(&"C"));
+/// assert_eq!(m.keys().copied().collect::<Vec<u8>>(), [1, 3]);
+/// ```
+#[derive(Clone, Copy)]
+pub struct ArrayMap<A: Array> {
+  vec: ArrayVec<A>,
+}
+
+impl<A: Array> Default for ArrayMap<A> {
+  #[inline]
+  fn default() -> Self {
+    Self { vec: ArrayVec::default() }
+  }
+}
+
+impl<K: Ord, V, A: Array<Item = (K, V)>> ArrayMap<A> {
+  /// The pairs of the map, sorted by key.
+  #[inline(always)]
+  #[must_use]
+  pub fn as_slice(&self) -> &[(K, V)] {
+    self.vec.as_slice()
+  }
+
+  /// The capacity of the map.
+  #[inline(always)]
+  #[must_use]
+  pub fn capacity(&self) -> usize {
+    self.vec.capacity()
+  }
+
+  /// Removes all pairs from the map.
+  #[inline(always)]
+  pub fn clear(&mut self) {
+    self.vec.clear()
+  }
+
+  /// If the map has a value for the key.
+  #[inline]
+  #[must_use]
+  pub fn contains_key<Q>(&self, key: &Q) -> bool
+  where
+    K: Borrow<Q>,
+    Q: Ord + ?Sized,
+  {
+    self.search(key).is_ok()
+  }
+
+  /// Gets the entry for the key, to inspect or change it in place.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut counts: ArrayMap<[(char, u32); 8]> = ArrayMap::new();
+  /// for c in "abracadabra".chars() {
+  ///   *counts.entry(c).or_insert(0) += 1;
+  /// }
+  /// assert_eq!(counts.get(&'a'), Some(&5));
+  /// assert_eq!(counts.get(&'d'), Some(&1));
+  /// ```
+  #[inline]
+  pub fn entry(&mut self, key: K) -> ArrayMapEntry<'_, A> {
+    match self.search(&key) {
+      Ok(index) => ArrayMapEntry::Occupied(ArrayMapOccupiedEntry {
+        vec: &mut self.vec,
+        index,
+      }),
+      Err(index) => ArrayMapEntry::Vacant(ArrayMapVacantEntry {
+        vec: &mut self.vec,
+        index,
+        key,
+      }),
+    }
+  }
+
+  /// The pair with the smallest key, if any.
+  #[inline]
+  #[must_use]
+  pub fn first_key_value(&self) -> Option<(&K, &V)> {
+    self.vec.first().map(|(k, v)| (k, v))
+  }
+
+  /// The value for the key, if any.
+  #[inline]
+  #[must_use]
+  pub fn get<'a, Q>(&'a self, key: &Q) -> Option<&'a V>
+  where
+    K: 'a + Borrow<Q>,
+    Q: Ord + ?Sized,
+  {
+    match self.search(key) {
+      Ok(index) => {
+        let (_, v) = &self.vec[index];
+        Some(v)
+      }
+      Err(_) => None,
+    }
+  }
+
+  /// The stored key and the value for the key, if any.
+  #[inline]
+  #[must_use]
+  pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+  where
+    K: Borrow<Q>,
+    Q: Ord + ?Sized,
+  {
+    match self.search(key) {
+      Ok(index) => {
+        let (k, v) = &self.vec[index];
+        Some((k, v))
+      }
+      Err(_) => None,
+    }
+  }
+
+  /// The value for the key, if any.
+  #[inline]
+  #[must_use]
+  pub fn get_mut<'a, Q>(&'a mut self, key: &Q) -> Option<&'a mut V>
+  where
+    K: 'a + Borrow<Q>,
+    Q: Ord + ?Sized,
+  {
+    match self.search(key) {
+      Ok(index) => {
+        let (_, v) = &mut self.vec[index];
+        Some(v)
+      }
+      Err(_) => None,
+    }
+  }
+
+  /// Inserts a pair into the map.
+  ///
+  /// If the key was already present the old value is replaced (the old key is
+  /// kept) and returned.
+  ///
+  /// ## Panics
+  /// * If the key is new and the map is already full.
+  #[inline]
+  pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+    match self.try_insert(key, value) {
+      Ok(old) => old,
+      Err(_) => panic!(
+        "ArrayMap::insert> capacity overflow! Capacity: {}",
+        self.capacity()
+      ),
+    }
+  }
+
+  /// If the map is empty.
+  #[inline(always)]
+  #[must_use]
+  pub fn is_empty(&self) -> bool {
+    self.vec.is_empty()
+  }
+
+  /// Iterates over the pairs, in key order.
+  #[inline]
+  #[must_use]
+  pub fn iter(&self) -> ArrayMapIter<'_, K, V> {
+    ArrayMapIter { inner: self.vec.iter() }
+  }
+
+  /// Iterates over the pairs in key order, with unique access to the values.
+  #[inline]
+  #[must_use]
+  pub fn iter_mut(&mut self) -> ArrayMapIterMut<'_, K, V> {
+    ArrayMapIterMut { inner: self.vec.iter_mut() }
+  }
+
+  /// Iterates over the keys, in order.
+  #[inline]
+  pub fn keys(&self) -> MapKeys<ArrayMapIter<'_, K, V>> {
+    MapKeys::new(self.iter())
+  }
+
+  /// The pair with the largest key, if any.
+  #[inline]
+  #[must_use]
+  pub fn last_key_value(&self) -> Option<(&K, &V)> {
+    self.vec.last().map(|(k, v)| (k, v))
+  }
+
+  /// The number of pairs in the map.
+  #[inline(always)]
+  #[must_use]
+  pub fn len(&self) -> usize {
+    self.vec.len()
+  }
+
+  /// Makes a new, empty map.
+  #[inline(always)]
+  #[must_use]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Removes and returns the pair with the smallest key, if any.
+  #[inline]
+  pub fn pop_first(&mut self) -> Option<(K, V)> {
+    self.vec.try_remove(0)
+  }
+
+  /// Removes and returns the pair with the largest key, if any.
+  #[inline]
+  pub fn pop_last(&mut self) -> Option<(K, V)> {
+    self.vec.pop()
+  }
+
+  /// Iterates over the pairs with keys in the range, in key order.
+  ///
+  /// ## Panics
+  /// * If the range's start is after its end, or if the start and end are
+  ///   equal and both excluded.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let m: ArrayMap<[(u32, char); 8]> =
+  ///   (0..6).zip("abcdef".chars()).collect();
+  /// let mid: Vec<char> = m.range(2..4).map(|(_, c)| *c).collect();
+  /// assert_eq!(mid, ['c', 'd']);
+  /// let tail: Vec<u32> = m.range(4..).map(|(k, _)| *k).collect();
+  /// assert_eq!(tail, [4, 5]);
+  /// ```
+  #[inline]
+  pub fn range<Q, R>(&self, range: R) -> ArrayMapIter<'_, K, V>
+  where
+    K: Borrow<Q>,
+    Q: Ord + ?Sized,
+    R: RangeBounds<Q>,
+  {
+    let (start, end) =
+      sorted_range(&self.vec, |(k, _)| k, range, "ArrayMap::range");
+    ArrayMapIter { inner: self.vec[start..end].iter() }
+  }
+
+  /// Iterates over the pairs with keys in the range in key order, with unique
+  /// access to the values.
+  ///
+  /// ## Panics
+  /// * The same as [`range`](ArrayMap::range).
+  #[inline]
+  pub fn range_mut<Q, R>(&mut self, range: R) -> ArrayMapIterMut<'_, K, V>
+  where
+    K: Borrow<Q>,
+    Q: Ord + ?Sized,
+    R: RangeBounds<Q>,
+  {
+    let (start, end) =
+      sorted_range(&self.vec, |(k, _)| k, range, "ArrayMap::range_mut");
+    ArrayMapIterMut { inner: self.vec[start..end].iter_mut() }
+  }
+
+  /// Removes the key from the map, returning its value if it was present.
+  #[inline]
+  pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+  where
+    K: Borrow<Q>,
+    Q: Ord + ?Sized,
+  {
+    self.remove_entry(key).map(|(_, v)| v)
+  }
+
+  /// Removes the key from the map, returning the stored pair if it was
+  /// present.
+  #[inline]
+  pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+  where
+    K: Borrow<Q>,
+    Q: Ord + ?Sized,
+  {
+    match self.search(key) {
+      Ok(index) => Some(self.vec.remove(index)),
+      Err(_) => None,
+    }
+  }
+
+  /// Keeps only the pairs that pass the predicate given.
+  #[inline]
+  pub fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut acceptable: F) {
+    self.vec.retain_mut(|(k, v)| acceptable(k, v))
+  }
+
+  /// Inserts a pair into the map, if there's room.
+  ///
+  /// If the key was already present the old value is replaced (the old key is
+  /// kept) and returned. That always works, even when the map is full.
+  ///
+  /// ## Failure
+  /// * If the key is new and the map is full you get the pair back in the
+  ///   error and the map is unchanged.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut m: ArrayMap<[(u8, u8); 1]> = ArrayMap::new();
+  /// assert_eq!(m.try_insert(1, 10), Ok(None));
+  /// assert_eq!(m.try_insert(1, 11), Ok(Some(10)));
+  /// assert_eq!(m.try_insert(2, 20).unwrap_err().element(), (2, 20));
+  /// ```
+  #[inline]
+  pub fn try_insert(
+    &mut self,
+    key: K,
+    value: V,
+  ) -> Result<Option<V>, CapacityError<(K, V)>> {
+    match self.search(&key) {
+      Ok(index) => Ok(Some(replace(&mut self.vec[index].1, value))),
+      Err(index) => self.vec.try_insert(index, (key, value)).map(|()| None),
+    }
+  }
+
+  /// Iterates over the values, in key order.
+  #[inline]
+  pub fn values(&self) -> MapValues<ArrayMapIter<'_, K, V>> {
+    MapValues::new(self.iter())
+  }
+
+  /// Iterates over unique references to the values, in key order.
+  #[inline]
+  pub fn values_mut(&mut self) -> MapValues<ArrayMapIterMut<'_, K, V>> {
+    MapValues::new(self.iter_mut())
+  }
+
+  /// Binary search for the key.
+  #[inline]
+  fn search<Q>(&self, key: &Q) -> Result<usize, usize>
+  where
+    K: Borrow<Q>,
+    Q: Ord + ?Sized,
+  {
+    self.vec.binary_search_by(|(k, _)| k.borrow().cmp(key))
+  }
+}
+
+/// The indexes `start..end` of the items of a slice (sorted by key) that have
+/// keys in the range.
+///
+/// ## Panics
+/// * If the range's start is after its end, or if the start and end are
+///   equal and both excluded. The panic message starts with `who`.
+#[inline]
+pub(crate) fn sorted_range<T, K, Q, R, F>(
+  sorted: &[T],
+  key: F,
+  range: R,
+  who: &str,
+) -> (usize, usize)
+where
+  K: Borrow<Q>,
+  Q: Ord + ?Sized,
+  R: RangeBounds<Q>,
+  F: Fn(&T) -> &K,
+{
+  fn bound_key<Q: ?Sized>(bound: Bound<&Q>) -> Option<&Q> {
+    match bound {
+      Bound::Included(q) | Bound::Excluded(q) => Some(q),
+      Bound::Unbounded => None,
+    }
+  }
+  if let (Some(s), Some(e)) =
+    (bound_key(range.start_bound()), bound_key(range.end_bound()))
+  {
+    if s > e {
+      panic!("{}> range start is greater than range end", who);
+    }
+    if s == e {
+      if let (Bound::Excluded(_), Bound::Excluded(_)) =
+        (range.start_bound(), range.end_bound())
+      {
+        panic!("{}> range start and end are equal and excluded", who);
+      }
+    }
+  }
+  // The number of items (at the front) that pass the test, which must be true
+  // for a prefix of the items and false after.
+  let partition = |is_before: &dyn Fn(&Q) -> bool| -> usize {
+    match sorted.binary_search_by(|t| {
+      if is_before(key(t).borrow()) {
+        Ordering::Less
+      } else {
+        Ordering::Greater
+      }
+    }) {
+      Ok(index) | Err(index) => index,
+    }
+  };
+  let start = match range.start_bound() {
+    Bound::Included(q) => partition(&|k| k < q),
+    Bound::Excluded(q) => partition(&|k| k <= q),
+    Bound::Unbounded => 0,
+  };
+  let end = match range.end_bound() {
+    Bound::Included(q) => partition(&|k| k <= q),
+    Bound::Excluded(q) => partition(&|k| k < q),
+    Bound::Unbounded => sorted.len(),
+  };
+  (start, end)
+}
+
+/// A view into a single entry of an [`ArrayMap`], which is either occupied or
+/// vacant.
+///
+/// See [`ArrayMap::entry`]
+pub enum ArrayMapEntry<'a, A: Array>
+where
+  A::Item: KeyValue,
+{
+  #[allow(missing_docs)]
+  Occupied(ArrayMapOccupiedEntry<'a, A>),
+  #[allow(missing_docs)]
+  Vacant(ArrayMapVacantEntry<'a, A>),
+}
+
+impl<'a, K: 'a + Ord, V: 'a, A: Array<Item = (K, V)>> ArrayMapEntry<'a, A> {
+  /// Calls the function on the value if the entry is occupied.
+  #[inline]
+  pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+    if let ArrayMapEntry::Occupied(o) = &mut self {
+      f(o.get_mut())
+    }
+    self
+  }
+
+  /// The key of the entry.
+  #[inline]
+  #[must_use]
+  pub fn key(&self) -> &K {
+    match self {
+      ArrayMapEntry::Occupied(o) => o.key(),
+      ArrayMapEntry::Vacant(v) => v.key(),
+    }
+  }
+
+  /// The value of the entry, after inserting `V::default()` if it's vacant.
+  ///
+  /// ## Panics
+  /// * If the entry is vacant and the map is full.
+  #[inline]
+  pub fn or_default(self) -> &'a mut V
+  where
+    V: Default,
+  {
+    self.or_insert_with(V::default)
+  }
+
+  /// The value of the entry, after inserting `default` if it's vacant.
+  ///
+  /// ## Panics
+  /// * If the entry is vacant and the map is full.
+  #[inline]
+  pub fn or_insert(self, default: V) -> &'a mut V {
+    self.or_insert_with(|| default)
+  }
+
+  /// The value of the entry, after inserting the output of the function if
+  /// it's vacant.
+  ///
+  /// ## Panics
+  /// * If the entry is vacant and the map is full.
+  #[inline]
+  pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+    match self {
+      ArrayMapEntry::Occupied(o) => o.into_mut(),
+      ArrayMapEntry::Vacant(v) => v.insert(default()),
+    }
+  }
+}
+
+/// An occupied entry of an [`ArrayMap`].
+pub struct ArrayMapOccupiedEntry<'a, A: Array> {
+  vec: &'a mut ArrayVec<A>,
+  index: usize,
+}
+
+impl<'a, K: 'a + Ord, V: 'a, A: Array<Item = (K, V)>>
+  ArrayMapOccupiedEntry<'a, A>
+{
+  /// The value of the entry.
+  #[inline]
+  #[must_use]
+  pub fn get(&self) -> &V {
+    &self.vec[self.index].1
+  }
+
+  /// The value of the entry.
+  #[inline]
+  #[must_use]
+  pub fn get_mut(&mut self) -> &mut V {
+    &mut self.vec[self.index].1
+  }
+
+  /// Replaces the value of the entry, returning the old value.
+  #[inline]
+  pub fn insert(&mut self, value: V) -> V {
+    replace(self.get_mut(), value)
+  }
+
+  /// The value of the entry, borrowed for as long as the map was.
+  #[inline]
+  #[must_use]
+  pub fn into_mut(self) -> &'a mut V {
+    &mut self.vec[self.index].1
+  }
+
+  /// The key of the entry.
+  #[inline]
+  #[must_use]
+  pub fn key(&self) -> &K {
+    &self.vec[self.index].0
+  }
+
+  /// Removes the entry from the map, returning the value.
+  #[inline]
+  #[allow(clippy::must_use_candidate)]
+  pub fn remove(self) -> V {
+    self.remove_entry().1
+  }
+
+  /// Removes the entry from the map, returning the stored pair.
+  #[inline]
+  #[allow(clippy::must_use_candidate)]
+  pub fn remove_entry(self) -> (K, V) {
+    self.vec.remove(self.index)
+  }
+}
+
+/// A vacant entry of an [`ArrayMap`].
+pub struct ArrayMapVacantEntry<'a, A: Array>
+where
+  A::Item: KeyValue,
+{
+  vec: &'a mut ArrayVec<A>,
+  index: usize,
+  key: <A::Item as KeyValue>::Key,
+}
+
+impl<'a, K: 'a + Ord, V: 'a, A: Array<Item = (K, V)>>
+  ArrayMapVacantEntry<'a, A>
+{
+  /// Inserts the value for the entry's key.
+  ///
+  /// ## Panics
+  /// * If the map is full.
+  #[inline]
+  pub fn insert(self, value: V) -> &'a mut V {
+    match self.try_insert(value) {
+      Ok(v) => v,
+      Err(_) => panic!("ArrayMapVacantEntry::insert> capacity overflow!"),
+    }
+  }
+
+  /// Takes back ownership of the key.
+  #[inline]
+  #[must_use]
+  pub fn into_key(self) -> K {
+    self.key
+  }
+
+  /// The key of the entry.
+  #[inline]
+  #[must_use]
+  pub fn key(&self) -> &K {
+    &self.key
+  }
+
+  /// Inserts the value for the entry's key, if there's room.
+  ///
+  /// ## Failure
+  /// * If the map is full you get the pair back in the error and the map is
+  ///   unchanged.
+  #[inline]
+  pub fn try_insert(
+    self,
+    value: V,
+  ) -> Result<&'a mut V, CapacityError<(K, V)>> {
+    let vec = self.vec;
+    let index = self.index;
+    vec.try_insert(index, (self.key, value))?;
+    Ok(&mut vec[index].1)
+  }
+}
+
+/// Iterator over the pairs of an [`ArrayMap`], in key order.
+pub struct ArrayMapIter<'a, K, V> {
+  inner: core::slice::Iter<'a, (K, V)>,
+}
+impl<'a, K, V> Iterator for ArrayMapIter<'a, K, V> {
+  type Item = (&'a K, &'a V);
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    self.inner.next().map(|(k, v)| (k, v))
+  }
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.inner.size_hint()
+  }
+}
+impl<'a, K, V> DoubleEndedIterator for ArrayMapIter<'a, K, V> {
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    self.inner.next_back().map(|(k, v)| (k, v))
+  }
+}
+impl<'a, K, V> ExactSizeIterator for ArrayMapIter<'a, K, V> {}
+impl<'a, K, V> FusedIterator for ArrayMapIter<'a, K, V> {}
+impl<'a, K, V> Clone for ArrayMapIter<'a, K, V> {
+  #[inline]
+  fn clone(&self) -> Self {
+    Self { inner: self.inner.clone() }
+  }
+}
+
+/// Iterator over the pairs of an [`ArrayMap`] in key order, with unique access
+/// to the values.
+pub struct ArrayMapIterMut<'a, K, V> {
+  inner: core::slice::IterMut<'a, (K, V)>,
+}
+impl<'a, K, V> Iterator for ArrayMapIterMut<'a, K, V> {
+  type Item = (&'a K, &'a mut V);
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    self.inner.next().map(|(k, v)| (&*k, v))
+  }
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.inner.size_hint()
+  }
+}
+impl<'a, K, V> DoubleEndedIterator for ArrayMapIterMut<'a, K, V> {
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    self.inner.next_back().map(|(k, v)| (&*k, v))
+  }
+}
+impl<'a, K, V> ExactSizeIterator for ArrayMapIterMut<'a, K, V> {}
+impl<'a, K, V> FusedIterator for ArrayMapIterMut<'a, K, V> {}
+
+/// Iterator over the keys of a map, in order.
+///
+/// See [`ArrayMap::keys`] and [`TinyMap::keys`]
+#[derive(Clone)]
+pub struct MapKeys<I> {
+  inner: I,
+}
+impl<'a, K: 'a, V, I: Iterator<Item = (&'a K, V)>> Iterator for MapKeys<I> {
+  type Item = &'a K;
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    self.inner.next().map(|(k, _)| k)
+  }
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.inner.size_hint()
+  }
+}
+impl<'a, K: 'a, V, I: DoubleEndedIterator<Item = (&'a K, V)>>
+  DoubleEndedIterator for MapKeys<I>
+{
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    self.inner.next_back().map(|(k, _)| k)
+  }
+}
+impl<'a, K: 'a, V, I: ExactSizeIterator<Item = (&'a K, V)>> ExactSizeIterator
+  for MapKeys<I>
+{
+}
+impl<'a, K: 'a, V, I: FusedIterator<Item = (&'a K, V)>> FusedIterator
+  for MapKeys<I>
+{
+}
+impl<I> MapKeys<I> {
+  #[inline(always)]
+  pub(crate) fn new(inner: I) -> Self {
+    Self { inner }
+  }
+}
+
+/// Iterator over the values of a map, in key order.
+///
+/// See [`ArrayMap::values`] and [`TinyMap::values`]
+#[derive(Clone)]
+pub struct MapValues<I> {
+  inner: I,
+}
+impl<'a, K: 'a, V, I: Iterator<Item = (&'a K, V)>> Iterator for MapValues<I> {
+  type Item = V;
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    self.inner.next().map(|(_, v)| v)
+  }
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.inner.size_hint()
+  }
+}
+impl<'a, K: 'a, V, I: DoubleEndedIterator<Item = (&'a K, V)>>
+  DoubleEndedIterator for MapValues<I>
+{
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    self.inner.next_back().map(|(_, v)| v)
+  }
+}
+impl<'a, K: 'a, V, I: ExactSizeIterator<Item = (&'a K, V)>> ExactSizeIterator
+  for MapValues<I>
+{
+}
+impl<'a, K: 'a, V, I: FusedIterator<Item = (&'a K, V)>> FusedIterator
+  for MapValues<I>
+{
+}
+impl<I> MapValues<I> {
+  #[inline(always)]
+  pub(crate) fn new(inner: I) -> Self {
+    Self { inner }
+  }
+}
+
+impl<K: Ord, V, A: Array<Item = (K, V)>> Extend<(K, V)> for ArrayMap<A> {
+  #[inline]
+  fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+    for (k, v) in iter {
+      self.insert(k, v);
+    }
+  }
+}
+
+impl<K: Ord, V, A: Array<Item = (K, V)>> FromIterator<(K, V)> for ArrayMap<A> {
+  #[inline]
+  fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+    let mut map = Self::default();
+    map.extend(iter);
+    map
+  }
+}
+
+impl<K: Ord, V, A: Array<Item = (K, V)>> IntoIterator for ArrayMap<A> {
+  type Item = (K, V);
+  type IntoIter = ArrayVecIterator<A>;
+  #[inline(always)]
+  fn into_iter(self) -> Self::IntoIter {
+    self.vec.into_iter()
+  }
+}
+
+impl<'a, K: 'a + Ord, V: 'a, A: Array<Item = (K, V)>> IntoIterator
+  for &'a ArrayMap<A>
+{
+  type Item = (&'a K, &'a V);
+  type IntoIter = ArrayMapIter<'a, K, V>;
+  #[inline(always)]
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+
+impl<'a, K: 'a + Ord, V: 'a, A: Array<Item = (K, V)>> IntoIterator
+  for &'a mut ArrayMap<A>
+{
+  type Item = (&'a K, &'a mut V);
+  type IntoIter = ArrayMapIterMut<'a, K, V>;
+  #[inline(always)]
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter_mut()
+  }
+}
+
+impl<K: Ord, V, A: Array<Item = (K, V)>> PartialEq for ArrayMap<A>
+where
+  V: PartialEq,
+{
+  #[inline]
+  fn eq(&self, other: &Self) -> bool {
+    self.as_slice() == other.as_slice()
+  }
+}
+impl<K: Ord, V, A: Array<Item = (K, V)>> Eq for ArrayMap<A> where V: Eq {}
+
+impl<K: Ord, V, A: Array<Item = (K, V)>> PartialOrd for ArrayMap<A>
+where
+  V: PartialOrd,
+{
+  #[inline]
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    self.as_slice().partial_cmp(other.as_slice())
+  }
+}
+impl<K: Ord, V, A: Array<Item = (K, V)>> Ord for ArrayMap<A>
+where
+  V: Ord,
+{
+  #[inline]
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.as_slice().cmp(other.as_slice())
+  }
+}
+
+impl<K: Ord, V, A: Array<Item = (K, V)>> Debug for ArrayMap<A>
+where
+  K: Debug,
+  V: Debug,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    f.debug_map().entries(self.iter()).finish()
+  }
+}
@@ -0,0 +1,675 @@
+use super::*;
+
+use core::{
+  cmp::Ordering,
+  iter::Peekable,
+  ops::{BitAnd, BitOr, BitXor, Sub},
+};
+
+/// A set stored in an array, kept sorted and without duplicates.
+///
+/// * Fixed capacity (based on array size)
+/// * Lookups are a binary search, and inserts and removals shift the elements
+///   after that point, just like `ArrayVec::insert` and `ArrayVec::remove`.
+/// * Iteration is always in order, and the set algebra (union, intersection,
+///   and so on) is a single merge pass over both sets.
+///
+/// Because the elements are stored in an [`ArrayVec`], the element type has
+/// to be `Default`.
+///
+/// ```rust
+/// use tinyvec::*;
+///
+/// let a: ArraySet<[u32; 8]> = [5, 1, 3, 1].iter().copied().collect();
+/// let b: ArraySet<[u32; 8]> = [3, 4, 5].iter().copied().collect();
+/// assert_eq!(a.as_slice(), &[1, 3, 5]);
+/// assert!(a.intersection(&b).eq([3, 5].iter()));
+/// assert_eq!((&a | &b).as_slice(), &[1, 3, 4, 5]);
+/// ```
+#[derive(Clone, Copy)]
+pub struct ArraySet<A: Array> {
+  vec: ArrayVec<A>,
+}
+
+impl<A: Array> Default for ArraySet<A> {
+  #[inline]
+  fn default() -> Self {
+    Self { vec: ArrayVec::default() }
+  }
+}
+
+impl<T: Ord, A: Array<Item = T>> ArraySet<A> {
+  /// The elements of the set, in order.
+  #[inline(always)]
+  #[must_use]
+  pub fn as_slice(&self) -> &[T] {
+    self.vec.as_slice()
+  }
+
+  /// The capacity of the set.
+  #[inline(always)]
+  #[must_use]
+  pub fn capacity(&self) -> usize {
+    self.vec.capacity()
+  }
+
+  /// Removes all elements from the set.
+  #[inline(always)]
+  pub fn clear(&mut self) {
+    self.vec.clear()
+  }
+
+  /// If the set has the value.
+  #[inline]
+  #[must_use]
+  pub fn contains<Q>(&self, value: &Q) -> bool
+  where
+    T: Borrow<Q>,
+    Q: Ord + ?Sized,
+  {
+    self.search(value).is_ok()
+  }
+
+  /// Iterates over the elements in `self` but not in `other`, in order.
+  #[inline]
+  pub fn difference<'a, B: Array<Item = T>>(
+    &'a self,
+    other: &'a ArraySet<B>,
+  ) -> SetDifference<core::slice::Iter<'a, T>> {
+    SetDifference::new(self.iter(), other.iter())
+  }
+
+  /// The smallest element, if any.
+  #[inline]
+  #[must_use]
+  pub fn first(&self) -> Option<&T> {
+    self.vec.first()
+  }
+
+  /// The stored element equal to the value, if any.
+  #[inline]
+  #[must_use]
+  pub fn get<Q>(&self, value: &Q) -> Option<&T>
+  where
+    T: Borrow<Q>,
+    Q: Ord + ?Sized,
+  {
+    match self.search(value) {
+      Ok(index) => Some(&self.vec[index]),
+      Err(_) => None,
+    }
+  }
+
+  /// Adds the value to the set.
+  ///
+  /// Returns `true` if the value was new. If it was already present the set
+  /// is unchanged (the stored value is kept).
+  ///
+  /// ## Panics
+  /// * If the value is new and the set is already full.
+  #[inline]
+  pub fn insert(&mut self, value: T) -> bool {
+    match self.try_insert(value) {
+      Ok(added) => added,
+      Err(_) => panic!(
+        "ArraySet::insert> capacity overflow! Capacity: {}",
+        self.capacity()
+      ),
+    }
+  }
+
+  /// Iterates over the elements in both `self` and `other`, in order.
+  #[inline]
+  pub fn intersection<'a, B: Array<Item = T>>(
+    &'a self,
+    other: &'a ArraySet<B>,
+  ) -> SetIntersection<core::slice::Iter<'a, T>> {
+    SetIntersection::new(self.iter(), other.iter())
+  }
+
+  /// If `self` and `other` have no elements in common.
+  #[inline]
+  #[must_use]
+  pub fn is_disjoint<B: Array<Item = T>>(&self, other: &ArraySet<B>) -> bool {
+    self.intersection(other).next().is_none()
+  }
+
+  /// If the set is empty.
+  #[inline(always)]
+  #[must_use]
+  pub fn is_empty(&self) -> bool {
+    self.vec.is_empty()
+  }
+
+  /// If every element of `self` is also in `other`.
+  #[inline]
+  #[must_use]
+  pub fn is_subset<B: Array<Item = T>>(&self, other: &ArraySet<B>) -> bool {
+    self.len() <= other.len() && self.difference(other).next().is_none()
+  }
+
+  /// If every element of `other` is also in `self`.
+  #[inline]
+  #[must_use]
+  pub fn is_superset<B: Array<Item = T>>(&self, other: &ArraySet<B>) -> bool {
+    other.is_subset(self)
+  }
+
+  /// Iterates over the elements, in order.
+  #[inline]
+  pub fn iter(&self) -> core::slice::Iter<'_, T> {
+    self.vec.iter()
+  }
+
+  /// The largest element, if any.
+  #[inline]
+  #[must_use]
+  pub fn last(&self) -> Option<&T> {
+    self.vec.last()
+  }
+
+  /// The number of elements in the set.
+  #[inline(always)]
+  #[must_use]
+  pub fn len(&self) -> usize {
+    self.vec.len()
+  }
+
+  /// Makes a new, empty set.
+  #[inline(always)]
+  #[must_use]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Removes and returns the smallest element, if any.
+  #[inline]
+  pub fn pop_first(&mut self) -> Option<T> {
+    self.vec.try_remove(0)
+  }
+
+  /// Removes and returns the largest element, if any.
+  #[inline]
+  pub fn pop_last(&mut self) -> Option<T> {
+    self.vec.pop()
+  }
+
+  /// Iterates over the elements in the range, in order.
+  ///
+  /// ## Panics
+  /// * If the range's start is after its end, or if the start and end are
+  ///   equal and both excluded.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let s: ArraySet<[u8; 8]> = (0..8).map(|x| x * 3).collect();
+  /// assert!(s.range(4..=12).eq([6, 9, 12].iter()));
+  /// ```
+  #[inline]
+  pub fn range<Q, R>(&self, range: R) -> core::slice::Iter<'_, T>
+  where
+    T: Borrow<Q>,
+    Q: Ord + ?Sized,
+    R: RangeBounds<Q>,
+  {
+    let (start, end) = sorted_range(&self.vec, |t| t, range, "ArraySet::range");
+    self.vec[start..end].iter()
+  }
+
+  /// Removes the value from the set, returning if it was present.
+  #[inline]
+  pub fn remove<Q>(&mut self, value: &Q) -> bool
+  where
+    T: Borrow<Q>,
+    Q: Ord + ?Sized,
+  {
+    self.take(value).is_some()
+  }
+
+  /// Keeps only the elements that pass the predicate given.
+  #[inline]
+  pub fn retain<F: FnMut(&T) -> bool>(&mut self, acceptable: F) {
+    self.vec.retain(acceptable)
+  }
+
+  /// Iterates over the elements in exactly one of `self` and `other`, in
+  /// order.
+  #[inline]
+  pub fn symmetric_difference<'a, B: Array<Item = T>>(
+    &'a self,
+    other: &'a ArraySet<B>,
+  ) -> SetSymmetricDifference<core::slice::Iter<'a, T>> {
+    SetSymmetricDifference::new(self.iter(), other.iter())
+  }
+
+  /// Removes the value from the set, returning the stored element if it was
+  /// present.
+  #[inline]
+  pub fn take<Q>(&mut self, value: &Q) -> Option<T>
+  where
+    T: Borrow<Q>,
+    Q: Ord + ?Sized,
+  {
+    match self.search(value) {
+      Ok(index) => Some(self.vec.remove(index)),
+      Err(_) => None,
+    }
+  }
+
+  /// Adds the value to the set, if there's room.
+  ///
+  /// Returns `Ok(true)` if the value was new. If it was already present the
+  /// set is unchanged and you get `Ok(false)`, even when the set is full.
+  ///
+  /// ## Failure
+  /// * If the value is new and the set is full you get it back in the error
+  ///   and the set is unchanged.
+  #[inline]
+  pub fn try_insert(&mut self, value: T) -> Result<bool, CapacityError<T>> {
+    match self.search(&value) {
+      Ok(_) => Ok(false),
+      Err(index) => self.vec.try_insert(index, value).map(|()| true),
+    }
+  }
+
+  /// Makes a new set of the elements in `self` or `other`, if it fits.
+  ///
+  /// ## Failure
+  /// * If the union has more elements than the capacity you get an error.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let a: ArraySet<[u8; 3]> = [1, 2].iter().copied().collect();
+  /// let b: ArraySet<[u8; 3]> = [2, 3].iter().copied().collect();
+  /// let c: ArraySet<[u8; 3]> = [4].iter().copied().collect();
+  /// let ab = a.try_union(&b).unwrap();
+  /// assert_eq!(ab.as_slice(), &[1, 2, 3]);
+  /// assert!(ab.try_union(&c).is_err());
+  /// ```
+  #[inline]
+  pub fn try_union<B: Array<Item = T>>(
+    &self,
+    other: &ArraySet<B>,
+  ) -> Result<Self, CapacityError<()>>
+  where
+    T: Clone,
+  {
+    Self::try_from_sorted(self.union(other).cloned())
+  }
+
+  /// Makes a new set of the elements in exactly one of `self` and `other`,
+  /// if it fits.
+  ///
+  /// ## Failure
+  /// * If the symmetric difference has more elements than the capacity you
+  ///   get an error.
+  #[inline]
+  pub fn try_symmetric_difference<B: Array<Item = T>>(
+    &self,
+    other: &ArraySet<B>,
+  ) -> Result<Self, CapacityError<()>>
+  where
+    T: Clone,
+  {
+    Self::try_from_sorted(self.symmetric_difference(other).cloned())
+  }
+
+  /// Iterates over the elements in `self` or `other` (or both), in order.
+  #[inline]
+  pub fn union<'a, B: Array<Item = T>>(
+    &'a self,
+    other: &'a ArraySet<B>,
+  ) -> SetUnion<core::slice::Iter<'a, T>> {
+    SetUnion::new(self.iter(), other.iter())
+  }
+
+  /// Binary search for the value.
+  #[inline]
+  fn search<Q>(&self, value: &Q) -> Result<usize, usize>
+  where
+    T: Borrow<Q>,
+    Q: Ord + ?Sized,
+  {
+    self.vec.binary_search_by(|t| t.borrow().cmp(value))
+  }
+
+  /// Collects elements that are already sorted and unique.
+  #[inline]
+  fn try_from_sorted<I: Iterator<Item = T>>(
+    iter: I,
+  ) -> Result<Self, CapacityError<()>> {
+    let mut vec = ArrayVec::new();
+    for t in iter {
+      if vec.try_push(t).is_err() {
+        return Err(CapacityError::new(()));
+      }
+    }
+    Ok(Self { vec })
+  }
+}
+
+/// Merge-iterator over the union of two sorted, duplicate-free iterators.
+///
+/// See [`ArraySet::union`] and [`TinySet::union`]
+pub struct SetUnion<I: Iterator> {
+  a: Peekable<I>,
+  b: Peekable<I>,
+}
+
+/// Merge-iterator over the intersection of two sorted, duplicate-free
+/// iterators.
+///
+/// See [`ArraySet::intersection`] and [`TinySet::intersection`]
+pub struct SetIntersection<I: Iterator> {
+  a: Peekable<I>,
+  b: Peekable<I>,
+}
+
+/// Merge-iterator over the difference of two sorted, duplicate-free
+/// iterators.
+///
+/// See [`ArraySet::difference`] and [`TinySet::difference`]
+pub struct SetDifference<I: Iterator> {
+  a: Peekable<I>,
+  b: Peekable<I>,
+}
+
+/// Merge-iterator over the symmetric difference of two sorted,
+/// duplicate-free iterators.
+///
+/// See [`ArraySet::symmetric_difference`] and
+/// [`TinySet::symmetric_difference`]
+pub struct SetSymmetricDifference<I: Iterator> {
+  a: Peekable<I>,
+  b: Peekable<I>,
+}
+
+/// How the next elements of two peekable iterators compare. A missing element
+/// sorts after everything, and `None` means both are done.
+#[inline]
+fn peek_cmp<I: Iterator>(
+  a: &mut Peekable<I>,
+  b: &mut Peekable<I>,
+) -> Option<Ordering>
+where
+  I::Item: Ord,
+{
+  match (a.peek(), b.peek()) {
+    (Some(x), Some(y)) => Some(x.cmp(y)),
+    (Some(_), None) => Some(Ordering::Less),
+    (None, Some(_)) => Some(Ordering::Greater),
+    (None, None) => None,
+  }
+}
+
+macro_rules! impl_set_iterator_common {
+  ($name:ident) => {
+    impl<I: Iterator> $name<I> {
+      #[inline]
+      pub(crate) fn new(a: I, b: I) -> Self {
+        Self { a: a.peekable(), b: b.peekable() }
+      }
+    }
+    impl<I: Iterator> Clone for $name<I>
+    where
+      Peekable<I>: Clone,
+    {
+      #[inline]
+      fn clone(&self) -> Self {
+        Self { a: self.a.clone(), b: self.b.clone() }
+      }
+    }
+    impl<I: FusedIterator> FusedIterator for $name<I> where I::Item: Ord {}
+  };
+}
+impl_set_iterator_common!(SetUnion);
+impl_set_iterator_common!(SetIntersection);
+impl_set_iterator_common!(SetDifference);
+impl_set_iterator_common!(SetSymmetricDifference);
+
+impl<I: Iterator> Iterator for SetUnion<I>
+where
+  I::Item: Ord,
+{
+  type Item = I::Item;
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    match peek_cmp(&mut self.a, &mut self.b)? {
+      Ordering::Less => self.a.next(),
+      Ordering::Greater => self.b.next(),
+      Ordering::Equal => {
+        self.b.next();
+        self.a.next()
+      }
+    }
+  }
+  #[inline]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let (a_min, a_max) = self.a.size_hint();
+    let (b_min, b_max) = self.b.size_hint();
+    let max = match (a_max, b_max) {
+      (Some(a), Some(b)) => a.checked_add(b),
+      _ => None,
+    };
+    (a_min.max(b_min), max)
+  }
+}
+
+impl<I: Iterator> Iterator for SetIntersection<I>
+where
+  I::Item: Ord,
+{
+  type Item = I::Item;
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    loop {
+      if self.a.peek().is_none() || self.b.peek().is_none() {
+        return None;
+      }
+      match peek_cmp(&mut self.a, &mut self.b)? {
+        Ordering::Less => {
+          self.a.next();
+        }
+        Ordering::Greater => {
+          self.b.next();
+        }
+        Ordering::Equal => {
+          self.b.next();
+          return self.a.next();
+        }
+      }
+    }
+  }
+  #[inline]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let max = match (self.a.size_hint().1, self.b.size_hint().1) {
+      (Some(a), Some(b)) => Some(a.min(b)),
+      (a, b) => a.or(b),
+    };
+    (0, max)
+  }
+}
+
+impl<I: Iterator> Iterator for SetDifference<I>
+where
+  I::Item: Ord,
+{
+  type Item = I::Item;
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    loop {
+      self.a.peek()?;
+      match peek_cmp(&mut self.a, &mut self.b)? {
+        Ordering::Less => return self.a.next(),
+        Ordering::Greater => {
+          self.b.next();
+        }
+        Ordering::Equal => {
+          self.a.next();
+          self.b.next();
+        }
+      }
+    }
+  }
+  #[inline]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (0, self.a.size_hint().1)
+  }
+}
+
+impl<I: Iterator> Iterator for SetSymmetricDifference<I>
+where
+  I::Item: Ord,
+{
+  type Item = I::Item;
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    loop {
+      match peek_cmp(&mut self.a, &mut self.b)? {
+        Ordering::Less => return self.a.next(),
+        Ordering::Greater => return self.b.next(),
+        Ordering::Equal => {
+          self.a.next();
+          self.b.next();
+        }
+      }
+    }
+  }
+  #[inline]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let max = match (self.a.size_hint().1, self.b.size_hint().1) {
+      (Some(a), Some(b)) => a.checked_add(b),
+      _ => None,
+    };
+    (0, max)
+  }
+}
+
+impl<T: Ord, A: Array<Item = T>> Extend<T> for ArraySet<A> {
+  #[inline]
+  fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+    for t in iter {
+      self.insert(t);
+    }
+  }
+}
+
+impl<T: Ord, A: Array<Item = T>> From<ArrayVec<A>> for ArraySet<A> {
+  /// Sorts the vec and removes the duplicates.
+  #[inline]
+  fn from(mut vec: ArrayVec<A>) -> Self {
+    vec.sort_unstable();
+    vec.dedup();
+    Self { vec }
+  }
+}
+
+impl<T: Ord, A: Array<Item = T>> FromIterator<T> for ArraySet<A> {
+  #[inline]
+  fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+    let mut set = Self::default();
+    set.extend(iter);
+    set
+  }
+}
+
+impl<T: Ord, A: Array<Item = T>> IntoIterator for ArraySet<A> {
+  type Item = T;
+  type IntoIter = ArrayVecIterator<A>;
+  #[inline(always)]
+  fn into_iter(self) -> Self::IntoIter {
+    self.vec.into_iter()
+  }
+}
+
+impl<'a, T: 'a + Ord, A: Array<Item = T>> IntoIterator for &'a ArraySet<A> {
+  type Item = &'a T;
+  type IntoIter = core::slice::Iter<'a, T>;
+  #[inline(always)]
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+
+impl<T: Ord + Clone, A: Array<Item = T>> BitAnd for &ArraySet<A> {
+  type Output = ArraySet<A>;
+  /// The intersection of the sets, which always fits.
+  #[inline]
+  fn bitand(self, rhs: Self) -> ArraySet<A> {
+    let mut vec = ArrayVec::new();
+    vec.extend(self.intersection(rhs).cloned());
+    ArraySet { vec }
+  }
+}
+
+impl<T: Ord + Clone, A: Array<Item = T>> BitOr for &ArraySet<A> {
+  type Output = ArraySet<A>;
+  /// The union of the sets.
+  ///
+  /// ## Panics
+  /// * If the union doesn't fit in the capacity. See
+  ///   [`try_union`](ArraySet::try_union).
+  #[inline]
+  fn bitor(self, rhs: Self) -> ArraySet<A> {
+    self.try_union(rhs).expect("ArraySet::bitor> capacity overflow!")
+  }
+}
+
+impl<T: Ord + Clone, A: Array<Item = T>> BitXor for &ArraySet<A> {
+  type Output = ArraySet<A>;
+  /// The symmetric difference of the sets.
+  ///
+  /// ## Panics
+  /// * If the symmetric difference doesn't fit in the capacity. See
+  ///   [`try_symmetric_difference`](ArraySet::try_symmetric_difference).
+  #[inline]
+  fn bitxor(self, rhs: Self) -> ArraySet<A> {
+    self
+      .try_symmetric_difference(rhs)
+      .expect("ArraySet::bitxor> capacity overflow!")
+  }
+}
+
+impl<T: Ord + Clone, A: Array<Item = T>> Sub for &ArraySet<A> {
+  type Output = ArraySet<A>;
+  /// The difference of the sets, which always fits.
+  #[inline]
+  fn sub(self, rhs: Self) -> ArraySet<A> {
+    let mut vec = ArrayVec::new();
+    vec.extend(self.difference(rhs).cloned());
+    ArraySet { vec }
+  }
+}
+
+impl<T: Ord, A: Array<Item = T>> PartialEq for ArraySet<A> {
+  #[inline]
+  fn eq(&self, other: &Self) -> bool {
+    self.as_slice() == other.as_slice()
+  }
+}
+impl<T: Ord, A: Array<Item = T>> Eq for ArraySet<A> {}
+
+impl<T: Ord, A: Array<Item = T>> PartialOrd for ArraySet<A> {
+  #[inline]
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+impl<T: Ord, A: Array<Item = T>> Ord for ArraySet<A> {
+  #[inline]
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.as_slice().cmp(other.as_slice())
+  }
+}
+
+impl<T: Ord, A: Array<Item = T>> Debug for ArraySet<A>
+where
+  T: Debug,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    f.debug_set().entries(self.iter()).finish()
+  }
+}
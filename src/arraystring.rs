@@ -0,0 +1,476 @@
+use super::*;
+
+use core::{
+  fmt::Write,
+  hash::{Hash, Hasher},
+  str::{from_utf8, from_utf8_mut, FromStr},
+};
+
+/// Helper to make an `ArrayString` from format arguments.
+///
+/// You specify the backing array type, then give the format string and
+/// arguments just like with `format!`.
+///
+/// The output is a `Result`:
+/// * `Ok` if everything fit.
+/// * `Err` if the output was too long. In this case the `Err` holds as much of
+///   the output as could fit (cut at a `char` boundary).
+///
+/// ```rust
+/// use tinyvec::*;
+///
+/// let s = array_format!([u8; 16], "{}-{}", "abc", 123).unwrap();
+/// assert_eq!(s, "abc-123");
+///
+/// let cut = array_format!([u8; 4], "{}", "hello").unwrap_err();
+/// assert_eq!(cut, "hell");
+/// ```
+#[macro_export]
+macro_rules! array_format {
+  ($array_type:ty, $($arg:tt)*) => {
+    $crate::ArrayString::<$array_type>::try_from_fmt(format_args!($($arg)*))
+  };
+}
+
+/// An array-backed UTF-8 string with a fixed capacity.
+///
+/// * Fixed capacity (based on array size, in bytes)
+/// * Variable length
+/// * The bytes in use are always valid UTF-8.
+///
+/// The storage is an [`ArrayVec`] of bytes, so any length supported by
+/// [`Array`] can be used.
+///
+/// Because the crate has no `unsafe`, getting a `&str` (via `Deref`, `Display`
+/// and so on) has to re-validate the UTF-8 of the bytes in use. That's a
+/// linear scan over the current length, which is at most `A::CAPACITY` bytes.
+///
+/// ```rust
+/// use tinyvec::*;
+///
+/// let mut s: ArrayString<[u8; 8]> = ArrayString::new();
+/// s.push_str("héllo");
+/// s.push('!');
+/// assert_eq!(s.len(), 7);
+/// assert_eq!(s.to_uppercase(), "HÉLLO!");
+/// assert!(s.try_push_str("!!").is_err());
+/// ```
+#[derive(Clone, Copy)]
+pub struct ArrayString<A: Array<Item = u8>> {
+  vec: ArrayVec<A>,
+}
+
+impl<A: Array<Item = u8>> Default for ArrayString<A> {
+  #[inline]
+  fn default() -> Self {
+    Self { vec: ArrayVec::default() }
+  }
+}
+
+impl<A: Array<Item = u8>> Deref for ArrayString<A> {
+  type Target = str;
+  #[inline(always)]
+  fn deref(&self) -> &Self::Target {
+    from_utf8(self.vec.as_slice())
+      .expect("ArrayString: the UTF-8 invariant was broken")
+  }
+}
+
+impl<A: Array<Item = u8>> DerefMut for ArrayString<A> {
+  #[inline(always)]
+  fn deref_mut(&mut self) -> &mut Self::Target {
+    from_utf8_mut(self.vec.as_mut_slice())
+      .expect("ArrayString: the UTF-8 invariant was broken")
+  }
+}
+
+impl<A: Array<Item = u8>> ArrayString<A> {
+  /// Gives the bytes in use.
+  #[inline(always)]
+  #[must_use]
+  pub fn as_bytes(&self) -> &[u8] {
+    self.vec.as_slice()
+  }
+
+  /// Helper for getting the mut `str`.
+  #[inline(always)]
+  #[must_use]
+  pub fn as_mut_str(&mut self) -> &mut str {
+    self.deref_mut()
+  }
+
+  /// Helper for getting the shared `str`.
+  #[inline(always)]
+  #[must_use]
+  pub fn as_str(&self) -> &str {
+    self.deref()
+  }
+
+  /// The capacity of the `ArrayString` (in bytes).
+  ///
+  /// This is fixed based on the array type.
+  #[inline(always)]
+  #[must_use]
+  pub fn capacity(&self) -> usize {
+    A::CAPACITY
+  }
+
+  /// Removes all of the text.
+  #[inline(always)]
+  pub fn clear(&mut self) {
+    self.vec.clear()
+  }
+
+  /// Wraps up a byte vec, if the bytes in use are valid UTF-8.
+  ///
+  /// ## Failure
+  ///
+  /// If the bytes aren't UTF-8 you get the vec back in the `Err`.
+  #[inline]
+  pub fn from_utf8(vec: ArrayVec<A>) -> Result<Self, ArrayVec<A>> {
+    if from_utf8(vec.as_slice()).is_ok() {
+      Ok(Self { vec })
+    } else {
+      Err(vec)
+    }
+  }
+
+  /// Inserts a `char` at the byte index given, moving all following text
+  /// forward.
+  ///
+  /// ## Panics
+  /// * If `index` > `len` or isn't on a `char` boundary.
+  /// * If the length would overflow the capacity.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut s: ArrayString<[u8; 8]> = "ac".parse().unwrap();
+  /// s.insert(1, 'b');
+  /// assert_eq!(s, "abc");
+  /// ```
+  #[inline]
+  pub fn insert(&mut self, index: usize, ch: char) {
+    let mut buf = [0; 4];
+    self.insert_str(index, ch.encode_utf8(&mut buf))
+  }
+
+  /// Inserts a string slice at the byte index given, moving all following
+  /// text forward.
+  ///
+  /// ## Panics
+  /// * If `index` > `len` or isn't on a `char` boundary.
+  /// * If the length would overflow the capacity.
+  #[inline]
+  pub fn insert_str(&mut self, index: usize, s: &str) {
+    assert!(
+      self.is_char_boundary(index),
+      "ArrayString::insert_str> index {} is not a char boundary",
+      index
+    );
+    if self.try_push_str(s).is_err() {
+      panic!("ArrayString: overflow!")
+    }
+    self.vec[index..].rotate_right(s.len());
+  }
+
+  /// Unwraps the vec of bytes.
+  #[inline(always)]
+  #[must_use]
+  pub fn into_bytes(self) -> ArrayVec<A> {
+    self.vec
+  }
+
+  /// Makes a new, empty string.
+  #[inline(always)]
+  #[must_use]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Remove and return the last `char`, if there is one.
+  ///
+  /// ## Failure
+  /// * If the string is empty you get `None`.
+  #[inline]
+  pub fn pop(&mut self) -> Option<char> {
+    let ch = self.chars().next_back()?;
+    let new_len = self.len() - ch.len_utf8();
+    self.vec.truncate(new_len);
+    Some(ch)
+  }
+
+  /// Place a `char` onto the end of the string.
+  ///
+  /// See also, [`try_push`](ArrayString::try_push)
+  /// ## Panics
+  /// * If the length would overflow the capacity.
+  #[inline]
+  pub fn push(&mut self, ch: char) {
+    if self.try_push(ch).is_err() {
+      panic!("ArrayString: overflow!")
+    }
+  }
+
+  /// Place a string slice onto the end of the string.
+  ///
+  /// See also, [`try_push_str`](ArrayString::try_push_str)
+  /// ## Panics
+  /// * If the length would overflow the capacity.
+  #[inline]
+  pub fn push_str(&mut self, s: &str) {
+    if self.try_push_str(s).is_err() {
+      panic!("ArrayString: overflow!")
+    }
+  }
+
+  /// Removes the `char` at the byte index given, moving all following text
+  /// back.
+  ///
+  /// ## Panics
+  /// * If `index` isn't the start of a `char` in the string.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut s: ArrayString<[u8; 8]> = "añb".parse().unwrap();
+  /// assert_eq!(s.remove(1), 'ñ');
+  /// assert_eq!(s, "ab");
+  /// ```
+  #[inline]
+  pub fn remove(&mut self, index: usize) -> char {
+    let ch = match self[index..].chars().next() {
+      Some(ch) => ch,
+      None => panic!(
+        "ArrayString::remove> index {} is out of bounds {}",
+        index,
+        self.len()
+      ),
+    };
+    let ch_len = ch.len_utf8();
+    self.vec[index..].rotate_left(ch_len);
+    let new_len = self.len() - ch_len;
+    self.vec.truncate(new_len);
+    ch
+  }
+
+  /// Reduces the string's length to the given byte length.
+  ///
+  /// If the string is already shorter than the input, nothing happens.
+  ///
+  /// ## Panics
+  /// * If `new_len` isn't on a `char` boundary.
+  #[inline]
+  pub fn truncate(&mut self, new_len: usize) {
+    if new_len < self.len() {
+      assert!(
+        self.is_char_boundary(new_len),
+        "ArrayString::truncate> new_len {} is not a char boundary",
+        new_len
+      );
+      self.vec.truncate(new_len)
+    }
+  }
+
+  /// Builds a string from format arguments.
+  ///
+  /// Usually you'd call this via the
+  /// [`array_format!`](crate::array_format!) macro.
+  ///
+  /// ## Failure
+  ///
+  /// If the output doesn't fit you get as much as did fit (cut at a `char`
+  /// boundary) in the `Err`.
+  #[inline]
+  pub fn try_from_fmt(args: core::fmt::Arguments<'_>) -> Result<Self, Self> {
+    let mut out = Self::default();
+    let mut w = TruncatingWriter { out: &mut out };
+    if w.write_fmt(args).is_ok() {
+      Ok(out)
+    } else {
+      Err(out)
+    }
+  }
+
+  /// Pushes a `char` if there's room.
+  ///
+  /// ## Failure
+  ///
+  /// If there's not enough capacity the string is unchanged, and you get the
+  /// `char` back in the `Err`.
+  #[inline]
+  pub fn try_push(&mut self, ch: char) -> Result<(), char> {
+    let mut buf = [0; 4];
+    match self.try_push_str(ch.encode_utf8(&mut buf)) {
+      Ok(()) => Ok(()),
+      Err(_) => Err(ch),
+    }
+  }
+
+  /// Pushes a string slice if there's room for all of it.
+  ///
+  /// ## Failure
+  ///
+  /// If there's not enough capacity the string is unchanged, and you get the
+  /// input back in the `Err`.
+  #[inline]
+  pub fn try_push_str<'s>(&mut self, s: &'s str) -> Result<(), &'s str> {
+    if s.len() <= A::CAPACITY - self.len() {
+      self.vec.extend_from_slice(s.as_bytes());
+      Ok(())
+    } else {
+      Err(s)
+    }
+  }
+}
+
+/// Pushes as much as fits, then reports an error.
+struct TruncatingWriter<'s, A: Array<Item = u8>> {
+  out: &'s mut ArrayString<A>,
+}
+impl<'s, A: Array<Item = u8>> Write for TruncatingWriter<'s, A> {
+  #[inline]
+  fn write_str(&mut self, s: &str) -> core::fmt::Result {
+    if self.out.try_push_str(s).is_ok() {
+      Ok(())
+    } else {
+      let mut end = A::CAPACITY - self.out.len();
+      while !s.is_char_boundary(end) {
+        end -= 1;
+      }
+      self.out.push_str(&s[..end]);
+      Err(core::fmt::Error)
+    }
+  }
+}
+
+impl<A: Array<Item = u8>> Write for ArrayString<A> {
+  /// Errors (and leaves the string unchanged) if `s` doesn't fit.
+  #[inline]
+  fn write_str(&mut self, s: &str) -> core::fmt::Result {
+    self.try_push_str(s).map_err(|_| core::fmt::Error)
+  }
+  #[inline]
+  fn write_char(&mut self, c: char) -> core::fmt::Result {
+    self.try_push(c).map_err(|_| core::fmt::Error)
+  }
+}
+
+impl<A: Array<Item = u8>> FromStr for ArrayString<A> {
+  /// The only way to fail is by the input not fitting.
+  type Err = core::fmt::Error;
+  #[inline]
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let mut out = Self::default();
+    out.write_str(s)?;
+    Ok(out)
+  }
+}
+
+impl<A: Array<Item = u8>> AsRef<str> for ArrayString<A> {
+  #[inline(always)]
+  fn as_ref(&self) -> &str {
+    self.as_str()
+  }
+}
+
+impl<A: Array<Item = u8>> AsRef<[u8]> for ArrayString<A> {
+  #[inline(always)]
+  fn as_ref(&self) -> &[u8] {
+    self.as_bytes()
+  }
+}
+
+impl<A: Array<Item = u8>> Borrow<str> for ArrayString<A> {
+  #[inline(always)]
+  fn borrow(&self) -> &str {
+    self.as_str()
+  }
+}
+
+impl<A: Array<Item = u8>> Extend<char> for ArrayString<A> {
+  #[inline]
+  fn extend<T: IntoIterator<Item = char>>(&mut self, iter: T) {
+    for ch in iter {
+      self.push(ch)
+    }
+  }
+}
+
+impl<'a, A: Array<Item = u8>> Extend<&'a str> for ArrayString<A> {
+  #[inline]
+  fn extend<T: IntoIterator<Item = &'a str>>(&mut self, iter: T) {
+    for s in iter {
+      self.push_str(s)
+    }
+  }
+}
+
+impl<A: Array<Item = u8>> FromIterator<char> for ArrayString<A> {
+  #[inline]
+  fn from_iter<T: IntoIterator<Item = char>>(iter: T) -> Self {
+    let mut out = Self::default();
+    out.extend(iter);
+    out
+  }
+}
+
+impl<A: Array<Item = u8>> Hash for ArrayString<A> {
+  #[inline]
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.as_str().hash(state)
+  }
+}
+
+impl<A: Array<Item = u8>> PartialEq for ArrayString<A> {
+  #[inline]
+  fn eq(&self, other: &Self) -> bool {
+    self.as_bytes() == other.as_bytes()
+  }
+}
+impl<A: Array<Item = u8>> Eq for ArrayString<A> {}
+
+impl<A: Array<Item = u8>> PartialOrd for ArrayString<A> {
+  #[inline]
+  fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+    Some(self.cmp(other))
+  }
+}
+impl<A: Array<Item = u8>> Ord for ArrayString<A> {
+  #[inline]
+  fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+    self.as_bytes().cmp(other.as_bytes())
+  }
+}
+
+impl<A: Array<Item = u8>> PartialEq<str> for ArrayString<A> {
+  #[inline]
+  fn eq(&self, other: &str) -> bool {
+    self.as_bytes() == other.as_bytes()
+  }
+}
+
+impl<A: Array<Item = u8>> PartialEq<&str> for ArrayString<A> {
+  #[inline]
+  fn eq(&self, other: &&str) -> bool {
+    self.as_bytes() == other.as_bytes()
+  }
+}
+
+// //
+// Formatting impls
+// //
+
+impl<A: Array<Item = u8>> Debug for ArrayString<A> {
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    Debug::fmt(self.as_str(), f)
+  }
+}
+
+impl<A: Array<Item = u8>> Display for ArrayString<A> {
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    Display::fmt(self.as_str(), f)
+  }
+}
@@ -1,49 +1,126 @@
 use super::*;
 
+#[cfg(feature = "serde")]
+use core::marker::PhantomData;
+#[cfg(feature = "serde")]
+use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
+#[cfg(feature = "serde")]
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, Zeroizing};
+
 /// Helper to make an `ArrayVec`.
 ///
 /// You specify the backing array type, and optionally give all the elements you
-/// want to initially place into the array.
-///
-/// As an unfortunate restriction, the backing array type must support `Default`
-/// for it to work with this macro.
+/// want to initially place into the array. Use `_` as the element type to have
+/// it inferred from the elements.
 ///
 /// ```rust
 /// use tinyvec::*;
-/// 
+///
 /// let empty_av = array_vec!([u8; 16]);
-/// 
+///
 /// let some_ints = array_vec!([i32; 4], 1, 2, 3);
+///
+/// let inferred = array_vec!([_; 4], 1_u16, 2, 3);
+/// assert_eq!(inferred.as_slice(), &[1_u16, 2, 3]);
+/// ```
+///
+/// You can also fill the vec with some number of clones of one element, like
+/// with `vec!`.
+///
+/// ```rust
+/// use tinyvec::*;
+///
+/// let zeroes = array_vec!([u8; 16] => 0; 10);
+/// assert_eq!(zeroes.as_slice(), &[0; 10]);
 /// ```
+///
+/// Giving more elements than the capacity panics. With the `rustc_1_61`
+/// feature it's a compile error instead.
+///
+/// With the `rustc_1_61` feature you can also put `const` in front of the type
+/// to build the vec in a const context (such as a `static`). That needs the
+/// elements to be `Copy` and [`ConstDefault`]. See
+/// [`from_array_prefix`](ArrayVec::from_array_prefix) for the details.
 #[macro_export]
 macro_rules! array_vec {
-  ($array_type:ty) => {
+  (const $array_type:ty $(, $elem:expr)* $(,)?) => {
+    $crate::ArrayVec::<$array_type>::from_array_prefix([$($elem),*])
+  };
+  ($array_type:ty => $elem:expr; $n:expr) => {
     {
-      let av: ArrayVec<$array_type> = Default::default();
+      let mut av: $crate::ArrayVec<$array_type> = $crate::ArrayVec::new();
+      av.resize($n, $elem);
       av
     }
   };
-  ($array_type:ty, $($elem:expr),*) => {
+  ($array_type:ty $(,)?) => {
+    $crate::ArrayVec::<$array_type>::new()
+  };
+  ($array_type:ty, $($elem:expr),* $(,)?) => {
     {
-      let mut av: ArrayVec<$array_type> = Default::default();
+      let mut av: $crate::ArrayVec<$array_type> = $crate::ArrayVec::new();
+      $crate::__tinyvec_check_len!(av, $($elem),*);
       $( av.push($elem); )*
       av
     }
   };
 }
 
+/// Counts the expressions given, as a `usize` constant.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tinyvec_count {
+  (@one $_elem:expr) => {
+    1_usize
+  };
+  ($($elem:expr),*) => {
+    0_usize $(+ $crate::__tinyvec_count!(@one $elem))*
+  };
+}
+
+/// Checks at compile time that the elements fit in the vec.
+#[cfg(feature = "rustc_1_61")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tinyvec_check_len {
+  ($av:ident, $($elem:expr),*) => {
+    $av.__assert_len_fits::<{ $crate::__tinyvec_count!($($elem),*) }>()
+  };
+}
+
+/// Checks at compile time that the elements fit in the vec.
+///
+/// This needs the `rustc_1_61` feature, otherwise pushing the elements panics.
+#[cfg(not(feature = "rustc_1_61"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tinyvec_check_len {
+  ($av:ident, $($elem:expr),*) => {
+    ()
+  };
+}
+
 /// An array-backed vector-like data structure.
 ///
 /// * Fixed capacity (based on array size)
 /// * Variable length
 /// * All of the array memory is always initialized.
 #[repr(C)]
-#[derive(Clone, Copy, Default)]
+#[derive(Clone, Copy)]
 pub struct ArrayVec<A: Array> {
   len: usize,
   data: A,
 }
 
+impl<A: Array> Default for ArrayVec<A> {
+  #[inline]
+  fn default() -> Self {
+    Self { len: 0, data: A::default_array() }
+  }
+}
+
 impl<A: Array> Deref for ArrayVec<A> {
   type Target = [A::Item];
   #[inline(always)]
@@ -138,8 +215,15 @@ impl<A: Array> ArrayVec<A> {
     self.truncate(0)
   }
 
-  /// De-duplicates the vec.
-  #[cfg(feature = "nightly_slice_partition_dedup")]
+  /// De-duplicates the vec, removing consecutive repeated elements.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut av = array_vec!([i32; 8], 1, 1, 2, 3, 3, 3, 1);
+  /// av.dedup();
+  /// assert_eq!(av.as_slice(), &[1, 2, 3, 1][..]);
+  /// ```
   #[inline(always)]
   pub fn dedup(&mut self)
   where
@@ -149,21 +233,31 @@ impl<A: Array> ArrayVec<A> {
   }
 
   /// De-duplicates the vec according to the predicate given.
-  #[cfg(feature = "nightly_slice_partition_dedup")]
-  #[inline(always)]
-  pub fn dedup_by<F>(&mut self, same_bucket: F)
+  ///
+  /// The predicate gets each element along with the last element that was
+  /// kept before it, and the element is removed if it returns `true`. This
+  /// takes one pass over the vec, and if the predicate panics the vec is left
+  /// with the elements kept so far followed by the ones not yet looked at.
+  #[inline]
+  pub fn dedup_by<F>(&mut self, mut same_bucket: F)
   where
     F: FnMut(&mut A::Item, &mut A::Item) -> bool,
   {
-    let len = {
-      let (dedup, _) = self.as_mut_slice().partition_dedup_by(same_bucket);
-      dedup.len()
-    };
-    self.truncate(len);
+    if self.len <= 1 {
+      return;
+    }
+    let mut gap = ArrayVecGap { parent: self, write: 1, read: 1 };
+    while gap.read < gap.parent.len {
+      let (kept, rest) = gap.parent.as_mut_slice().split_at_mut(gap.read);
+      if !same_bucket(&mut rest[0], &mut kept[gap.write - 1]) {
+        gap.parent.as_mut_slice().swap(gap.write, gap.read);
+        gap.write += 1;
+      }
+      gap.read += 1;
+    }
   }
 
   /// De-duplicates the vec according to the key selector given.
-  #[cfg(feature = "nightly_slice_partition_dedup")]
   #[inline(always)]
   pub fn dedup_by_key<F, K>(&mut self, mut key: F)
   where
@@ -219,24 +313,125 @@ impl<A: Array> ArrayVec<A> {
       end,
       self.len
     );
-    ArrayVecDrain {
-      parent: self,
-      target_index: start,
-      target_count: end - start,
-    }
+    ArrayVecDrain { parent: self, start, front: start, back: end, end }
   }
 
-  // LATER(Vec): drain_filter #nightly https://github.com/rust-lang/rust/issues/43244
-
   /// Clone each element of the slice into this vec.
+  ///
+  /// The capacity is checked once up front, and then the whole slice is
+  /// cloned over in one go (which is a plain copy for `Copy` types).
+  ///
+  /// ## Panics
+  /// * If the length of the vec would overflow the capacity.
   #[inline]
   pub fn extend_from_slice(&mut self, sli: &[A::Item])
   where
     A::Item: Clone,
   {
-    for i in sli {
-      self.push(i.clone())
+    let new_len = self.len + sli.len();
+    if new_len > A::CAPACITY {
+      panic!(
+        "ArrayVec::extend_from_slice> total length {} exceeds capacity {}!",
+        new_len,
+        A::CAPACITY
+      )
     }
+    self.data.slice_mut()[self.len..new_len].clone_from_slice(sli);
+    self.len = new_len;
+  }
+
+  /// Creates an iterator that removes and yields the elements in the range
+  /// for which the predicate returns `true`.
+  ///
+  /// Elements for which the predicate returns `false` stay in the vec, in
+  /// their original order. The whole thing takes one pass over the vec, even
+  /// if the iterator is dropped early (any elements it didn't get to are
+  /// kept).
+  ///
+  /// ## Panics
+  /// * If the start is greater than the end
+  /// * If the end is past the edge of the vec.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut av = array_vec!([i32; 8], 1, 2, 3, 4, 5, 6);
+  /// let evens: Vec<i32> = av.extract_if(.., |x| *x % 2 == 0).collect();
+  /// assert_eq!(evens, vec![2, 4, 6]);
+  /// assert_eq!(av.as_slice(), &[1, 3, 5][..]);
+  /// ```
+  #[inline]
+  pub fn extract_if<R, F>(
+    &mut self,
+    range: R,
+    pred: F,
+  ) -> ArrayVecExtractIf<'_, A, F>
+  where
+    R: RangeBounds<usize>,
+    F: FnMut(&mut A::Item) -> bool,
+  {
+    use core::ops::Bound;
+    let start = match range.start_bound() {
+      Bound::Included(x) => *x,
+      Bound::Excluded(x) => x + 1,
+      Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+      Bound::Included(x) => x + 1,
+      Bound::Excluded(x) => *x,
+      Bound::Unbounded => self.len,
+    };
+    assert!(
+      start <= end,
+      "ArrayVec::extract_if> Illegal range, {} to {}",
+      start,
+      end
+    );
+    assert!(
+      end <= self.len,
+      "ArrayVec::extract_if> Range ends at {} but length is only {}!",
+      end,
+      self.len
+    );
+    ArrayVecExtractIf { parent: self, write: start, index: start, end, pred }
+  }
+
+  /// Wraps up an array as an empty vec.
+  ///
+  /// The array's elements are only spare space, they're not part of the vec.
+  /// With the `rustc_1_61` feature this is a `const fn`.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let av = ArrayVec::from_array_empty([0_u8; 10]);
+  /// assert!(av.is_empty());
+  /// assert_eq!(av.capacity(), 10);
+  /// ```
+  #[cfg(not(feature = "rustc_1_61"))]
+  #[inline(always)]
+  #[must_use]
+  pub fn from_array_empty(data: A) -> Self {
+    Self { data, len: 0 }
+  }
+
+  /// Wraps up an array as an empty vec.
+  ///
+  /// The array's elements are only spare space, they're not part of the vec.
+  /// With the `rustc_1_61` feature this is a `const fn`.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// static EMPTY: ArrayVec<[u8; 10]> = ArrayVec::from_array_empty([0; 10]);
+  /// assert!(EMPTY.is_empty());
+  /// assert_eq!(EMPTY.capacity(), 10);
+  /// ```
+  #[cfg(feature = "rustc_1_61")]
+  #[inline(always)]
+  #[must_use]
+  pub const fn from_array_empty(data: A) -> Self {
+    Self { data, len: 0 }
   }
 
   /// Wraps up an array and uses the given length as the initial length.
@@ -246,6 +441,7 @@ impl<A: Array> ArrayVec<A> {
   /// ## Panics
   ///
   /// The length must be less than or equal to the capacity of the array.
+  #[cfg(not(feature = "rustc_1_61"))]
   #[inline]
   #[must_use]
   #[allow(clippy::match_wild_err_arm)]
@@ -258,6 +454,34 @@ impl<A: Array> ArrayVec<A> {
     }
   }
 
+  /// Wraps up an array and uses the given length as the initial length.
+  ///
+  /// Note that the `From` impl for arrays assumes the full length is used.
+  /// With the `rustc_1_61` feature this is a `const fn`, so a bad length in a
+  /// const context is a compile error.
+  ///
+  /// ## Panics
+  ///
+  /// The length must be less than or equal to the capacity of the array.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// const PRIMES: ArrayVec<[u8; 8]> =
+  ///   ArrayVec::from_array_len([2, 3, 5, 7, 0, 0, 0, 0], 4);
+  /// assert_eq!(PRIMES.as_slice(), &[2, 3, 5, 7]);
+  /// ```
+  #[cfg(feature = "rustc_1_61")]
+  #[inline]
+  #[must_use]
+  pub const fn from_array_len(data: A, len: usize) -> Self {
+    assert!(
+      len <= A::CAPACITY,
+      "ArrayVec::from_array_len> length exceeds capacity!"
+    );
+    Self { data, len }
+  }
+
   /// Inserts an item at the position given, moving all following elements +1
   /// index.
   ///
@@ -275,26 +499,59 @@ impl<A: Array> ArrayVec<A> {
   /// ```
   #[inline]
   pub fn insert(&mut self, index: usize, item: A::Item) {
-    use core::cmp::Ordering;
-    match index.cmp(&self.len) {
-      Ordering::Less => {
-        let targets: &mut [A::Item] = &mut self.as_mut_slice()[index..];
-        let mut temp = item;
-        for target in targets.iter_mut() {
-          temp = replace(target, temp);
-        }
-        self.push(temp);
-      }
-      Ordering::Equal => {
-        self.push(item);
-      }
-      Ordering::Greater => {
-        panic!(
-          "ArrayVec::insert> index {} is out of bounds {}",
-          index, self.len
-        );
-      }
+    if index > self.len {
+      panic!("ArrayVec::insert> index {} is out of bounds {}", index, self.len);
+    }
+    self.push(item);
+    self.as_mut_slice()[index..].rotate_right(1);
+  }
+
+  /// Clones a slice into the vec at the position given, moving all following
+  /// elements up by the length of the slice.
+  ///
+  /// The following elements are only moved once, no matter how long the
+  /// slice is.
+  ///
+  /// ## Panics
+  /// * If `index` > `len`
+  /// * If the length of the vec would overflow the capacity.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut av = array_vec!([u8; 8], 1, 2, 3);
+  /// av.insert_from_slice(1, &[7, 8, 9]);
+  /// assert_eq!(av.as_slice(), &[1, 7, 8, 9, 2, 3][..]);
+  /// ```
+  #[inline]
+  pub fn insert_from_slice(&mut self, index: usize, sli: &[A::Item])
+  where
+    A::Item: Clone,
+  {
+    if index > self.len {
+      panic!(
+        "ArrayVec::insert_from_slice> index {} is out of bounds {}",
+        index, self.len
+      );
     }
+    self.extend_from_slice(sli);
+    self.as_mut_slice()[index..].rotate_right(sli.len());
+  }
+
+  /// Unwraps the backing array.
+  ///
+  /// This is the whole array, including the spare capacity past the length.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let av = array_vec!([i32; 4], 1, 2);
+  /// assert_eq!(av.into_inner(), [1, 2, 0, 0]);
+  /// ```
+  #[inline(always)]
+  #[must_use]
+  pub fn into_inner(self) -> A {
+    self.data
   }
 
   /// If the vec is empty.
@@ -314,10 +571,7 @@ impl<A: Array> ArrayVec<A> {
   /// Makes a new, empty vec.
   #[inline(always)]
   #[must_use]
-  pub fn new() -> Self
-  where
-    A: Default,
-  {
+  pub fn new() -> Self {
     Self::default()
   }
 
@@ -367,13 +621,30 @@ impl<A: Array> ArrayVec<A> {
   /// ```
   #[inline]
   pub fn remove(&mut self, index: usize) -> A::Item {
-    let targets: &mut [A::Item] = &mut self.deref_mut()[index..];
-    let mut spare = A::Item::default();
-    for target in targets.iter_mut().rev() {
-      spare = replace(target, spare);
+    if index >= self.len {
+      panic!("ArrayVec::remove> index {} is out of bounds {}", index, self.len);
     }
-    self.len -= 1;
-    spare
+    self.as_mut_slice()[index..].rotate_left(1);
+    self.pop().unwrap()
+  }
+
+  /// Removes all the elements in the range, moving all following elements
+  /// down to fill the gap.
+  ///
+  /// ## Panics
+  /// * If the start is greater than the end
+  /// * If the end is past the edge of the vec.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut av = array_vec!([i32; 6], 1, 2, 3, 4, 5);
+  /// av.remove_range(1..4);
+  /// assert_eq!(av.as_slice(), &[1, 5][..]);
+  /// ```
+  #[inline]
+  pub fn remove_range<R: RangeBounds<usize>>(&mut self, range: R) {
+    self.drain(range);
   }
 
   // NIGHTLY: remove_item, https://github.com/rust-lang/rust/issues/40062
@@ -466,17 +737,92 @@ impl<A: Array> ArrayVec<A> {
   /// ```
   #[inline]
   pub fn retain<F: FnMut(&A::Item) -> bool>(&mut self, mut acceptable: F) {
-    let mut i = 0;
-    while i < self.len {
-      if !acceptable(&self[i]) {
-        self.remove(i);
+    self.retain_mut(|x| acceptable(x))
+  }
+
+  /// Walk the vec and keep only the elements that pass the predicate given,
+  /// which can also modify them.
+  ///
+  /// This takes one pass over the vec. If the predicate panics, the elements
+  /// it hasn't looked at yet are kept.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use tinyvec::*;
+  ///
+  /// let mut av = array_vec!([i32; 10], 1, 2, 3, 4);
+  /// av.retain_mut(|x| {
+  ///   *x *= 10;
+  ///   *x > 20
+  /// });
+  /// assert_eq!(av.as_slice(), &[30, 40][..]);
+  /// ```
+  #[inline]
+  pub fn retain_mut<F: FnMut(&mut A::Item) -> bool>(
+    &mut self,
+    mut acceptable: F,
+  ) {
+    self.extract_if(.., |x| !acceptable(x)).for_each(drop)
+  }
+
+  /// Replaces the elements in the range with the elements of `replacement`,
+  /// giving back the removed elements.
+  ///
+  /// Unlike `Vec::splice` the replacement is done right away, and the
+  /// returned iterator owns the removed elements. The replacement is
+  /// collected before the vec is touched, and then both the removal and the
+  /// insertion take one pass over the tail of the vec.
+  ///
+  /// See also, [`try_splice`](ArrayVec::try_splice)
+  ///
+  /// ## Panics
+  /// * If the start is greater than the end
+  /// * If the end is past the edge of the vec.
+  /// * If the length of the vec would overflow the capacity.
+  ///
+  /// The vec is unchanged if any of these panic.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut av = array_vec!([i32; 6], 1, 2, 3, 4);
+  /// let removed: Vec<i32> = av.splice(1..3, vec![7, 8, 9]).collect();
+  /// assert_eq!(removed, vec![2, 3]);
+  /// assert_eq!(av.as_slice(), &[1, 7, 8, 9, 4][..]);
+  /// ```
+  #[inline]
+  pub fn splice<R, I>(
+    &mut self,
+    range: R,
+    replacement: I,
+  ) -> ArrayVecIterator<A>
+  where
+    R: RangeBounds<usize>,
+    I: IntoIterator<Item = A::Item>,
+  {
+    let drain = self.drain(range);
+    let (start, end) = (drain.start, drain.end);
+    drain.keep_rest();
+    let room = A::CAPACITY - (self.len - (end - start));
+    let mut incoming = Self::default();
+    for item in replacement {
+      if incoming.len == room {
+        panic!(
+          "ArrayVec::splice> the replacement has more than the {} elements \
+           that fit",
+          room
+        );
       }
-      i += 1;
+      incoming.push(item);
     }
+    let removed: Self = self.drain(start..end).collect();
+    let added = incoming.len;
+    self.extend(incoming);
+    self.as_mut_slice()[start..].rotate_right(added);
+    removed.into_iter()
   }
 
-  // LATER(Vec): splice
-
   /// Splits the collection at the point given.
   ///
   /// * `[0, at)` stays in this vec
@@ -495,11 +841,7 @@ impl<A: Array> ArrayVec<A> {
   /// assert_eq!(av2.as_slice(), &[2, 3][..]);
   /// ```
   #[inline]
-  pub fn split_off(&mut self, at: usize) -> Self
-  where
-    Self: Default,
-  {
-    // FIXME: should this just use drain into the output?
+  pub fn split_off(&mut self, at: usize) -> Self {
     if at > self.len {
       panic!(
         "ArrayVec::split_off> at value {} exceeds length of {}",
@@ -508,11 +850,9 @@ impl<A: Array> ArrayVec<A> {
     }
     let mut new = Self::default();
     let moves = &mut self.as_mut_slice()[at..];
-    let targets = new.data.slice_mut();
-    for (m, t) in moves.iter_mut().zip(targets) {
-      replace(t, replace(m, A::Item::default()));
-    }
-    new.len = self.len - at;
+    let split_len = moves.len();
+    new.data.slice_mut()[..split_len].swap_with_slice(moves);
+    new.len = split_len;
     self.len = at;
     new
   }
@@ -554,7 +894,7 @@ impl<A: Array> ArrayVec<A> {
   /// If the vec is already shorter than the input, nothing happens.
   #[inline]
   pub fn truncate(&mut self, new_len: usize) {
-    if needs_drop::<A::Item>() {
+    if needs_drop::<A::Item>() || cfg!(feature = "wipe_vacated") {
       while self.len > new_len {
         self.pop();
       }
@@ -563,73 +903,550 @@ impl<A: Array> ArrayVec<A> {
     }
   }
 
-  /// Wraps an array, using the given length as the starting length.
-  ///
-  /// If you want to use the whole length of the array, you can just use the
-  /// `From` impl.
+  /// Move all values from `other` into this vec, if they'll all fit.
   ///
   /// ## Failure
   ///
-  /// If the given length is greater than the capacity of the array this will
-  /// error, and you'll get the array back in the `Err`.
+  /// If there's not room for all of `other` then neither vec is changed.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut av = array_vec!([i32; 4], 1, 2);
+  /// let mut av2 = array_vec!([i32; 4], 3, 4, 5);
+  /// assert!(av.try_append(&mut av2).is_err());
+  /// av2.pop();
+  /// assert!(av.try_append(&mut av2).is_ok());
+  /// assert_eq!(av.as_slice(), &[1, 2, 3, 4][..]);
+  /// ```
   #[inline]
-  pub fn try_from_array_len(data: A, len: usize) -> Result<Self, A> {
-    if len <= A::CAPACITY {
-      Ok(Self { data, len })
+  pub fn try_append(
+    &mut self,
+    other: &mut Self,
+  ) -> Result<(), CapacityError<()>> {
+    if other.len <= A::CAPACITY - self.len {
+      self.append(other);
+      Ok(())
     } else {
-      Err(data)
+      Err(CapacityError::new(()))
     }
   }
 
-  /// Pushes an item if there's room.
+  /// Creates a draining iterator, if the range is valid for this vec.
+  ///
+  /// This is [`drain`](ArrayVec::drain) without the panics.
   ///
   /// ## Failure
+  /// * If the start is greater than the end, or the end is past the edge of
+  ///   the vec, you get `None` and the vec is unchanged.
   ///
-  /// If there's no more capacity the vec is unchanged, and you get the item
-  /// back in the `Err`.
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut av = array_vec!([i32; 4], 1, 2, 3);
+  /// assert!(av.try_drain(2..5).is_none());
+  /// let av2: ArrayVec<[i32; 4]> = av.try_drain(1..).unwrap().collect();
+  /// assert_eq!(av.as_slice(), &[1][..]);
+  /// assert_eq!(av2.as_slice(), &[2, 3][..]);
+  /// ```
   #[inline]
-  pub fn try_push(&mut self, val: A::Item) -> Result<(), A::Item> {
-    if self.len < A::CAPACITY {
-      replace(&mut self.data.slice_mut()[self.len], val);
-      self.len += 1;
-      Ok(())
-    } else {
+  pub fn try_drain<R: RangeBounds<usize>>(
+    &mut self,
+    range: R,
+  ) -> Option<ArrayVecDrain<'_, A>> {
+    use core::ops::Bound;
+    let start = match range.start_bound() {
+      Bound::Included(x) => *x,
+      Bound::Excluded(x) => x.checked_add(1)?,
+      Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+      Bound::Included(x) => x.checked_add(1)?,
+      Bound::Excluded(x) => *x,
+      Bound::Unbounded => self.len,
+    };
+    if start <= end && end <= self.len {
+      Some(ArrayVecDrain { parent: self, start, front: start, back: end, end })
+    } else {
+      None
+    }
+  }
+
+  /// Pushes every item of the iterator, for as long as there's room.
+  ///
+  /// ## Failure
+  ///
+  /// If the vec fills up before the iterator runs out, you get back an
+  /// iterator of everything that didn't fit (starting with the item that
+  /// was rejected) in the `Err`.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut av = array_vec!([i32; 4], 1);
+  /// let rest = av.try_extend(2..7).unwrap_err().element();
+  /// assert_eq!(av.as_slice(), &[1, 2, 3, 4][..]);
+  /// assert_eq!(rest.collect::<Vec<_>>(), vec![5, 6]);
+  /// ```
+  #[inline]
+  #[allow(clippy::type_complexity)]
+  pub fn try_extend<I: IntoIterator<Item = A::Item>>(
+    &mut self,
+    iter: I,
+  ) -> Result<(), CapacityError<Chain<Once<A::Item>, I::IntoIter>>> {
+    let mut iter = iter.into_iter();
+    while let Some(item) = iter.next() {
+      if let Err(item) = self.try_push(item) {
+        return Err(CapacityError::new(once(item).chain(iter)));
+      }
+    }
+    Ok(())
+  }
+
+  /// Clone each element of the slice into this vec, if they'll all fit.
+  ///
+  /// ## Failure
+  ///
+  /// If there's not room for the whole slice the vec is unchanged, and you get
+  /// the slice back in the `Err`.
+  #[inline]
+  pub fn try_extend_from_slice<'s>(
+    &mut self,
+    sli: &'s [A::Item],
+  ) -> Result<(), CapacityError<&'s [A::Item]>>
+  where
+    A::Item: Clone,
+  {
+    if sli.len() <= A::CAPACITY - self.len {
+      self.extend_from_slice(sli);
+      Ok(())
+    } else {
+      Err(CapacityError::new(sli))
+    }
+  }
+
+  /// Wraps an array, using the given length as the starting length.
+  ///
+  /// If you want to use the whole length of the array, you can just use the
+  /// `From` impl.
+  ///
+  /// ## Failure
+  ///
+  /// If the given length is greater than the capacity of the array this will
+  /// error, and you'll get the array back in the `Err`.
+  #[inline]
+  pub fn try_from_array_len(data: A, len: usize) -> Result<Self, A> {
+    if len <= A::CAPACITY {
+      Ok(Self { data, len })
+    } else {
+      Err(data)
+    }
+  }
+
+  /// Collects an iterator into a new vec, if it all fits.
+  ///
+  /// ## Failure
+  ///
+  /// If the iterator has more items than the capacity, the ones that fit are
+  /// dropped and you get back an iterator of everything that didn't fit in
+  /// the `Err`.
+  #[inline]
+  #[allow(clippy::type_complexity)]
+  pub fn try_from_iter<I: IntoIterator<Item = A::Item>>(
+    iter: I,
+  ) -> Result<Self, CapacityError<Chain<Once<A::Item>, I::IntoIter>>> {
+    let mut av = Self::default();
+    av.try_extend(iter)?;
+    Ok(av)
+  }
+
+  /// Inserts an item at the position given, if there's room.
+  ///
+  /// ## Panics
+  /// * If `index` > `len`, the same as [`insert`](ArrayVec::insert). A bad
+  ///   index is a bug in the caller, not a capacity problem, so check it first
+  ///   if it comes from untrusted input.
+  ///
+  /// ## Failure
+  ///
+  /// If there's no more capacity the vec is unchanged, and you get the item
+  /// back in the `Err`.
+  #[inline]
+  pub fn try_insert(
+    &mut self,
+    index: usize,
+    item: A::Item,
+  ) -> Result<(), CapacityError<A::Item>> {
+    if index > self.len {
+      panic!(
+        "ArrayVec::try_insert> index {} is out of bounds {}",
+        index, self.len
+      );
+    }
+    if self.len < A::CAPACITY {
+      self.insert(index, item);
+      Ok(())
+    } else {
+      Err(CapacityError::new(item))
+    }
+  }
+
+  /// Pushes an item if there's room.
+  ///
+  /// ## Failure
+  ///
+  /// If there's no more capacity the vec is unchanged, and you get the item
+  /// back in the `Err`.
+  #[inline]
+  pub fn try_push(&mut self, val: A::Item) -> Result<(), A::Item> {
+    if self.len < A::CAPACITY {
+      replace(&mut self.data.slice_mut()[self.len], val);
+      self.len += 1;
+      Ok(())
+    } else {
       Err(val)
     }
   }
 
-  // LATER: try_insert ?
+  /// Remove an element, shifting all following elements down, if the index is
+  /// in bounds.
+  ///
+  /// ## Failure
+  /// * If the index is out of bounds you get `None` and the vec is unchanged.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut av = array_vec!([i32; 4], 1, 2, 3);
+  /// assert_eq!(av.try_remove(3), None);
+  /// assert_eq!(av.try_remove(1), Some(2));
+  /// assert_eq!(av.as_slice(), &[1, 3][..]);
+  /// ```
+  #[inline]
+  pub fn try_remove(&mut self, index: usize) -> Option<A::Item> {
+    if index < self.len {
+      Some(self.remove(index))
+    } else {
+      None
+    }
+  }
 
-  // LATER: try_remove ?
+  /// Resize the vec to the new length, if it fits.
+  ///
+  /// ## Failure
+  ///
+  /// If the new length is more than the capacity the vec is unchanged, and
+  /// you get the fill value back in the `Err`.
+  #[inline]
+  pub fn try_resize(
+    &mut self,
+    new_len: usize,
+    new_val: A::Item,
+  ) -> Result<(), CapacityError<A::Item>>
+  where
+    A::Item: Clone,
+  {
+    if new_len <= A::CAPACITY {
+      self.resize(new_len, new_val);
+      Ok(())
+    } else {
+      Err(CapacityError::new(new_val))
+    }
+  }
+
+  /// Resize the vec to the new length using a fill function, if it fits.
+  ///
+  /// ## Failure
+  ///
+  /// If the new length is more than the capacity the vec is unchanged, the
+  /// function is never called, and you get it back in the `Err`.
+  #[inline]
+  pub fn try_resize_with<F: FnMut() -> A::Item>(
+    &mut self,
+    new_len: usize,
+    f: F,
+  ) -> Result<(), CapacityError<F>> {
+    if new_len <= A::CAPACITY {
+      self.resize_with(new_len, f);
+      Ok(())
+    } else {
+      Err(CapacityError::new(f))
+    }
+  }
+
+  /// Replaces the elements in the range with the elements of `replacement`,
+  /// if the result will fit.
+  ///
+  /// This is [`splice`](ArrayVec::splice), but the replacement must know its
+  /// exact length so that the capacity can be checked before anything moves.
+  ///
+  /// ## Panics
+  /// * If the start is greater than the end
+  /// * If the end is past the edge of the vec.
+  ///
+  /// ## Failure
+  ///
+  /// If the new length would be more than the capacity the vec is unchanged,
+  /// and you get the replacement iterator back in the `Err`.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut av = array_vec!([i32; 4], 1, 2, 3);
+  /// assert!(av.try_splice(..1, vec![5, 6, 7]).is_err());
+  /// assert_eq!(av.try_splice(..1, vec![5, 6]).unwrap().next(), Some(1));
+  /// assert_eq!(av.as_slice(), &[5, 6, 2, 3][..]);
+  /// ```
+  #[inline]
+  pub fn try_splice<R, I>(
+    &mut self,
+    range: R,
+    replacement: I,
+  ) -> Result<ArrayVecIterator<A>, CapacityError<I::IntoIter>>
+  where
+    R: RangeBounds<usize>,
+    I: IntoIterator<Item = A::Item>,
+    I::IntoIter: ExactSizeIterator,
+  {
+    let replacement = replacement.into_iter();
+    let drain = self.drain(range);
+    let (start, end) = (drain.start, drain.end);
+    drain.keep_rest();
+    let new_len = self.len - (end - start);
+    if replacement.len() <= A::CAPACITY - new_len {
+      let count = replacement.len();
+      Ok(self.splice(start..end, replacement.take(count)))
+    } else {
+      Err(CapacityError::new(replacement))
+    }
+  }
+
+  /// Splits the collection at the point given, if it's in bounds.
+  ///
+  /// ## Failure
+  /// * If `at` > `len` you get `None` and the vec is unchanged.
+  #[inline]
+  pub fn try_split_off(&mut self, at: usize) -> Option<Self> {
+    if at <= self.len {
+      Some(self.split_off(at))
+    } else {
+      None
+    }
+  }
+
+  /// Remove an element, swapping the end of the vec into its place, if the
+  /// index is in bounds.
+  ///
+  /// ## Failure
+  /// * If the index is out of bounds you get `None` and the vec is unchanged.
+  #[inline]
+  pub fn try_swap_remove(&mut self, index: usize) -> Option<A::Item> {
+    if index < self.len {
+      Some(self.swap_remove(index))
+    } else {
+      None
+    }
+  }
 }
 
-/// Draining iterator for `ArrayVecDrain`
-/// 
-/// See [`ArrayVecDrain::drain`](ArrayVecDrain::<A>::drain)
+/// Closes up the gap left by `dedup_by`, even if the predicate panics.
+///
+/// The elements in `[write, read)` have been removed, the ones past `read`
+/// haven't been looked at yet. The removed elements are rotated to the end
+/// and then dropped, leaving `Default` values in their slots.
+struct ArrayVecGap<'p, A: Array> {
+  parent: &'p mut ArrayVec<A>,
+  write: usize,
+  read: usize,
+}
+impl<'p, A: Array> Drop for ArrayVecGap<'p, A> {
+  #[inline]
+  fn drop(&mut self) {
+    let removed = self.read - self.write;
+    if removed > 0 {
+      let old_len = self.parent.len;
+      self.parent.as_mut_slice()[self.write..].rotate_left(removed);
+      self.parent.len -= removed;
+      let new_len = self.parent.len;
+      for slot in &mut self.parent.data.slice_mut()[new_len..old_len] {
+        // `mem::take` needs Rust 1.40.
+        #[allow(clippy::mem_replace_with_default)]
+        drop(replace(slot, A::Item::default()));
+      }
+    }
+  }
+}
+
+/// Filtering iterator for `ArrayVec`
+///
+/// See [`ArrayVec::extract_if`](ArrayVec::<A>::extract_if)
+pub struct ArrayVecExtractIf<'p, A, F>
+where
+  A: Array,
+  F: FnMut(&mut A::Item) -> bool,
+{
+  parent: &'p mut ArrayVec<A>,
+  write: usize,
+  index: usize,
+  end: usize,
+  pred: F,
+}
+impl<'p, A, F> Iterator for ArrayVecExtractIf<'p, A, F>
+where
+  A: Array,
+  F: FnMut(&mut A::Item) -> bool,
+{
+  type Item = A::Item;
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    while self.index < self.end {
+      let i = self.index;
+      let targets = self.parent.as_mut_slice();
+      if (self.pred)(&mut targets[i]) {
+        self.index += 1;
+        // `mem::take` needs Rust 1.40.
+        #[allow(clippy::mem_replace_with_default)]
+        let out = replace(&mut targets[i], A::Item::default());
+        return Some(out);
+      }
+      targets.swap(self.write, i);
+      self.write += 1;
+      self.index += 1;
+    }
+    None
+  }
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (0, Some(self.end - self.index))
+  }
+}
+impl<'p, A, F> Drop for ArrayVecExtractIf<'p, A, F>
+where
+  A: Array,
+  F: FnMut(&mut A::Item) -> bool,
+{
+  #[inline]
+  fn drop(&mut self) {
+    // Only the holes left by yielded elements get closed up here, the
+    // predicate isn't called again.
+    let removed = self.index - self.write;
+    if removed > 0 {
+      self.parent.as_mut_slice()[self.write..].rotate_left(removed);
+      self.parent.len -= removed;
+    }
+  }
+}
+
+/// Draining iterator for `ArrayVec`
+///
+/// See [`ArrayVec::drain`](ArrayVec::<A>::drain)
 pub struct ArrayVecDrain<'p, A: Array> {
   parent: &'p mut ArrayVec<A>,
-  target_index: usize,
-  target_count: usize,
+  start: usize,
+  front: usize,
+  back: usize,
+  end: usize,
+}
+impl<'p, A: Array> ArrayVecDrain<'p, A> {
+  /// The elements that haven't been yielded yet, as a slice.
+  #[inline]
+  #[must_use]
+  pub fn as_slice(&self) -> &[A::Item] {
+    &self.parent[self.front..self.back]
+  }
+
+  /// Stops draining, and puts the elements that haven't been yielded yet
+  /// back into the vec where they were.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut v = array_vec!([i32; 4], 1, 2, 3, 4);
+  /// let mut d = v.drain(..);
+  /// assert_eq!(d.next(), Some(1));
+  /// assert_eq!(d.next_back(), Some(4));
+  /// d.keep_rest();
+  /// assert_eq!(v.as_slice(), &[2, 3][..]);
+  /// ```
+  #[inline]
+  pub fn keep_rest(mut self) {
+    self.close_gap();
+  }
+
+  /// Shifts the kept elements and the tail down over the yielded slots, all
+  /// in one pass, and leaves the drain empty.
+  #[inline]
+  fn close_gap(&mut self) {
+    let kept = self.back - self.front;
+    let removed = (self.end - self.start) - kept;
+    if removed > 0 {
+      let targets =
+        &mut self.parent.data.slice_mut()[..self.parent.len][self.start..];
+      targets.rotate_left(self.front - self.start);
+      targets[kept..].rotate_left(self.end - self.back);
+      self.parent.len -= removed;
+    }
+    self.front = self.start;
+    self.back = self.start;
+    self.end = self.start;
+  }
 }
-// GoodFirstIssue: this entire type is correct but slow.
-// NIGHTLY: vec_drain_as_slice, https://github.com/rust-lang/rust/issues/58957
 impl<'p, A: Array> Iterator for ArrayVecDrain<'p, A> {
   type Item = A::Item;
   #[inline]
   fn next(&mut self) -> Option<Self::Item> {
-    if self.target_count > 0 {
-      let out = self.parent.remove(self.target_index);
-      self.target_count -= 1;
+    if self.front < self.back {
+      // `mem::take` needs Rust 1.40.
+      #[allow(clippy::mem_replace_with_default)]
+      let out = replace(
+        &mut self.parent.data.slice_mut()[..self.parent.len][self.front],
+        A::Item::default(),
+      );
+      self.front += 1;
       Some(out)
     } else {
       None
     }
   }
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let s = self.back - self.front;
+    (s, Some(s))
+  }
 }
+impl<'p, A: Array> DoubleEndedIterator for ArrayVecDrain<'p, A> {
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    if self.front < self.back {
+      self.back -= 1;
+      // `mem::take` needs Rust 1.40.
+      #[allow(clippy::mem_replace_with_default)]
+      let out = replace(
+        &mut self.parent.data.slice_mut()[..self.parent.len][self.back],
+        A::Item::default(),
+      );
+      Some(out)
+    } else {
+      None
+    }
+  }
+}
+impl<'p, A: Array> ExactSizeIterator for ArrayVecDrain<'p, A> {}
+impl<'p, A: Array> FusedIterator for ArrayVecDrain<'p, A> {}
 impl<'p, A: Array> Drop for ArrayVecDrain<'p, A> {
   #[inline]
   fn drop(&mut self) {
-    for _ in self {}
+    for _ in self.by_ref() {}
+    self.close_gap();
+  }
+}
+impl<'p, A: Array> Debug for ArrayVecDrain<'p, A>
+where
+  A::Item: Debug,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    f.debug_tuple("ArrayVecDrain").field(&self.as_slice()).finish()
   }
 }
 
@@ -686,7 +1503,7 @@ impl<A: Array> From<A> for ArrayVec<A> {
   }
 }
 
-impl<A: Array + Default> FromIterator<A::Item> for ArrayVec<A> {
+impl<A: Array> FromIterator<A::Item> for ArrayVec<A> {
   #[inline]
   #[must_use]
   fn from_iter<T: IntoIterator<Item = A::Item>>(iter: T) -> Self {
@@ -698,12 +1515,108 @@ impl<A: Array + Default> FromIterator<A::Item> for ArrayVec<A> {
   }
 }
 
+/// Checks at compile time that `K` elements fit in the array type `A`.
+#[cfg(feature = "rustc_1_61")]
+struct LenFits<A, const K: usize>(core::marker::PhantomData<A>);
+
+#[cfg(feature = "rustc_1_61")]
+impl<A: Array, const K: usize> LenFits<A, K> {
+  const OK: () =
+    assert!(K <= A::CAPACITY, "array_vec!> more elements than the capacity!");
+}
+
+#[cfg(feature = "rustc_1_61")]
+impl<A: Array> ArrayVec<A> {
+  /// Used by `array_vec!` for its compile-time length check.
+  #[doc(hidden)]
+  #[inline(always)]
+  #[allow(clippy::let_unit_value)]
+  pub fn __assert_len_fits<const K: usize>(&self) {
+    let () = LenFits::<A, K>::OK;
+  }
+}
+
+/// Checks at compile time that `K` elements fit in a capacity of `N`.
+#[cfg(feature = "rustc_1_61")]
+struct PrefixFits<const K: usize, const N: usize>;
+
+#[cfg(feature = "rustc_1_61")]
+impl<const K: usize, const N: usize> PrefixFits<K, N> {
+  const OK: () = assert!(
+    K <= N,
+    "ArrayVec::from_array_prefix> the elements must fit in the capacity!"
+  );
+}
+
+#[cfg(feature = "rustc_1_61")]
+impl<T: Copy + ConstDefault, const N: usize> ArrayVec<[T; N]>
+where
+  [T; N]: Array<Item = T>,
+{
+  /// Makes a vec holding a copy of the elements of a (possibly shorter)
+  /// array.
+  ///
+  /// * Requires the `rustc_1_61` feature
+  ///
+  /// This is a `const fn`, and it's what `array_vec!(const ...)` uses. The
+  /// spare capacity is filled with [`ConstDefault::DEFAULT`], the same as the
+  /// spare capacity of any other vec holds the default value.
+  ///
+  /// ## Compile Errors
+  /// * If `K` is more than the capacity.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// static TABLE: ArrayVec<[u16; 8]> = array_vec!(const [u16; 8], 10, 20, 30);
+  /// assert_eq!(TABLE.as_slice(), &[10, 20, 30]);
+  /// assert_eq!(TABLE.capacity(), 8);
+  ///
+  /// const ALSO: ArrayVec<[u16; 8]> = ArrayVec::from_array_prefix([10, 20, 30]);
+  /// assert_eq!(TABLE, ALSO);
+  /// ```
+  ///
+  /// ```compile_fail
+  /// use tinyvec::*;
+  /// let av = array_vec!(const [u8; 2], 1, 2, 3);
+  /// ```
+  #[inline]
+  #[must_use]
+  #[allow(clippy::let_unit_value)]
+  pub const fn from_array_prefix<const K: usize>(prefix: [T; K]) -> Self {
+    let () = PrefixFits::<K, N>::OK;
+    let mut data = [T::DEFAULT; N];
+    let mut i = 0;
+    while i < K {
+      data[i] = prefix[i];
+      i += 1;
+    }
+    Self { data, len: K }
+  }
+}
+
 /// Iterator for consuming an `ArrayVec` and returning owned elements.
+#[derive(Clone)]
 pub struct ArrayVecIterator<A: Array> {
   base: usize,
   len: usize,
   data: A,
 }
+impl<A: Array> ArrayVecIterator<A> {
+  /// The elements that haven't been yielded yet, as a slice.
+  #[inline]
+  #[must_use]
+  pub fn as_slice(&self) -> &[A::Item] {
+    &self.data.slice()[self.base..self.len]
+  }
+
+  /// The elements that haven't been yielded yet, as a mutable slice.
+  #[inline]
+  #[must_use]
+  pub fn as_mut_slice(&mut self) -> &mut [A::Item] {
+    &mut self.data.slice_mut()[self.base..self.len]
+  }
+}
 impl<A: Array> Iterator for ArrayVecIterator<A> {
   type Item = A::Item;
   #[inline]
@@ -729,19 +1642,52 @@ impl<A: Array> Iterator for ArrayVecIterator<A> {
   }
   #[inline]
   fn last(mut self) -> Option<Self::Item> {
-    Some(replace(&mut self.data.slice_mut()[self.len], A::Item::default()))
+    self.next_back()
   }
   #[inline]
   fn nth(&mut self, n: usize) -> Option<A::Item> {
-    let i = self.base + (n - 1);
-    if i < self.len {
-      let out = replace(&mut self.data.slice_mut()[i], A::Item::default());
-      self.base = i + 1;
+    let skip = n.min(self.len - self.base);
+    for x in &mut self.data.slice_mut()[self.base..self.base + skip] {
+      *x = A::Item::default();
+    }
+    self.base += skip;
+    self.next()
+  }
+}
+impl<A: Array> DoubleEndedIterator for ArrayVecIterator<A> {
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    if self.base < self.len {
+      self.len -= 1;
+      // `mem::take` needs Rust 1.40.
+      #[allow(clippy::mem_replace_with_default)]
+      let out =
+        replace(&mut self.data.slice_mut()[self.len], A::Item::default());
       Some(out)
     } else {
       None
     }
   }
+  #[inline]
+  fn nth_back(&mut self, n: usize) -> Option<A::Item> {
+    let skip = n.min(self.len - self.base);
+    for x in &mut self.data.slice_mut()[self.len - skip..self.len] {
+      *x = A::Item::default();
+    }
+    self.len -= skip;
+    self.next_back()
+  }
+}
+impl<A: Array> ExactSizeIterator for ArrayVecIterator<A> {}
+impl<A: Array> FusedIterator for ArrayVecIterator<A> {}
+impl<A: Array> Debug for ArrayVecIterator<A>
+where
+  A::Item: Debug,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    f.debug_tuple("ArrayVecIterator").field(&self.as_slice()).finish()
+  }
 }
 
 impl<A: Array> IntoIterator for ArrayVec<A> {
@@ -984,3 +1930,162 @@ where
     write!(f, "]")
   }
 }
+
+#[cfg(feature = "serde")]
+impl<A: Array> Serialize for ArrayVec<A>
+where
+  A::Item: Serialize,
+{
+  /// Serializes as a sequence of the elements.
+  #[inline]
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut seq = serializer.serialize_seq(Some(self.len()))?;
+    for element in self.iter() {
+      seq.serialize_element(element)?;
+    }
+    seq.end()
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, A: Array> Deserialize<'de> for ArrayVec<A>
+where
+  A::Item: Deserialize<'de>,
+{
+  /// Deserializes from a sequence of elements.
+  ///
+  /// ## Failure
+  /// * If the sequence is longer than the capacity you get an invalid length
+  ///   error.
+  #[inline]
+  fn deserialize<D: Deserializer<'de>>(
+    deserializer: D,
+  ) -> Result<Self, D::Error> {
+    deserializer.deserialize_seq(ArrayVecVisitor(PhantomData))
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<A: Array<Item = u8>> ArrayVec<A> {
+  /// Serializes the vec as a byte string, for use with
+  /// `#[serde(serialize_with = "ArrayVec::serialize_bytes")]`.
+  ///
+  /// * Requires the `serde` feature
+  ///
+  /// Binary formats can store bytes much more compactly than a sequence of
+  /// `u8`.
+  #[inline]
+  pub fn serialize_bytes<S: Serializer>(
+    &self,
+    serializer: S,
+  ) -> Result<S::Ok, S::Error> {
+    serializer.serialize_bytes(self.as_slice())
+  }
+
+  /// Deserializes the vec from a byte string (or a sequence of `u8`), for use
+  /// with `#[serde(deserialize_with = "ArrayVec::deserialize_bytes")]`.
+  ///
+  /// * Requires the `serde` feature
+  ///
+  /// ## Failure
+  /// * If there are more bytes than the capacity you get an invalid length
+  ///   error.
+  #[inline]
+  pub fn deserialize_bytes<'de, D: Deserializer<'de>>(
+    deserializer: D,
+  ) -> Result<Self, D::Error> {
+    deserializer.deserialize_bytes(ArrayVecBytesVisitor(PhantomData))
+  }
+}
+
+#[cfg(feature = "serde")]
+struct ArrayVecVisitor<A>(PhantomData<A>);
+
+#[cfg(feature = "serde")]
+impl<'de, A: Array> Visitor<'de> for ArrayVecVisitor<A>
+where
+  A::Item: Deserialize<'de>,
+{
+  type Value = ArrayVec<A>;
+
+  fn expecting(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "a sequence of at most {} elements", A::CAPACITY)
+  }
+
+  fn visit_seq<S: SeqAccess<'de>>(
+    self,
+    mut seq: S,
+  ) -> Result<Self::Value, S::Error> {
+    if let Some(len) = seq.size_hint() {
+      if len > A::CAPACITY {
+        return Err(serde::de::Error::invalid_length(len, &self));
+      }
+    }
+    let mut out = ArrayVec::default();
+    while let Some(element) = seq.next_element()? {
+      if out.try_push(element).is_err() {
+        return Err(serde::de::Error::invalid_length(A::CAPACITY + 1, &self));
+      }
+    }
+    Ok(out)
+  }
+}
+
+#[cfg(feature = "serde")]
+struct ArrayVecBytesVisitor<A>(PhantomData<A>);
+
+#[cfg(feature = "serde")]
+impl<'de, A: Array<Item = u8>> Visitor<'de> for ArrayVecBytesVisitor<A> {
+  type Value = ArrayVec<A>;
+
+  fn expecting(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "at most {} bytes", A::CAPACITY)
+  }
+
+  fn visit_bytes<E: serde::de::Error>(
+    self,
+    v: &[u8],
+  ) -> Result<Self::Value, E> {
+    let mut out = ArrayVec::default();
+    match out.try_extend_from_slice(v) {
+      Ok(()) => Ok(out),
+      Err(_) => Err(E::invalid_length(v.len(), &self)),
+    }
+  }
+
+  fn visit_seq<S: SeqAccess<'de>>(
+    self,
+    seq: S,
+  ) -> Result<Self::Value, S::Error> {
+    ArrayVecVisitor(PhantomData).visit_seq(seq)
+  }
+}
+
+#[cfg(feature = "zeroize")]
+impl<A: Array> Zeroize for ArrayVec<A>
+where
+  A::Item: Zeroize,
+{
+  /// Zeroizes the whole backing array, including the spare capacity, and
+  /// then sets the length to 0.
+  #[inline]
+  fn zeroize(&mut self) {
+    for x in self.data.slice_mut() {
+      x.zeroize();
+    }
+    self.len.zeroize();
+  }
+}
+
+/// An `ArrayVec` that zeroizes all of its memory when it's dropped.
+///
+/// * Requires the `zeroize` feature
+///
+/// ```rust
+/// use tinyvec::*;
+/// let mut key = ZeroizingArrayVec::new(array_vec!([u8; 64]));
+/// key.extend_from_slice(b"hunter2");
+/// assert_eq!(key.len(), 7);
+/// ```
+#[cfg(feature = "zeroize")]
+pub type ZeroizingArrayVec<A> = Zeroizing<ArrayVec<A>>;
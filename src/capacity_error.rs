@@ -0,0 +1,64 @@
+use super::*;
+
+/// The error for when an operation would go past the capacity of a fixed
+/// capacity collection.
+///
+/// The element (or elements) that didn't fit are handed back to you inside
+/// the error, so nothing is lost. Use [`element`](CapacityError::element) to
+/// get them out again.
+///
+/// `Debug` and `Display` are implemented without needing anything of `T`, so
+/// you can `unwrap` a result carrying a `CapacityError` of any type. With the
+/// `std` feature this also implements `std::error::Error`.
+///
+/// ```rust
+/// use tinyvec::*;
+///
+/// let mut av = array_vec!([i32; 2], 1, 2);
+/// let err = av.try_insert(0, 7).unwrap_err();
+/// assert_eq!(err.element(), 7);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CapacityError<T> {
+  element: T,
+}
+
+impl<T> CapacityError<T> {
+  /// Wraps up the element(s) that didn't fit.
+  #[inline(always)]
+  #[must_use]
+  pub fn new(element: T) -> Self {
+    Self { element }
+  }
+
+  /// Unwraps the element(s) that didn't fit.
+  #[inline(always)]
+  #[must_use]
+  pub fn element(self) -> T {
+    self.element
+  }
+
+  /// Discards the element(s), leaving just the fact that there was an error.
+  #[inline(always)]
+  #[must_use]
+  pub fn simplify(self) -> CapacityError<()> {
+    CapacityError { element: () }
+  }
+}
+
+impl<T> Debug for CapacityError<T> {
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "CapacityError: insufficient capacity")
+  }
+}
+
+impl<T> Display for CapacityError<T> {
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "insufficient capacity")
+  }
+}
+
+#[cfg(feature = "std")]
+impl<T> std::error::Error for CapacityError<T> {}
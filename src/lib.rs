@@ -32,10 +32,45 @@
 //!     another name for this sort of data structure. Please [contact
 //!     us](https://github.com/Lokathor/tinyvec/issues) with a better name
 //!     before this crate is 1.0 if you can think of one.)
+//! * [`ArrayDeque`] is an array-backed ring buffer with a fixed capacity. It
+//!   has the same growth rules as `ArrayVec`, but can push and pop at either
+//!   end in constant time.
+//! * [`ArrayString`] is a UTF-8 string stored in an `ArrayVec` of bytes. The
+//!   [`array_format!`] macro formats text directly into one.
+//! * [`SliceVec`] is like an `ArrayVec`, but the backing memory is a mutable
+//!   slice that you lend to it, so the capacity can be decided at runtime.
+//! * [`OptionArrayVec`] is an `ArrayVec` for element types that don't have a
+//!   `Default`. The backing array is `[Option<T>; N]`, with every spare slot
+//!   left as `None`.
+//! * [`ArrayMap`] is a map stored in an `ArrayVec` of `(K, V)` pairs, kept
+//!   sorted by key and searched with a binary search.
+//! * [`ArraySet`] is a set stored in an `ArrayVec`, kept sorted and without
+//!   duplicates, with merge-based set algebra.
+//! * [`ArrayHashMap`] is a hash map stored in an array of [`HashSlot`]s,
+//!   using open addressing and a pluggable `BuildHasher`.
+//! * [`ArrayBinaryHeap`] is a priority queue stored in an `ArrayVec`, as a
+//!   binary max-heap.
 //! * [`TinyVec`] is an enum that's either an "inline" `ArrayVec` or a "heap"
 //!   `Vec`. If it's in array mode and you try to grow the vec beyond its
 //!   capacity it'll quietly transition into heap mode for you and then continue
-//!   operation. This type is naturally behind the `alloc` feature gate.
+//!   operation. This type is naturally behind the `alloc` feature gate. How
+//!   it moves to the heap (and whether it ever comes back) is decided by a
+//!   [`SpillPolicy`], given as an optional second type parameter.
+//! * [`TinyDeque`] is the same idea for a double-ended queue: an "inline"
+//!   `ArrayDeque` or a "heap" `VecDeque`. It's also behind the `alloc` feature
+//!   gate.
+//! * [`TinyString`] is the same idea for text: an "inline" `ArrayString` or a
+//!   "heap" `String`. It's also behind the `alloc` feature gate.
+//! * [`TinyOptionVec`] is the same idea for `OptionArrayVec`: an "inline"
+//!   array of `Option<T>` or a "heap" `Vec<Option<T>>`. It's also behind the
+//!   `alloc` feature gate.
+//! * [`TinyMap`] is the same idea for maps: an "inline" `ArrayMap` or a
+//!   "heap" `BTreeMap`. It's also behind the `alloc` feature gate.
+//! * [`TinySet`] is the same idea for sets: an "inline" `ArraySet` or a
+//!   "heap" `BTreeSet`. It's also behind the `alloc` feature gate.
+//! * [`TinyBinaryHeap`] is the same idea for priority queues: an "inline"
+//!   `ArrayBinaryHeap` or a "heap" `BinaryHeap`. It's also behind the `alloc`
+//!   feature gate.
 //!
 //! ## Stability Goal
 //!
@@ -72,7 +107,10 @@ use core::{
     Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, Pointer,
     UpperExp, UpperHex,
   },
-  iter::{Extend, FromIterator, IntoIterator, Iterator},
+  iter::{
+    once, Chain, Extend, FromIterator, FusedIterator, IntoIterator, Iterator,
+    Once,
+  },
   mem::{needs_drop, replace},
   ops::{Deref, DerefMut, Index, IndexMut, RangeBounds},
   slice::SliceIndex,
@@ -81,13 +119,76 @@ use core::{
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(feature = "std")]
+extern crate std;
+
 mod array;
 pub use array::*;
 
+mod capacity_error;
+pub use capacity_error::*;
+
+mod spill;
+pub use spill::*;
+
 mod arrayvec;
 pub use arrayvec::*;
 
+mod arraydeque;
+pub use arraydeque::*;
+
+mod arraystring;
+pub use arraystring::*;
+
+mod slicevec;
+pub use slicevec::*;
+
+mod optionarrayvec;
+pub use optionarrayvec::*;
+
+mod arraymap;
+pub use arraymap::*;
+
+mod arrayset;
+pub use arrayset::*;
+
+mod arrayhashmap;
+pub use arrayhashmap::*;
+
+mod arraybinaryheap;
+pub use arraybinaryheap::*;
+
 #[cfg(feature = "alloc")]
 mod tinyvec;
 #[cfg(feature = "alloc")]
 pub use tinyvec::*;
+
+#[cfg(feature = "alloc")]
+mod tinydeque;
+#[cfg(feature = "alloc")]
+pub use tinydeque::*;
+
+#[cfg(feature = "alloc")]
+mod tinystring;
+#[cfg(feature = "alloc")]
+pub use tinystring::*;
+
+#[cfg(feature = "alloc")]
+mod tinyoptionvec;
+#[cfg(feature = "alloc")]
+pub use tinyoptionvec::*;
+
+#[cfg(feature = "alloc")]
+mod tinymap;
+#[cfg(feature = "alloc")]
+pub use tinymap::*;
+
+#[cfg(feature = "alloc")]
+mod tinyset;
+#[cfg(feature = "alloc")]
+pub use tinyset::*;
+
+#[cfg(feature = "alloc")]
+mod tinybinaryheap;
+#[cfg(feature = "alloc")]
+pub use tinybinaryheap::*;
@@ -0,0 +1,781 @@
+use super::*;
+
+/// An array-backed vector for element types that don't have a `Default`.
+///
+/// * Fixed capacity (based on array size)
+/// * Variable length
+/// * Any element type at all.
+///
+/// The backing array type is `[Option<T>; N]`, and `Option<T>` is always
+/// `Default` (it's `None`). Every slot in the live part of the vec is `Some`,
+/// and every slot in the spare capacity is `None`. The price is the space for
+/// the `Option` discriminant in each slot, except for the element types where
+/// the compiler can hide it (references, `Box`, the `NonZero` integers, and so
+/// on).
+///
+/// Because the elements are stored as `Option<T>`, this can't `Deref` to a
+/// slice of `T`. Use [`iter`](OptionArrayVec::iter),
+/// [`get`](OptionArrayVec::get), or indexing instead, or
+/// [`as_option_slice`](OptionArrayVec::as_option_slice) to see the slots.
+///
+/// ```rust
+/// use core::num::NonZeroU32;
+/// use tinyvec::*;
+///
+/// let mut ids: OptionArrayVec<[Option<NonZeroU32>; 4]> = OptionArrayVec::new();
+/// ids.push(NonZeroU32::new(7).unwrap());
+/// ids.push(NonZeroU32::new(9).unwrap());
+/// assert_eq!(ids[1].get(), 9);
+/// assert_eq!(ids.pop().map(NonZeroU32::get), Some(9));
+/// assert_eq!(ids.len(), 1);
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct OptionArrayVec<A: Array> {
+  vec: ArrayVec<A>,
+}
+
+impl<A: Array> Default for OptionArrayVec<A> {
+  #[inline]
+  fn default() -> Self {
+    Self { vec: ArrayVec::default() }
+  }
+}
+
+/// Takes the value out of a slot in the live part of a vec, which is always
+/// `Some`.
+#[inline(always)]
+pub(crate) fn unwrap_slot<T>(slot: Option<T>) -> T {
+  match slot {
+    Some(t) => t,
+    None => unreachable!("tinyvec: an empty slot in the live part of a vec"),
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> OptionArrayVec<A> {
+  /// Move all values from `other` into this vec.
+  ///
+  /// ## Panics
+  /// * If the vec overflows its capacity
+  #[inline]
+  pub fn append(&mut self, other: &mut Self) {
+    for item in other.drain(..) {
+      self.push(item)
+    }
+  }
+
+  /// The slots of the live part of the vec, which are all `Some`.
+  #[inline(always)]
+  #[must_use]
+  pub fn as_option_slice(&self) -> &[Option<T>] {
+    self.vec.as_slice()
+  }
+
+  /// The capacity of the vec.
+  #[inline(always)]
+  #[must_use]
+  pub fn capacity(&self) -> usize {
+    self.vec.capacity()
+  }
+
+  /// Removes all elements from the vec.
+  #[inline(always)]
+  pub fn clear(&mut self) {
+    self.vec.clear()
+  }
+
+  /// Creates a draining iterator that removes the specified range in the vector
+  /// and yields the removed items.
+  ///
+  /// See [`ArrayVec::drain`](ArrayVec::<A>::drain)
+  ///
+  /// ## Panics
+  /// * If the start is greater than the end
+  /// * If the end is past the edge of the vec.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut av: OptionArrayVec<[Option<&str>; 4]> =
+  ///   ["a", "b", "c"].iter().copied().collect();
+  /// let drained: Vec<&str> = av.drain(1..).collect();
+  /// assert_eq!(drained, ["b", "c"]);
+  /// assert_eq!(av.len(), 1);
+  /// ```
+  #[inline]
+  pub fn drain<R: RangeBounds<usize>>(
+    &mut self,
+    range: R,
+  ) -> OptionArrayVecDrain<'_, A> {
+    OptionArrayVecDrain { inner: self.vec.drain(range) }
+  }
+
+  /// Clone each element of the slice into this vec.
+  ///
+  /// ## Panics
+  /// * If the vec would overflow its capacity, in which case it's unchanged.
+  #[inline]
+  pub fn extend_from_slice(&mut self, sli: &[T])
+  where
+    T: Clone,
+  {
+    if self.len() + sli.len() > self.capacity() {
+      panic!(
+        "OptionArrayVec::extend_from_slice> total length {} exceeds capacity {}!",
+        self.len() + sli.len(),
+        self.capacity()
+      );
+    }
+    for item in sli {
+      self.push(item.clone());
+    }
+  }
+
+  /// The first element, if any.
+  #[inline]
+  #[must_use]
+  pub fn first(&self) -> Option<&T> {
+    self.get(0)
+  }
+
+  /// The first element, if any.
+  #[inline]
+  #[must_use]
+  pub fn first_mut(&mut self) -> Option<&mut T> {
+    self.get_mut(0)
+  }
+
+  /// The element at the index, if it's in bounds.
+  #[inline]
+  #[must_use]
+  pub fn get(&self, index: usize) -> Option<&T> {
+    self.vec.get(index).and_then(Option::as_ref)
+  }
+
+  /// The element at the index, if it's in bounds.
+  #[inline]
+  #[must_use]
+  pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+    self.vec.get_mut(index).and_then(Option::as_mut)
+  }
+
+  /// Inserts an item at the position given, moving all following elements +1
+  /// index.
+  ///
+  /// ## Panics
+  /// * If `index` > `len`
+  /// * If the capacity is exhausted
+  #[inline]
+  pub fn insert(&mut self, index: usize, item: T) {
+    self.vec.insert(index, Some(item))
+  }
+
+  /// If the vec is empty.
+  #[inline(always)]
+  #[must_use]
+  pub fn is_empty(&self) -> bool {
+    self.vec.is_empty()
+  }
+
+  /// Iterates over shared references to the elements.
+  #[inline]
+  #[must_use]
+  pub fn iter(&self) -> OptionIter<'_, T> {
+    OptionIter::new(self.vec.iter())
+  }
+
+  /// Iterates over unique references to the elements.
+  #[inline]
+  #[must_use]
+  pub fn iter_mut(&mut self) -> OptionIterMut<'_, T> {
+    OptionIterMut::new(self.vec.iter_mut())
+  }
+
+  /// The last element, if any.
+  #[inline]
+  #[must_use]
+  pub fn last(&self) -> Option<&T> {
+    self.vec.last().and_then(Option::as_ref)
+  }
+
+  /// The last element, if any.
+  #[inline]
+  #[must_use]
+  pub fn last_mut(&mut self) -> Option<&mut T> {
+    self.vec.last_mut().and_then(Option::as_mut)
+  }
+
+  /// The length of the vec (in elements).
+  #[inline(always)]
+  #[must_use]
+  pub fn len(&self) -> usize {
+    self.vec.len()
+  }
+
+  /// Makes a new, empty vec.
+  #[inline(always)]
+  #[must_use]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Remove and return the last element of the vec, if there is one.
+  ///
+  /// ## Failure
+  /// * If the vec is empty you get `None`.
+  #[inline]
+  pub fn pop(&mut self) -> Option<T> {
+    self.vec.pop().map(unwrap_slot)
+  }
+
+  /// Place an element onto the end of the vec.
+  ///
+  /// ## Panics
+  /// * If the length of the vec would overflow the capacity.
+  #[inline]
+  pub fn push(&mut self, val: T) {
+    self.vec.push(Some(val))
+  }
+
+  /// Removes the item at `index`, shifting all others down by one index.
+  ///
+  /// Returns the removed element.
+  ///
+  /// ## Panics
+  /// * If the index is out of bounds.
+  #[inline]
+  pub fn remove(&mut self, index: usize) -> T {
+    unwrap_slot(self.vec.remove(index))
+  }
+
+  /// Resize the vec to the new length.
+  ///
+  /// If it needs to be longer, it's filled with repeated calls to the provided
+  /// function. If it needs to be shorter, it's truncated.
+  ///
+  /// ## Panics
+  /// * If the new length is over the capacity.
+  #[inline]
+  pub fn resize_with<F: FnMut() -> T>(&mut self, new_len: usize, mut f: F) {
+    self.vec.resize_with(new_len, || Some(f()))
+  }
+
+  /// Walk the vec and keep only the elements that pass the predicate given.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut av: OptionArrayVec<[Option<&str>; 4]> =
+  ///   ["a", "bb", "c", "dd"].iter().copied().collect();
+  /// av.retain(|s| s.len() == 1);
+  /// assert!(av.iter().eq(["a", "c"].iter()));
+  /// ```
+  #[inline]
+  pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut acceptable: F) {
+    self.retain_mut(|x| acceptable(x))
+  }
+
+  /// Walk the vec and keep only the elements that pass the predicate given,
+  /// which can also change the elements.
+  #[inline]
+  pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut acceptable: F) {
+    self.vec.retain_mut(|slot| match slot {
+      Some(x) => acceptable(x),
+      None => false,
+    })
+  }
+
+  /// Splits the collection at the point given.
+  ///
+  /// * `[0, at)` stays in this vec
+  /// * `[at, len)` ends up in the new vec.
+  ///
+  /// ## Panics
+  /// * if at > len
+  #[inline]
+  pub fn split_off(&mut self, at: usize) -> Self {
+    Self { vec: self.vec.split_off(at) }
+  }
+
+  /// Remove an element, swapping the end of the vec into its place.
+  ///
+  /// ## Panics
+  /// * If the index is out of bounds.
+  #[inline]
+  pub fn swap_remove(&mut self, index: usize) -> T {
+    unwrap_slot(self.vec.swap_remove(index))
+  }
+
+  /// Reduces the vec's length to the given value.
+  ///
+  /// If the vec is already shorter than the input, nothing happens.
+  #[inline]
+  pub fn truncate(&mut self, new_len: usize) {
+    self.vec.truncate(new_len)
+  }
+
+  /// Inserts an item at the position given, if there's room.
+  ///
+  /// ## Panics
+  /// * If `index` > `len`, the same as [`insert`](OptionArrayVec::insert).
+  ///
+  /// ## Failure
+  /// * If the vec is full you get the item back in the error and the vec is
+  ///   unchanged.
+  #[inline]
+  pub fn try_insert(
+    &mut self,
+    index: usize,
+    item: T,
+  ) -> Result<(), CapacityError<T>> {
+    self
+      .vec
+      .try_insert(index, Some(item))
+      .map_err(|e| CapacityError::new(unwrap_slot(e.element())))
+  }
+
+  /// Place an element onto the end of the vec, if there's room.
+  ///
+  /// ## Failure
+  /// * If the vec is full you get the item back in the `Err`.
+  #[inline]
+  pub fn try_push(&mut self, val: T) -> Result<(), T> {
+    self.vec.try_push(Some(val)).map_err(unwrap_slot)
+  }
+
+  /// Remove an element, shifting all following elements down, if the index is
+  /// in bounds.
+  ///
+  /// ## Failure
+  /// * If the index is out of bounds you get `None` and the vec is unchanged.
+  #[inline]
+  pub fn try_remove(&mut self, index: usize) -> Option<T> {
+    self.vec.try_remove(index).map(unwrap_slot)
+  }
+
+  /// Remove an element, swapping the end of the vec into its place, if the
+  /// index is in bounds.
+  ///
+  /// ## Failure
+  /// * If the index is out of bounds you get `None` and the vec is unchanged.
+  #[inline]
+  pub fn try_swap_remove(&mut self, index: usize) -> Option<T> {
+    self.vec.try_swap_remove(index).map(unwrap_slot)
+  }
+}
+
+/// Draining iterator for `OptionArrayVec`
+///
+/// See [`OptionArrayVec::drain`](OptionArrayVec::<A>::drain)
+pub struct OptionArrayVecDrain<'p, A: Array> {
+  inner: ArrayVecDrain<'p, A>,
+}
+impl<'p, T, A: Array<Item = Option<T>>> Iterator
+  for OptionArrayVecDrain<'p, A>
+{
+  type Item = T;
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    self.inner.next().map(unwrap_slot)
+  }
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.inner.size_hint()
+  }
+}
+impl<'p, T, A: Array<Item = Option<T>>> DoubleEndedIterator
+  for OptionArrayVecDrain<'p, A>
+{
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    self.inner.next_back().map(unwrap_slot)
+  }
+}
+impl<'p, T, A: Array<Item = Option<T>>> ExactSizeIterator
+  for OptionArrayVecDrain<'p, A>
+{
+}
+impl<'p, T, A: Array<Item = Option<T>>> FusedIterator
+  for OptionArrayVecDrain<'p, A>
+{
+}
+
+/// Iterator for consuming an `OptionArrayVec` and returning owned elements.
+#[derive(Clone)]
+pub struct OptionArrayVecIterator<A: Array> {
+  inner: ArrayVecIterator<A>,
+}
+impl<T, A: Array<Item = Option<T>>> Iterator for OptionArrayVecIterator<A> {
+  type Item = T;
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    self.inner.next().map(unwrap_slot)
+  }
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.inner.size_hint()
+  }
+}
+impl<T, A: Array<Item = Option<T>>> DoubleEndedIterator
+  for OptionArrayVecIterator<A>
+{
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    self.inner.next_back().map(unwrap_slot)
+  }
+}
+impl<T, A: Array<Item = Option<T>>> ExactSizeIterator
+  for OptionArrayVecIterator<A>
+{
+}
+impl<T, A: Array<Item = Option<T>>> FusedIterator
+  for OptionArrayVecIterator<A>
+{
+}
+
+/// Iterator over shared references to the elements of an `OptionArrayVec` or
+/// a `TinyOptionVec`.
+pub struct OptionIter<'a, T> {
+  inner: core::slice::Iter<'a, Option<T>>,
+}
+impl<'a, T> Iterator for OptionIter<'a, T> {
+  type Item = &'a T;
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    self.inner.next().map(|slot| unwrap_slot(slot.as_ref()))
+  }
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.inner.size_hint()
+  }
+}
+impl<'a, T> DoubleEndedIterator for OptionIter<'a, T> {
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    self.inner.next_back().map(|slot| unwrap_slot(slot.as_ref()))
+  }
+}
+impl<'a, T> ExactSizeIterator for OptionIter<'a, T> {}
+impl<'a, T> FusedIterator for OptionIter<'a, T> {}
+impl<'a, T> Clone for OptionIter<'a, T> {
+  #[inline]
+  fn clone(&self) -> Self {
+    Self { inner: self.inner.clone() }
+  }
+}
+impl<'a, T> OptionIter<'a, T> {
+  /// Makes an iterator over a slice where every slot is `Some`.
+  #[inline(always)]
+  pub(crate) fn new(inner: core::slice::Iter<'a, Option<T>>) -> Self {
+    Self { inner }
+  }
+}
+
+/// Iterator over unique references to the elements of an `OptionArrayVec` or
+/// a `TinyOptionVec`.
+pub struct OptionIterMut<'a, T> {
+  inner: core::slice::IterMut<'a, Option<T>>,
+}
+impl<'a, T> Iterator for OptionIterMut<'a, T> {
+  type Item = &'a mut T;
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    self.inner.next().map(|slot| unwrap_slot(slot.as_mut()))
+  }
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.inner.size_hint()
+  }
+}
+impl<'a, T> DoubleEndedIterator for OptionIterMut<'a, T> {
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    self.inner.next_back().map(|slot| unwrap_slot(slot.as_mut()))
+  }
+}
+impl<'a, T> ExactSizeIterator for OptionIterMut<'a, T> {}
+impl<'a, T> FusedIterator for OptionIterMut<'a, T> {}
+impl<'a, T> OptionIterMut<'a, T> {
+  /// Makes an iterator over a slice where every slot is `Some`.
+  #[inline(always)]
+  pub(crate) fn new(inner: core::slice::IterMut<'a, Option<T>>) -> Self {
+    Self { inner }
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> Index<usize> for OptionArrayVec<A> {
+  type Output = T;
+  #[inline]
+  fn index(&self, index: usize) -> &Self::Output {
+    match self.get(index) {
+      Some(x) => x,
+      None => panic!(
+        "OptionArrayVec::index> index {} is out of bounds {}",
+        index,
+        self.len()
+      ),
+    }
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> IndexMut<usize> for OptionArrayVec<A> {
+  #[inline]
+  fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+    let len = self.len();
+    match self.get_mut(index) {
+      Some(x) => x,
+      None => panic!(
+        "OptionArrayVec::index_mut> index {} is out of bounds {}",
+        index, len
+      ),
+    }
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> Extend<T> for OptionArrayVec<A> {
+  #[inline]
+  fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+    for t in iter {
+      self.push(t)
+    }
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> FromIterator<T> for OptionArrayVec<A> {
+  #[inline]
+  fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+    let mut av = Self::default();
+    for t in iter {
+      av.push(t)
+    }
+    av
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> IntoIterator for OptionArrayVec<A> {
+  type Item = T;
+  type IntoIter = OptionArrayVecIterator<A>;
+  #[inline(always)]
+  fn into_iter(self) -> Self::IntoIter {
+    OptionArrayVecIterator { inner: self.vec.into_iter() }
+  }
+}
+
+impl<'a, T: 'a, A: Array<Item = Option<T>>> IntoIterator
+  for &'a OptionArrayVec<A>
+{
+  type Item = &'a T;
+  type IntoIter = OptionIter<'a, T>;
+  #[inline(always)]
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+
+impl<'a, T: 'a, A: Array<Item = Option<T>>> IntoIterator
+  for &'a mut OptionArrayVec<A>
+{
+  type Item = &'a mut T;
+  type IntoIter = OptionIterMut<'a, T>;
+  #[inline(always)]
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter_mut()
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> PartialEq for OptionArrayVec<A>
+where
+  T: PartialEq,
+{
+  #[inline]
+  fn eq(&self, other: &Self) -> bool {
+    self.len() == other.len() && self.iter().eq(other.iter())
+  }
+}
+impl<T, A: Array<Item = Option<T>>> Eq for OptionArrayVec<A> where T: Eq {}
+
+impl<T, A: Array<Item = Option<T>>> PartialOrd for OptionArrayVec<A>
+where
+  T: PartialOrd,
+{
+  #[inline]
+  fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+    self.iter().partial_cmp(other.iter())
+  }
+}
+impl<T, A: Array<Item = Option<T>>> Ord for OptionArrayVec<A>
+where
+  T: Ord,
+{
+  #[inline]
+  fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+    self.iter().cmp(other.iter())
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> PartialEq<&[T]> for OptionArrayVec<A>
+where
+  T: PartialEq,
+{
+  #[inline]
+  fn eq(&self, other: &&[T]) -> bool {
+    self.len() == other.len() && self.iter().eq(other.iter())
+  }
+}
+
+// //
+// Formatting impls
+// //
+
+impl<T, A: Array<Item = Option<T>>> Binary for OptionArrayVec<A>
+where
+  T: Binary,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      Binary::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> Debug for OptionArrayVec<A>
+where
+  T: Debug,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      Debug::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> Display for OptionArrayVec<A>
+where
+  T: Display,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      Display::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> LowerExp for OptionArrayVec<A>
+where
+  T: LowerExp,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      LowerExp::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> LowerHex for OptionArrayVec<A>
+where
+  T: LowerHex,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      LowerHex::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> Octal for OptionArrayVec<A>
+where
+  T: Octal,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      Octal::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> Pointer for OptionArrayVec<A>
+where
+  T: Pointer,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      Pointer::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> UpperExp for OptionArrayVec<A>
+where
+  T: UpperExp,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      UpperExp::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> UpperHex for OptionArrayVec<A>
+where
+  T: UpperHex,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      UpperHex::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
@@ -0,0 +1,827 @@
+use super::*;
+
+/// A slice-backed vector-like data structure.
+///
+/// This is a very similar concept to `ArrayVec`, but instead of the backing
+/// memory being an owned array, the backing memory is a unique-borrowed slice.
+/// You can thus create one of these structures "around" some slice that
+/// you're working with to make it easier to manipulate.
+///
+/// * Has a fixed capacity (the initial slice size).
+/// * Has a variable length.
+/// * All of the slice memory is always initialized.
+///
+/// ```rust
+/// use tinyvec::*;
+///
+/// let mut buf = [0_u8; 8];
+/// let mut sv = SliceVec::from_slice_len(&mut buf, 0);
+/// sv.push(3);
+/// sv.extend_from_slice(&[4, 5]);
+/// sv.insert(0, 2);
+/// assert_eq!(sv.as_slice(), &[2, 3, 4, 5]);
+/// assert_eq!(sv.capacity(), 8);
+/// ```
+pub struct SliceVec<'s, T: Default> {
+  data: &'s mut [T],
+  len: usize,
+}
+
+impl<'s, T: Default> Default for SliceVec<'s, T> {
+  /// An empty vec with no capacity.
+  #[inline(always)]
+  fn default() -> Self {
+    Self { data: &mut [], len: 0 }
+  }
+}
+
+impl<'s, T: Default> Deref for SliceVec<'s, T> {
+  type Target = [T];
+  #[inline(always)]
+  fn deref(&self) -> &Self::Target {
+    &self.data[..self.len]
+  }
+}
+
+impl<'s, T: Default> DerefMut for SliceVec<'s, T> {
+  #[inline(always)]
+  fn deref_mut(&mut self) -> &mut Self::Target {
+    &mut self.data[..self.len]
+  }
+}
+
+impl<'s, T: Default, I: SliceIndex<[T]>> Index<I> for SliceVec<'s, T> {
+  type Output = <I as SliceIndex<[T]>>::Output;
+  #[inline(always)]
+  fn index(&self, index: I) -> &Self::Output {
+    &self.deref()[index]
+  }
+}
+
+impl<'s, T: Default, I: SliceIndex<[T]>> IndexMut<I> for SliceVec<'s, T> {
+  #[inline(always)]
+  fn index_mut(&mut self, index: I) -> &mut Self::Output {
+    &mut self.deref_mut()[index]
+  }
+}
+
+impl<'s, T: Default> SliceVec<'s, T> {
+  /// Move all values from `other` into this vec.
+  ///
+  /// ## Panics
+  /// * If the combined length would overflow the capacity.
+  #[inline]
+  pub fn append(&mut self, other: &mut SliceVec<'_, T>) {
+    for item in other.drain(..) {
+      self.push(item)
+    }
+  }
+
+  /// A mutable pointer to the backing slice.
+  ///
+  /// ## Safety
+  ///
+  /// This pointer has provenance over the _entire_ backing slice.
+  #[inline(always)]
+  #[must_use]
+  pub fn as_mut_ptr(&mut self) -> *mut T {
+    self.data.as_mut_ptr()
+  }
+
+  /// Helper for getting the mut slice.
+  #[inline(always)]
+  #[must_use]
+  pub fn as_mut_slice(&mut self) -> &mut [T] {
+    self.deref_mut()
+  }
+
+  /// A const pointer to the backing slice.
+  ///
+  /// ## Safety
+  ///
+  /// This pointer has provenance over the _entire_ backing slice.
+  #[inline(always)]
+  #[must_use]
+  pub fn as_ptr(&self) -> *const T {
+    self.data.as_ptr()
+  }
+
+  /// Helper for getting the shared slice.
+  #[inline(always)]
+  #[must_use]
+  pub fn as_slice(&self) -> &[T] {
+    self.deref()
+  }
+
+  /// The capacity of the `SliceVec`.
+  ///
+  /// This is fixed based on the length of the backing slice.
+  #[inline(always)]
+  #[must_use]
+  pub fn capacity(&self) -> usize {
+    self.data.len()
+  }
+
+  /// Removes all elements from the vec.
+  #[inline(always)]
+  pub fn clear(&mut self) {
+    self.truncate(0)
+  }
+
+  /// De-duplicates the vec.
+  #[cfg(feature = "nightly_slice_partition_dedup")]
+  #[inline(always)]
+  pub fn dedup(&mut self)
+  where
+    T: PartialEq,
+  {
+    self.dedup_by(|a, b| a == b)
+  }
+
+  /// De-duplicates the vec according to the predicate given.
+  #[cfg(feature = "nightly_slice_partition_dedup")]
+  #[inline(always)]
+  pub fn dedup_by<F>(&mut self, same_bucket: F)
+  where
+    F: FnMut(&mut T, &mut T) -> bool,
+  {
+    let len = {
+      let (dedup, _) = self.as_mut_slice().partition_dedup_by(same_bucket);
+      dedup.len()
+    };
+    self.truncate(len);
+  }
+
+  /// De-duplicates the vec according to the key selector given.
+  #[cfg(feature = "nightly_slice_partition_dedup")]
+  #[inline(always)]
+  pub fn dedup_by_key<F, K>(&mut self, mut key: F)
+  where
+    F: FnMut(&mut T) -> K,
+    K: PartialEq,
+  {
+    self.dedup_by(|a, b| key(a) == key(b))
+  }
+
+  /// Creates a draining iterator that removes the specified range in the vector
+  /// and yields the removed items.
+  ///
+  /// ## Panics
+  /// * If the start is greater than the end
+  /// * If the end is past the edge of the vec.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut arr = [6, 7, 8];
+  /// let mut sv = SliceVec::from(&mut arr[..]);
+  /// let drained: Vec<i32> = sv.drain(1..).collect();
+  /// assert_eq!(sv.as_slice(), &[6][..]);
+  /// assert_eq!(drained, vec![7, 8]);
+  ///
+  /// sv.drain(..);
+  /// assert_eq!(sv.as_slice(), &[]);
+  /// ```
+  #[inline]
+  pub fn drain<'p, R: RangeBounds<usize>>(
+    &'p mut self,
+    range: R,
+  ) -> SliceVecDrain<'p, 's, T> {
+    use core::ops::Bound;
+    let start = match range.start_bound() {
+      Bound::Included(x) => *x,
+      Bound::Excluded(x) => x + 1,
+      Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+      Bound::Included(x) => x + 1,
+      Bound::Excluded(x) => *x,
+      Bound::Unbounded => self.len,
+    };
+    assert!(
+      start <= end,
+      "SliceVec::drain> Illegal range, {} to {}",
+      start,
+      end
+    );
+    assert!(
+      end <= self.len,
+      "SliceVec::drain> Range ends at {} but length is only {}!",
+      end,
+      self.len
+    );
+    SliceVecDrain {
+      parent: self,
+      target_start: start,
+      target_index: start,
+      target_end: end,
+    }
+  }
+
+  /// Clone each element of the slice into this vec.
+  ///
+  /// ## Panics
+  /// * If the length of the vec would overflow the capacity.
+  #[inline]
+  pub fn extend_from_slice(&mut self, sli: &[T])
+  where
+    T: Clone,
+  {
+    let new_len = self.len + sli.len();
+    assert!(
+      new_len <= self.capacity(),
+      "SliceVec::extend_from_slice> total length {} exceeds capacity {}",
+      new_len,
+      self.capacity()
+    );
+    self.data[self.len..new_len].clone_from_slice(sli);
+    self.len = new_len;
+  }
+
+  /// Wraps up a slice and uses the given length as the initial length.
+  ///
+  /// If you want to use the whole length of the slice, you can just use the
+  /// `From` impl.
+  ///
+  /// ## Panics
+  ///
+  /// The length must be less than or equal to the length of the slice.
+  #[inline]
+  #[must_use]
+  #[allow(clippy::match_wild_err_arm)]
+  pub fn from_slice_len(data: &'s mut [T], len: usize) -> Self {
+    let capacity = data.len();
+    match Self::try_from_slice_len(data, len) {
+      Ok(out) => out,
+      Err(_) => {
+        panic!("SliceVec: length {} exceeds capacity {}!", len, capacity)
+      }
+    }
+  }
+
+  /// Inserts an item at the position given, moving all following elements +1
+  /// index.
+  ///
+  /// ## Panics
+  /// * If `index` > `len`
+  /// * If the length of the vec would overflow the capacity.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut arr = [1, 2, 3, 0, 0];
+  /// let mut sv = SliceVec::from_slice_len(&mut arr, 3);
+  /// sv.insert(1, 4);
+  /// assert_eq!(sv.as_slice(), &[1, 4, 2, 3]);
+  /// sv.insert(4, 5);
+  /// assert_eq!(sv.as_slice(), &[1, 4, 2, 3, 5]);
+  /// ```
+  #[inline]
+  pub fn insert(&mut self, index: usize, item: T) {
+    assert!(
+      index <= self.len,
+      "SliceVec::insert> index {} is out of bounds {}",
+      index,
+      self.len
+    );
+    self.push(item);
+    self.as_mut_slice()[index..].rotate_right(1);
+  }
+
+  /// If the vec is empty.
+  #[inline(always)]
+  #[must_use]
+  pub fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  /// The length of the vec (in elements).
+  #[inline(always)]
+  #[must_use]
+  pub fn len(&self) -> usize {
+    self.len
+  }
+
+  /// Remove and return the last element of the vec, if there is one.
+  ///
+  /// ## Failure
+  /// * If the vec is empty you get `None`.
+  #[inline]
+  pub fn pop(&mut self) -> Option<T> {
+    if self.len > 0 {
+      self.len -= 1;
+      // `mem::take` needs Rust 1.40.
+      #[allow(clippy::mem_replace_with_default)]
+      let out = replace(&mut self.data[self.len], T::default());
+      Some(out)
+    } else {
+      None
+    }
+  }
+
+  /// Place an element onto the end of the vec.
+  ///
+  /// See also, [`try_push`](SliceVec::try_push)
+  /// ## Panics
+  /// * If the length of the vec would overflow the capacity.
+  #[inline(always)]
+  pub fn push(&mut self, val: T) {
+    if self.try_push(val).is_err() {
+      panic!("SliceVec: overflow!")
+    }
+  }
+
+  /// Removes the item at `index`, shifting all others down by one index.
+  ///
+  /// Returns the removed element.
+  ///
+  /// ## Panics
+  ///
+  /// If the index is out of bounds.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut arr = [1, 2, 3];
+  /// let mut sv = SliceVec::from(&mut arr[..]);
+  /// assert_eq!(sv.remove(1), 2);
+  /// assert_eq!(sv.as_slice(), &[1, 3][..]);
+  /// ```
+  #[inline]
+  pub fn remove(&mut self, index: usize) -> T {
+    assert!(
+      index < self.len,
+      "SliceVec::remove> index {} is out of bounds {}",
+      index,
+      self.len
+    );
+    self.as_mut_slice()[index..].rotate_left(1);
+    self.pop().unwrap()
+  }
+
+  /// Resize the vec to the new length.
+  ///
+  /// If it needs to be longer, it's filled with clones of the provided value.
+  /// If it needs to be shorter, it's truncated.
+  ///
+  /// ## Panics
+  /// * If the new length would overflow the capacity.
+  #[inline]
+  pub fn resize(&mut self, new_len: usize, new_val: T)
+  where
+    T: Clone,
+  {
+    self.resize_with(new_len, || new_val.clone())
+  }
+
+  /// Resize the vec to the new length.
+  ///
+  /// If it needs to be longer, it's filled with repeated calls to the provided
+  /// function. If it needs to be shorter, it's truncated.
+  ///
+  /// ## Panics
+  /// * If the new length would overflow the capacity.
+  #[inline]
+  pub fn resize_with<F: FnMut() -> T>(&mut self, new_len: usize, mut f: F) {
+    use core::cmp::Ordering;
+    match new_len.cmp(&self.len) {
+      Ordering::Less => self.truncate(new_len),
+      Ordering::Equal => (),
+      Ordering::Greater => {
+        while self.len < new_len {
+          self.push(f());
+        }
+      }
+    }
+  }
+
+  /// Walk the vec and keep only the elements that pass the predicate given.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use tinyvec::*;
+  ///
+  /// let mut arr = [1, 1, 2, 3, 3, 4];
+  /// let mut sv = SliceVec::from(&mut arr[..]);
+  /// sv.retain(|&x| x % 2 == 0);
+  /// assert_eq!(sv.as_slice(), &[2, 4][..]);
+  /// ```
+  #[inline]
+  pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut acceptable: F) {
+    let mut kept = 0;
+    for i in 0..self.len {
+      if acceptable(&self[i]) {
+        self.swap(kept, i);
+        kept += 1;
+      }
+    }
+    self.truncate(kept);
+  }
+
+  /// Splits the vec at the point given, splitting the backing slice as well.
+  ///
+  /// * `[0, at)` stays in this vec, which keeps only that much capacity.
+  /// * `[at, len)` ends up in the new vec, which gets the rest of the backing
+  ///   slice as its capacity.
+  ///
+  /// ## Panics
+  /// * if at > len
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut arr = [1, 2, 3, 0];
+  /// let mut sv = SliceVec::from_slice_len(&mut arr, 3);
+  /// let sv2 = sv.split_off(1);
+  /// assert_eq!(sv.as_slice(), &[1][..]);
+  /// assert_eq!(sv.capacity(), 1);
+  /// assert_eq!(sv2.as_slice(), &[2, 3][..]);
+  /// assert_eq!(sv2.capacity(), 3);
+  /// ```
+  #[inline]
+  pub fn split_off(&mut self, at: usize) -> SliceVec<'s, T> {
+    if at > self.len {
+      panic!(
+        "SliceVec::split_off> at value {} exceeds length of {}",
+        at, self.len
+      );
+    }
+    // `mem::take` needs Rust 1.40.
+    #[allow(clippy::mem_replace_with_default)]
+    let data = replace(&mut self.data, &mut []);
+    let (this, other) = data.split_at_mut(at);
+    let other_len = self.len - at;
+    self.data = this;
+    self.len = at;
+    SliceVec { data: other, len: other_len }
+  }
+
+  /// Remove an element, swapping the end of the vec into its place.
+  ///
+  /// ## Panics
+  /// * If the index is out of bounds.
+  #[inline]
+  pub fn swap_remove(&mut self, index: usize) -> T {
+    assert!(
+      index < self.len,
+      "SliceVec::swap_remove> index {} is out of bounds {}",
+      index,
+      self.len
+    );
+    let last = self.len - 1;
+    self.swap(index, last);
+    self.pop().unwrap()
+  }
+
+  /// Reduces the vec's length to the given value.
+  ///
+  /// If the vec is already shorter than the input, nothing happens.
+  #[inline]
+  pub fn truncate(&mut self, new_len: usize) {
+    if needs_drop::<T>() || cfg!(feature = "wipe_vacated") {
+      while self.len > new_len {
+        self.pop();
+      }
+    } else {
+      self.len = self.len.min(new_len);
+    }
+  }
+
+  /// Wraps a slice, using the given length as the starting length.
+  ///
+  /// If you want to use the whole length of the slice, you can just use the
+  /// `From` impl.
+  ///
+  /// ## Failure
+  ///
+  /// If the given length is greater than the length of the slice this will
+  /// error, and you'll get the slice back in the `Err`.
+  #[inline]
+  pub fn try_from_slice_len(
+    data: &'s mut [T],
+    len: usize,
+  ) -> Result<Self, &'s mut [T]> {
+    if len <= data.len() {
+      Ok(Self { data, len })
+    } else {
+      Err(data)
+    }
+  }
+
+  /// Pushes an item if there's room.
+  ///
+  /// ## Failure
+  ///
+  /// If there's no more capacity the vec is unchanged, and you get the item
+  /// back in the `Err`.
+  #[inline]
+  pub fn try_push(&mut self, val: T) -> Result<(), T> {
+    if self.len < self.data.len() {
+      self.data[self.len] = val;
+      self.len += 1;
+      Ok(())
+    } else {
+      Err(val)
+    }
+  }
+}
+
+/// Draining iterator for `SliceVec`
+///
+/// See [`SliceVec::drain`](SliceVec::<T>::drain)
+pub struct SliceVecDrain<'p, 's, T: Default> {
+  parent: &'p mut SliceVec<'s, T>,
+  target_start: usize,
+  target_index: usize,
+  target_end: usize,
+}
+impl<'p, 's, T: Default> Iterator for SliceVecDrain<'p, 's, T> {
+  type Item = T;
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.target_index < self.target_end {
+      // `mem::take` needs Rust 1.40.
+      #[allow(clippy::mem_replace_with_default)]
+      let out = replace(&mut self.parent.data[self.target_index], T::default());
+      self.target_index += 1;
+      Some(out)
+    } else {
+      None
+    }
+  }
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let s = self.target_end - self.target_index;
+    (s, Some(s))
+  }
+}
+impl<'p, 's, T: Default> Drop for SliceVecDrain<'p, 's, T> {
+  #[inline]
+  fn drop(&mut self) {
+    for _ in self.by_ref() {}
+    let count = self.target_end - self.target_start;
+    let len = self.parent.len;
+    self.parent.data[self.target_start..len].rotate_left(count);
+    self.parent.len -= count;
+  }
+}
+
+impl<'s, T: Default> AsMut<[T]> for SliceVec<'s, T> {
+  #[inline(always)]
+  fn as_mut(&mut self) -> &mut [T] {
+    self.as_mut_slice()
+  }
+}
+
+impl<'s, T: Default> AsRef<[T]> for SliceVec<'s, T> {
+  #[inline(always)]
+  fn as_ref(&self) -> &[T] {
+    self.as_slice()
+  }
+}
+
+impl<'s, T: Default> Borrow<[T]> for SliceVec<'s, T> {
+  #[inline(always)]
+  fn borrow(&self) -> &[T] {
+    self.as_slice()
+  }
+}
+
+impl<'s, T: Default> BorrowMut<[T]> for SliceVec<'s, T> {
+  #[inline(always)]
+  fn borrow_mut(&mut self) -> &mut [T] {
+    self.as_mut_slice()
+  }
+}
+
+impl<'s, T: Default> Extend<T> for SliceVec<'s, T> {
+  #[inline]
+  fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+    for t in iter {
+      self.push(t)
+    }
+  }
+}
+
+impl<'s, T: Default> From<&'s mut [T]> for SliceVec<'s, T> {
+  /// The output has a length equal to the full slice.
+  ///
+  /// If you want to select a length, use
+  /// [`from_slice_len`](SliceVec::from_slice_len)
+  #[inline(always)]
+  fn from(data: &'s mut [T]) -> Self {
+    let len = data.len();
+    Self { data, len }
+  }
+}
+
+impl<'s, A: Array> From<&'s mut A> for SliceVec<'s, A::Item> {
+  /// The output has a length equal to the full array.
+  ///
+  /// If you want to select a length, use
+  /// [`from_slice_len`](SliceVec::from_slice_len)
+  #[inline(always)]
+  fn from(data: &'s mut A) -> Self {
+    Self::from(data.slice_mut())
+  }
+}
+
+impl<'s, T: Default> PartialEq for SliceVec<'s, T>
+where
+  T: PartialEq,
+{
+  #[inline]
+  fn eq(&self, other: &Self) -> bool {
+    self.as_slice().eq(other.as_slice())
+  }
+}
+impl<'s, T: Default> Eq for SliceVec<'s, T> where T: Eq {}
+
+impl<'s, T: Default> PartialOrd for SliceVec<'s, T>
+where
+  T: PartialOrd,
+{
+  #[inline]
+  fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+    self.as_slice().partial_cmp(other.as_slice())
+  }
+}
+impl<'s, T: Default> Ord for SliceVec<'s, T>
+where
+  T: Ord,
+{
+  #[inline]
+  fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+    self.as_slice().cmp(other.as_slice())
+  }
+}
+
+impl<'s, T: Default> PartialEq<&[T]> for SliceVec<'s, T>
+where
+  T: PartialEq,
+{
+  #[inline]
+  fn eq(&self, other: &&[T]) -> bool {
+    self.as_slice() == *other
+  }
+}
+
+// //
+// Formatting impls
+// //
+
+impl<'s, T: Default> Binary for SliceVec<'s, T>
+where
+  T: Binary,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      Binary::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<'s, T: Default> Debug for SliceVec<'s, T>
+where
+  T: Debug,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      Debug::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<'s, T: Default> Display for SliceVec<'s, T>
+where
+  T: Display,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      Display::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<'s, T: Default> LowerExp for SliceVec<'s, T>
+where
+  T: LowerExp,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      LowerExp::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<'s, T: Default> LowerHex for SliceVec<'s, T>
+where
+  T: LowerHex,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      LowerHex::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<'s, T: Default> Octal for SliceVec<'s, T>
+where
+  T: Octal,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      Octal::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<'s, T: Default> Pointer for SliceVec<'s, T>
+where
+  T: Pointer,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      Pointer::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<'s, T: Default> UpperExp for SliceVec<'s, T>
+where
+  T: UpperExp,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      UpperExp::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<'s, T: Default> UpperHex for SliceVec<'s, T>
+where
+  T: UpperHex,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      UpperHex::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
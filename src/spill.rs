@@ -0,0 +1,273 @@
+use super::*;
+
+/// Decides how a `TinyVec` moves between inline and heap storage.
+///
+/// The policy is the second type parameter of `TinyVec`, which defaults to
+/// [`Sticky`], the classic behavior.
+///
+/// Every part of the policy has a default, so a custom policy only needs to
+/// override the parts it cares about.
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use tinyvec::*;
+///
+/// struct BigFirstSpill;
+/// impl SpillPolicy for BigFirstSpill {
+///   fn heap_capacity(_inline: usize, _len: usize, _additional: usize) -> usize {
+///     256
+///   }
+/// }
+///
+/// let mut tv: TinyVec<[u8; 4], BigFirstSpill> = TinyVec::new();
+/// tv.extend_from_slice(&[1, 2, 3, 4, 5]);
+/// assert_eq!(tv.capacity(), 256);
+/// # }
+/// ```
+pub trait SpillPolicy {
+  /// Set this to make a heap vec only ever grow by exactly as much as it
+  /// needs, instead of the usual amortized doubling. This saves memory at the
+  /// cost of more reallocations.
+  const EXACT_GROWTH: bool = false;
+
+  /// The heap capacity to allocate when a vec holding `len` elements has to
+  /// move to the heap to make room for `additional` more.
+  ///
+  /// Anything less than `len + additional` is rounded up to that. By default
+  /// this is twice the inline capacity.
+  #[inline]
+  #[must_use]
+  fn heap_capacity(
+    inline_capacity: usize,
+    _len: usize,
+    _additional: usize,
+  ) -> usize {
+    inline_capacity.saturating_mul(2)
+  }
+
+  /// The length at (or under) which a heap vec moves back inline after a
+  /// `truncate`, `pop`, `retain` or `clear`.
+  ///
+  /// `None` means it stays on the heap, which is the default. Anything over
+  /// the inline capacity is treated as the inline capacity. Picking a length
+  /// under the inline capacity gives some hysteresis, so that a vec with a
+  /// length hovering around the inline capacity doesn't keep moving back and
+  /// forth.
+  #[inline]
+  #[must_use]
+  fn demote_len(_inline_capacity: usize) -> Option<usize> {
+    None
+  }
+
+  /// Where a `TinyVec` using this policy records its [`SpillStats`].
+  ///
+  /// * Requires the `spill_stats` feature
+  ///
+  /// The default is the global [`SPILL_STATS`].
+  #[cfg(feature = "spill_stats")]
+  #[inline]
+  #[must_use]
+  fn stats() -> &'static SpillStats {
+    &SPILL_STATS
+  }
+}
+
+/// Names the item type of an array along with a spill policy.
+///
+/// This is always just `A::Item`. `TinyVec` uses it in the type of its `Heap`
+/// variant, which is how the enum carries its policy type without needing a
+/// variant or field just for that.
+#[doc(hidden)]
+pub trait PolicyItem<P> {
+  /// The item type of the array.
+  type Item;
+}
+impl<A: Array, P> PolicyItem<P> for A {
+  type Item = A::Item;
+}
+
+/// Once on the heap, stay on the heap. This is the default policy.
+///
+/// The first heap allocation is twice the inline capacity, and growth after
+/// that is the same as `Vec`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Sticky;
+impl SpillPolicy for Sticky {}
+
+/// Move back inline once the length gets down to half the inline capacity.
+///
+/// Otherwise this is the same as [`Sticky`].
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use tinyvec::*;
+///
+/// let mut tv: TinyVec<[i32; 4], AutoDemote> = TinyVec::new();
+/// tv.extend(0..5);
+/// assert!(matches!(tv, TinyVec::Heap(_)));
+/// tv.truncate(2);
+/// assert!(matches!(tv, TinyVec::Inline(_)));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AutoDemote;
+impl SpillPolicy for AutoDemote {
+  #[inline]
+  fn demote_len(inline_capacity: usize) -> Option<usize> {
+    Some(inline_capacity / 2)
+  }
+}
+
+/// Never allocate more heap space than is needed right now.
+///
+/// The first heap allocation is exactly big enough for the elements, and each
+/// growth after that is exact too. Meant for memory-constrained targets.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Exact;
+impl SpillPolicy for Exact {
+  const EXACT_GROWTH: bool = true;
+
+  #[inline]
+  fn heap_capacity(
+    _inline_capacity: usize,
+    len: usize,
+    additional: usize,
+  ) -> usize {
+    len.saturating_add(additional)
+  }
+}
+
+#[cfg(feature = "spill_stats")]
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// The number of buckets in the [`SpillStats`] histograms.
+///
+/// * Requires the `spill_stats` feature
+///
+/// Bucket 0 is for length 0, and bucket `i` is for lengths in
+/// `2^(i-1) .. 2^i`. The last bucket also gets everything bigger than that.
+#[cfg(feature = "spill_stats")]
+pub const SPILL_STATS_BUCKETS: usize = 33;
+
+/// Counters for tuning the inline capacity of a `TinyVec`.
+///
+/// * Requires the `spill_stats` feature
+///
+/// Every `TinyVec` reports to the `SpillStats` given by its policy's
+/// [`stats`](SpillPolicy::stats), which is the global [`SPILL_STATS`] unless
+/// the policy says otherwise. Give a policy its own `static` to get numbers
+/// for just one type (or one call site, with one policy type per site).
+///
+/// Only the spill path touches the counters, so a vec that stays inline, or
+/// grows once it's on the heap, costs nothing extra. All of the counters are
+/// relaxed atomics, so this needs a target with atomic `usize` support.
+///
+/// ```rust
+/// use tinyvec::*;
+///
+/// static PACKET_STATS: SpillStats = SpillStats::new();
+/// struct PacketPolicy;
+/// impl SpillPolicy for PacketPolicy {
+///   fn stats() -> &'static SpillStats {
+///     &PACKET_STATS
+///   }
+/// }
+///
+/// let mut tv: TinyVec<[u8; 4], PacketPolicy> = TinyVec::new();
+/// tv.extend_from_slice(&[1, 2, 3, 4, 5]);
+/// assert_eq!(PACKET_STATS.spills(), 1);
+/// assert_eq!(PACKET_STATS.spill_len_histogram()[3], 1); // length 5
+/// assert_eq!(PACKET_STATS.peak_len_histogram()[3], 1);
+/// PACKET_STATS.reset();
+/// assert_eq!(PACKET_STATS.spills(), 0);
+/// ```
+#[cfg(feature = "spill_stats")]
+pub struct SpillStats {
+  spills: AtomicUsize,
+  spill_lens: [AtomicUsize; SPILL_STATS_BUCKETS],
+}
+
+/// The stats that every `TinyVec` reports to, unless its policy picks another
+/// [`SpillStats`].
+///
+/// * Requires the `spill_stats` feature
+#[cfg(feature = "spill_stats")]
+pub static SPILL_STATS: SpillStats = SpillStats::new();
+
+#[cfg(feature = "spill_stats")]
+impl Default for SpillStats {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(feature = "spill_stats")]
+impl SpillStats {
+  /// Makes a new set of counters, all at zero.
+  #[inline]
+  #[must_use]
+  #[allow(clippy::declare_interior_mutable_const)]
+  pub const fn new() -> Self {
+    const ZERO: AtomicUsize = AtomicUsize::new(0);
+    Self { spills: ZERO, spill_lens: [ZERO; SPILL_STATS_BUCKETS] }
+  }
+
+  /// How many times a vec has moved to the heap.
+  #[inline]
+  #[must_use]
+  pub fn spills(&self) -> usize {
+    self.spills.load(Ordering::Relaxed)
+  }
+
+  /// A histogram of the length each vec needed when it moved to the heap.
+  ///
+  /// See [`SPILL_STATS_BUCKETS`] for the bucket sizes.
+  #[inline]
+  #[must_use]
+  pub fn spill_len_histogram(&self) -> [usize; SPILL_STATS_BUCKETS] {
+    let mut out = [0; SPILL_STATS_BUCKETS];
+    for (o, c) in out.iter_mut().zip(self.spill_lens.iter()) {
+      *o = c.load(Ordering::Relaxed);
+    }
+    out
+  }
+
+  /// A histogram of the peak length of each vec that went on the heap.
+  ///
+  /// See [`SPILL_STATS_BUCKETS`] for the bucket sizes.
+  ///
+  /// The peak is recorded once per spill, as the length the vec needed at that
+  /// point, so pushing and popping around a bucket boundary afterwards doesn't
+  /// count the same vec again. Growth on the heap after the spill isn't seen,
+  /// and vecs that never leave inline storage aren't counted, their peak is at
+  /// most the inline capacity.
+  #[inline]
+  #[must_use]
+  pub fn peak_len_histogram(&self) -> [usize; SPILL_STATS_BUCKETS] {
+    self.spill_len_histogram()
+  }
+
+  /// Sets all of the counters back to zero.
+  #[inline]
+  pub fn reset(&self) {
+    self.spills.store(0, Ordering::Relaxed);
+    for c in self.spill_lens.iter() {
+      c.store(0, Ordering::Relaxed);
+    }
+  }
+
+  /// Which histogram bucket a length goes in.
+  #[inline]
+  fn bucket(len: usize) -> usize {
+    let bits = core::mem::size_of::<usize>() * 8;
+    (bits - len.leading_zeros() as usize).min(SPILL_STATS_BUCKETS - 1)
+  }
+
+  /// Counts a move to the heap by a vec that needed `len` elements.
+  #[inline]
+  pub(crate) fn record_spill(&self, len: usize) {
+    self.spills.fetch_add(1, Ordering::Relaxed);
+    self.spill_lens[Self::bucket(len)].fetch_add(1, Ordering::Relaxed);
+  }
+}
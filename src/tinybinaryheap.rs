@@ -0,0 +1,430 @@
+#![cfg(feature = "alloc")]
+
+use super::*;
+
+use alloc::{
+  collections::{binary_heap, BinaryHeap},
+  vec::Vec,
+};
+
+/// A priority queue that starts inline, but can automatically move to the
+/// heap.
+///
+/// * Requires the `alloc` feature
+///
+/// This is the `BinaryHeap` counterpart of [`TinyVec`]: while it's `Inline`
+/// it's an [`ArrayBinaryHeap`], and if you try to push past that capacity
+/// it'll quietly move all of its elements into a `BinaryHeap` and continue
+/// operation. Either way, `pop` gives the greatest element first.
+///
+/// ```rust
+/// use tinyvec::*;
+///
+/// let mut heap: TinyBinaryHeap<[u32; 2]> = TinyBinaryHeap::new();
+/// heap.push(2);
+/// heap.push(9);
+/// assert!(matches!(heap, TinyBinaryHeap::Inline(_)));
+/// heap.push(5);
+/// assert!(matches!(heap, TinyBinaryHeap::Heap(_)));
+/// assert_eq!(heap.pop(), Some(9));
+/// assert_eq!(heap.pop(), Some(5));
+/// ```
+pub enum TinyBinaryHeap<A: Array> {
+  #[allow(missing_docs)]
+  Inline(ArrayBinaryHeap<A>),
+  #[allow(missing_docs)]
+  Heap(BinaryHeap<A::Item>),
+}
+
+impl<A: Array> Clone for TinyBinaryHeap<A>
+where
+  ArrayBinaryHeap<A>: Clone,
+  BinaryHeap<A::Item>: Clone,
+{
+  #[inline]
+  fn clone(&self) -> Self {
+    match self {
+      TinyBinaryHeap::Inline(h) => TinyBinaryHeap::Inline(h.clone()),
+      TinyBinaryHeap::Heap(b) => TinyBinaryHeap::Heap(b.clone()),
+    }
+  }
+}
+
+impl<A: Array> Default for TinyBinaryHeap<A> {
+  #[inline]
+  fn default() -> Self {
+    TinyBinaryHeap::Inline(ArrayBinaryHeap::default())
+  }
+}
+
+impl<T: Ord, A: Array<Item = T>> TinyBinaryHeap<A> {
+  /// Removes all elements from the heap.
+  ///
+  /// A queue on the heap stays on the heap.
+  #[inline]
+  pub fn clear(&mut self) {
+    match self {
+      TinyBinaryHeap::Inline(h) => h.clear(),
+      TinyBinaryHeap::Heap(b) => b.clear(),
+    }
+  }
+
+  /// Removes all elements, as an iterator in heap order.
+  #[inline]
+  pub fn drain(&mut self) -> TinyBinaryHeapDrain<'_, A> {
+    match self {
+      TinyBinaryHeap::Inline(h) => TinyBinaryHeapDrain::Inline(h.drain()),
+      TinyBinaryHeap::Heap(b) => TinyBinaryHeapDrain::Heap(b.drain()),
+    }
+  }
+
+  /// Turns the heap into a `Vec`, sorted in ascending order.
+  #[inline]
+  #[must_use]
+  pub fn into_sorted_vec(self) -> Vec<T> {
+    match self {
+      TinyBinaryHeap::Inline(h) => h.into_sorted_vec().into_iter().collect(),
+      TinyBinaryHeap::Heap(b) => b.into_sorted_vec(),
+    }
+  }
+
+  /// Turns the heap into a `Vec`, in heap order (which isn't sorted).
+  #[inline]
+  #[must_use]
+  pub fn into_vec(self) -> Vec<T> {
+    match self {
+      TinyBinaryHeap::Inline(h) => h.into_iter().collect(),
+      TinyBinaryHeap::Heap(b) => b.into_vec(),
+    }
+  }
+
+  /// If the heap is empty.
+  #[inline]
+  #[must_use]
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Iterates over the elements, in heap order (which isn't sorted).
+  #[inline]
+  #[must_use]
+  pub fn iter(&self) -> TinyBinaryHeapIter<'_, T> {
+    match self {
+      TinyBinaryHeap::Inline(h) => TinyBinaryHeapIter::Inline(h.iter()),
+      TinyBinaryHeap::Heap(b) => TinyBinaryHeapIter::Heap(b.iter()),
+    }
+  }
+
+  /// The number of elements in the heap.
+  #[inline]
+  #[must_use]
+  pub fn len(&self) -> usize {
+    match self {
+      TinyBinaryHeap::Inline(h) => h.len(),
+      TinyBinaryHeap::Heap(b) => b.len(),
+    }
+  }
+
+  /// Moves the content of the TinyBinaryHeap to the heap, if it's inline.
+  #[allow(clippy::missing_inline_in_public_items)]
+  pub fn move_to_the_heap(&mut self) {
+    if let TinyBinaryHeap::Inline(h) = self {
+      let v: Vec<T> = h.drain().collect();
+      *self = TinyBinaryHeap::Heap(BinaryHeap::from(v));
+    }
+  }
+
+  /// Makes a new, empty heap.
+  #[inline(always)]
+  #[must_use]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// The greatest element, if any.
+  #[inline]
+  #[must_use]
+  pub fn peek(&self) -> Option<&T> {
+    match self {
+      TinyBinaryHeap::Inline(h) => h.peek(),
+      TinyBinaryHeap::Heap(b) => b.peek(),
+    }
+  }
+
+  /// Unique access to the greatest element, if any.
+  ///
+  /// The heap is fixed up when the returned guard is dropped, so the element
+  /// can be changed freely.
+  #[inline]
+  pub fn peek_mut(&mut self) -> Option<TinyBinaryHeapPeekMut<'_, A>> {
+    match self {
+      TinyBinaryHeap::Inline(h) => {
+        h.peek_mut().map(TinyBinaryHeapPeekMut::Inline)
+      }
+      TinyBinaryHeap::Heap(b) => b.peek_mut().map(TinyBinaryHeapPeekMut::Heap),
+    }
+  }
+
+  /// Removes the greatest element, if any.
+  #[inline]
+  pub fn pop(&mut self) -> Option<T> {
+    match self {
+      TinyBinaryHeap::Inline(h) => h.pop(),
+      TinyBinaryHeap::Heap(b) => b.pop(),
+    }
+  }
+
+  /// Pushes an element into the heap.
+  ///
+  /// If the inline heap is full, everything moves to the heap first.
+  #[inline]
+  pub fn push(&mut self, val: T) {
+    match self {
+      TinyBinaryHeap::Inline(h) => {
+        if let Err(val) = h.try_push(val) {
+          self.move_to_the_heap();
+          self.push(val)
+        }
+      }
+      TinyBinaryHeap::Heap(b) => b.push(val),
+    }
+  }
+}
+
+/// Unique access to the greatest element of a [`TinyBinaryHeap`].
+///
+/// See [`TinyBinaryHeap::peek_mut`]
+pub enum TinyBinaryHeapPeekMut<'a, A: Array>
+where
+  A::Item: Ord,
+{
+  #[allow(missing_docs)]
+  Inline(ArrayBinaryHeapPeekMut<'a, A>),
+  #[allow(missing_docs)]
+  Heap(binary_heap::PeekMut<'a, A::Item>),
+}
+
+impl<'a, A: Array> TinyBinaryHeapPeekMut<'a, A>
+where
+  A::Item: Ord,
+{
+  /// Removes the peeked element from the heap and returns it.
+  #[inline]
+  #[allow(clippy::must_use_candidate)]
+  pub fn pop(this: Self) -> A::Item {
+    match this {
+      TinyBinaryHeapPeekMut::Inline(p) => ArrayBinaryHeapPeekMut::pop(p),
+      TinyBinaryHeapPeekMut::Heap(p) => binary_heap::PeekMut::pop(p),
+    }
+  }
+}
+
+impl<'a, A: Array> Deref for TinyBinaryHeapPeekMut<'a, A>
+where
+  A::Item: Ord,
+{
+  type Target = A::Item;
+  #[inline]
+  fn deref(&self) -> &A::Item {
+    match self {
+      TinyBinaryHeapPeekMut::Inline(p) => p,
+      TinyBinaryHeapPeekMut::Heap(p) => p,
+    }
+  }
+}
+
+impl<'a, A: Array> DerefMut for TinyBinaryHeapPeekMut<'a, A>
+where
+  A::Item: Ord,
+{
+  #[inline]
+  fn deref_mut(&mut self) -> &mut A::Item {
+    match self {
+      TinyBinaryHeapPeekMut::Inline(p) => p,
+      TinyBinaryHeapPeekMut::Heap(p) => p,
+    }
+  }
+}
+
+/// Draining iterator for [`TinyBinaryHeap`], in heap order.
+///
+/// See [`TinyBinaryHeap::drain`]
+pub enum TinyBinaryHeapDrain<'a, A: Array> {
+  #[allow(missing_docs)]
+  Inline(ArrayVecDrain<'a, A>),
+  #[allow(missing_docs)]
+  Heap(binary_heap::Drain<'a, A::Item>),
+}
+impl<'a, A: Array> Iterator for TinyBinaryHeapDrain<'a, A> {
+  type Item = A::Item;
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    match self {
+      TinyBinaryHeapDrain::Inline(i) => i.next(),
+      TinyBinaryHeapDrain::Heap(i) => i.next(),
+    }
+  }
+  #[inline]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    match self {
+      TinyBinaryHeapDrain::Inline(i) => i.size_hint(),
+      TinyBinaryHeapDrain::Heap(i) => i.size_hint(),
+    }
+  }
+}
+impl<'a, A: Array> DoubleEndedIterator for TinyBinaryHeapDrain<'a, A> {
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    match self {
+      TinyBinaryHeapDrain::Inline(i) => i.next_back(),
+      TinyBinaryHeapDrain::Heap(i) => i.next_back(),
+    }
+  }
+}
+impl<'a, A: Array> ExactSizeIterator for TinyBinaryHeapDrain<'a, A> {}
+impl<'a, A: Array> FusedIterator for TinyBinaryHeapDrain<'a, A> {}
+
+/// Iterator over the elements of a [`TinyBinaryHeap`], in heap order.
+pub enum TinyBinaryHeapIter<'a, T> {
+  #[allow(missing_docs)]
+  Inline(core::slice::Iter<'a, T>),
+  #[allow(missing_docs)]
+  Heap(binary_heap::Iter<'a, T>),
+}
+impl<'a, T> Iterator for TinyBinaryHeapIter<'a, T> {
+  type Item = &'a T;
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    match self {
+      TinyBinaryHeapIter::Inline(i) => i.next(),
+      TinyBinaryHeapIter::Heap(i) => i.next(),
+    }
+  }
+  #[inline]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    match self {
+      TinyBinaryHeapIter::Inline(i) => i.size_hint(),
+      TinyBinaryHeapIter::Heap(i) => i.size_hint(),
+    }
+  }
+}
+impl<'a, T> DoubleEndedIterator for TinyBinaryHeapIter<'a, T> {
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    match self {
+      TinyBinaryHeapIter::Inline(i) => i.next_back(),
+      TinyBinaryHeapIter::Heap(i) => i.next_back(),
+    }
+  }
+}
+impl<'a, T> ExactSizeIterator for TinyBinaryHeapIter<'a, T> {}
+impl<'a, T> FusedIterator for TinyBinaryHeapIter<'a, T> {}
+impl<'a, T> Clone for TinyBinaryHeapIter<'a, T> {
+  #[inline]
+  fn clone(&self) -> Self {
+    match self {
+      TinyBinaryHeapIter::Inline(i) => TinyBinaryHeapIter::Inline(i.clone()),
+      TinyBinaryHeapIter::Heap(i) => TinyBinaryHeapIter::Heap(i.clone()),
+    }
+  }
+}
+
+/// Iterator for consuming a `TinyBinaryHeap` and returning owned elements, in
+/// heap order.
+pub enum TinyBinaryHeapIterator<A: Array> {
+  #[allow(missing_docs)]
+  Inline(ArrayVecIterator<A>),
+  #[allow(missing_docs)]
+  Heap(binary_heap::IntoIter<A::Item>),
+}
+impl<A: Array> Iterator for TinyBinaryHeapIterator<A> {
+  type Item = A::Item;
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    match self {
+      TinyBinaryHeapIterator::Inline(i) => i.next(),
+      TinyBinaryHeapIterator::Heap(i) => i.next(),
+    }
+  }
+  #[inline]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    match self {
+      TinyBinaryHeapIterator::Inline(i) => i.size_hint(),
+      TinyBinaryHeapIterator::Heap(i) => i.size_hint(),
+    }
+  }
+}
+impl<A: Array> DoubleEndedIterator for TinyBinaryHeapIterator<A> {
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    match self {
+      TinyBinaryHeapIterator::Inline(i) => i.next_back(),
+      TinyBinaryHeapIterator::Heap(i) => i.next_back(),
+    }
+  }
+}
+impl<A: Array> ExactSizeIterator for TinyBinaryHeapIterator<A> {}
+impl<A: Array> FusedIterator for TinyBinaryHeapIterator<A> {}
+
+impl<T: Ord, A: Array<Item = T>> Extend<T> for TinyBinaryHeap<A> {
+  #[inline]
+  fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+    for t in iter {
+      self.push(t);
+    }
+  }
+}
+
+impl<T: Ord, A: Array<Item = T>> From<ArrayBinaryHeap<A>>
+  for TinyBinaryHeap<A>
+{
+  #[inline(always)]
+  fn from(heap: ArrayBinaryHeap<A>) -> Self {
+    TinyBinaryHeap::Inline(heap)
+  }
+}
+
+impl<T: Ord, A: Array<Item = T>> FromIterator<T> for TinyBinaryHeap<A> {
+  #[inline]
+  fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+    let mut heap = Self::default();
+    heap.extend(iter);
+    heap
+  }
+}
+
+impl<T: Ord, A: Array<Item = T>> IntoIterator for TinyBinaryHeap<A> {
+  type Item = T;
+  type IntoIter = TinyBinaryHeapIterator<A>;
+  /// The elements in heap order, which isn't sorted.
+  #[inline]
+  fn into_iter(self) -> Self::IntoIter {
+    match self {
+      TinyBinaryHeap::Inline(h) => {
+        TinyBinaryHeapIterator::Inline(h.into_iter())
+      }
+      TinyBinaryHeap::Heap(b) => TinyBinaryHeapIterator::Heap(b.into_iter()),
+    }
+  }
+}
+
+impl<'a, T: 'a + Ord, A: Array<Item = T>> IntoIterator
+  for &'a TinyBinaryHeap<A>
+{
+  type Item = &'a T;
+  type IntoIter = TinyBinaryHeapIter<'a, T>;
+  #[inline(always)]
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+
+impl<T: Ord, A: Array<Item = T>> Debug for TinyBinaryHeap<A>
+where
+  T: Debug,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    f.debug_list().entries(self.iter()).finish()
+  }
+}
@@ -0,0 +1,793 @@
+#![cfg(feature = "alloc")]
+
+use super::*;
+
+use alloc::collections::{vec_deque, VecDeque};
+
+/// A double-ended queue that starts inline, but can automatically move to the
+/// heap.
+///
+/// * Requires the `alloc` feature
+///
+/// This is the `VecDeque` counterpart of [`TinyVec`]: while it's `Inline` it's
+/// an [`ArrayDeque`], and if you try to grow it past that capacity it'll
+/// quietly move all of its elements (in order) into a `VecDeque` and continue
+/// operation.
+///
+/// ```rust
+/// use tinyvec::*;
+///
+/// let mut q: TinyDeque<[i32; 2]> = TinyDeque::new();
+/// q.push_back(2);
+/// q.push_front(1);
+/// q.push_back(3);
+/// assert!(matches!(q, TinyDeque::Heap(_)));
+/// assert_eq!(q.pop_front(), Some(1));
+/// assert_eq!(q.pop_front(), Some(2));
+/// assert_eq!(q.pop_front(), Some(3));
+/// ```
+#[derive(Clone)]
+pub enum TinyDeque<A: Array> {
+  #[allow(missing_docs)]
+  Inline(ArrayDeque<A>),
+  #[allow(missing_docs)]
+  Heap(VecDeque<A::Item>),
+}
+impl<A: Array> Default for TinyDeque<A> {
+  #[inline]
+  fn default() -> Self {
+    TinyDeque::Inline(ArrayDeque::default())
+  }
+}
+impl<A: Array> TinyDeque<A> {
+  /// Moves the content of the TinyDeque to the heap, if it's inline.
+  #[allow(clippy::missing_inline_in_public_items)]
+  pub fn move_to_the_heap(&mut self) {
+    match self {
+      TinyDeque::Inline(ref mut q) => {
+        let mut v = VecDeque::with_capacity(A::CAPACITY * 2);
+        for item in q.drain(..) {
+          v.push_back(item);
+        }
+        *self = TinyDeque::Heap(v);
+      }
+      TinyDeque::Heap(_) => (),
+    }
+  }
+}
+
+impl<A: Array> TinyDeque<A> {
+  /// Move all values from `other` onto the back of this deque.
+  #[inline]
+  pub fn append(&mut self, other: &mut Self) {
+    for item in other.drain(..) {
+      self.push_back(item)
+    }
+  }
+
+  /// Gives the contents of the deque as two slices, front part first.
+  #[inline]
+  #[must_use]
+  pub fn as_slices(&self) -> (&[A::Item], &[A::Item]) {
+    match self {
+      TinyDeque::Inline(q) => q.as_slices(),
+      TinyDeque::Heap(v) => v.as_slices(),
+    }
+  }
+
+  /// Gives the contents of the deque as two mutable slices, front part
+  /// first.
+  #[inline]
+  #[must_use]
+  pub fn as_mut_slices(&mut self) -> (&mut [A::Item], &mut [A::Item]) {
+    match self {
+      TinyDeque::Inline(q) => q.as_mut_slices(),
+      TinyDeque::Heap(v) => v.as_mut_slices(),
+    }
+  }
+
+  /// Shared reference to the last element, if any.
+  #[inline]
+  #[must_use]
+  pub fn back(&self) -> Option<&A::Item> {
+    match self {
+      TinyDeque::Inline(q) => q.back(),
+      TinyDeque::Heap(v) => v.back(),
+    }
+  }
+
+  /// Unique reference to the last element, if any.
+  #[inline]
+  #[must_use]
+  pub fn back_mut(&mut self) -> Option<&mut A::Item> {
+    match self {
+      TinyDeque::Inline(q) => q.back_mut(),
+      TinyDeque::Heap(v) => v.back_mut(),
+    }
+  }
+
+  /// The capacity of the `TinyDeque`.
+  ///
+  /// While inline this is fixed based on the array type.
+  #[inline(always)]
+  #[must_use]
+  pub fn capacity(&self) -> usize {
+    match self {
+      TinyDeque::Inline(q) => q.capacity(),
+      TinyDeque::Heap(v) => v.capacity(),
+    }
+  }
+
+  /// Removes all elements from the deque.
+  #[inline(always)]
+  pub fn clear(&mut self) {
+    match self {
+      TinyDeque::Inline(q) => q.clear(),
+      TinyDeque::Heap(v) => v.clear(),
+    }
+  }
+
+  /// If the deque contains an element equal to the given value.
+  #[inline]
+  #[must_use]
+  pub fn contains(&self, x: &A::Item) -> bool
+  where
+    A::Item: PartialEq,
+  {
+    match self {
+      TinyDeque::Inline(q) => q.contains(x),
+      TinyDeque::Heap(v) => v.contains(x),
+    }
+  }
+
+  /// Creates a draining iterator that removes the specified range in the
+  /// deque and yields the removed items.
+  ///
+  /// ## Panics
+  /// * If the start is greater than the end
+  /// * If the end is past the edge of the deque.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut q: TinyDeque<[i32; 4]> = (1..=4).collect();
+  /// let drained: Vec<i32> = q.drain(1..3).collect();
+  /// assert_eq!(drained, vec![2, 3]);
+  /// assert_eq!(q.iter().copied().collect::<Vec<_>>(), vec![1, 4]);
+  /// ```
+  #[inline]
+  pub fn drain<R: RangeBounds<usize>>(
+    &mut self,
+    range: R,
+  ) -> TinyDequeDrain<'_, A> {
+    match self {
+      TinyDeque::Inline(q) => TinyDequeDrain::Inline(q.drain(range)),
+      TinyDeque::Heap(v) => TinyDequeDrain::Heap(v.drain(range)),
+    }
+  }
+
+  /// Shared reference to the first element, if any.
+  #[inline]
+  #[must_use]
+  pub fn front(&self) -> Option<&A::Item> {
+    match self {
+      TinyDeque::Inline(q) => q.front(),
+      TinyDeque::Heap(v) => v.front(),
+    }
+  }
+
+  /// Unique reference to the first element, if any.
+  #[inline]
+  #[must_use]
+  pub fn front_mut(&mut self) -> Option<&mut A::Item> {
+    match self {
+      TinyDeque::Inline(q) => q.front_mut(),
+      TinyDeque::Heap(v) => v.front_mut(),
+    }
+  }
+
+  /// Shared reference to the element at the given logical index, if any.
+  #[inline]
+  #[must_use]
+  pub fn get(&self, index: usize) -> Option<&A::Item> {
+    match self {
+      TinyDeque::Inline(q) => q.get(index),
+      TinyDeque::Heap(v) => v.get(index),
+    }
+  }
+
+  /// Unique reference to the element at the given logical index, if any.
+  #[inline]
+  #[must_use]
+  pub fn get_mut(&mut self, index: usize) -> Option<&mut A::Item> {
+    match self {
+      TinyDeque::Inline(q) => q.get_mut(index),
+      TinyDeque::Heap(v) => v.get_mut(index),
+    }
+  }
+
+  /// If the deque is empty.
+  #[inline(always)]
+  #[must_use]
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Iterates the deque by shared reference, front to back.
+  #[inline]
+  #[must_use]
+  pub fn iter(&self) -> TinyDequeIter<'_, A::Item> {
+    match self {
+      TinyDeque::Inline(q) => TinyDequeIter::Inline(q.iter()),
+      TinyDeque::Heap(v) => TinyDequeIter::Heap(v.iter()),
+    }
+  }
+
+  /// Iterates the deque by unique reference, front to back.
+  #[inline]
+  #[must_use]
+  pub fn iter_mut(&mut self) -> TinyDequeIterMut<'_, A::Item> {
+    match self {
+      TinyDeque::Inline(q) => TinyDequeIterMut::Inline(q.iter_mut()),
+      TinyDeque::Heap(v) => TinyDequeIterMut::Heap(v.iter_mut()),
+    }
+  }
+
+  /// The length of the deque (in elements).
+  #[inline(always)]
+  #[must_use]
+  pub fn len(&self) -> usize {
+    match self {
+      TinyDeque::Inline(q) => q.len(),
+      TinyDeque::Heap(v) => v.len(),
+    }
+  }
+
+  /// Rearranges the storage so that the contents are one slice, and returns
+  /// that slice.
+  #[inline]
+  pub fn make_contiguous(&mut self) -> &mut [A::Item] {
+    match self {
+      TinyDeque::Inline(q) => q.make_contiguous(),
+      TinyDeque::Heap(v) => v.make_contiguous(),
+    }
+  }
+
+  /// Makes a new, empty deque.
+  #[inline(always)]
+  #[must_use]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Remove and return the last element of the deque, if there is one.
+  ///
+  /// ## Failure
+  /// * If the deque is empty you get `None`.
+  #[inline]
+  pub fn pop_back(&mut self) -> Option<A::Item> {
+    match self {
+      TinyDeque::Inline(q) => q.pop_back(),
+      TinyDeque::Heap(v) => v.pop_back(),
+    }
+  }
+
+  /// Remove and return the first element of the deque, if there is one.
+  ///
+  /// ## Failure
+  /// * If the deque is empty you get `None`.
+  #[inline]
+  pub fn pop_front(&mut self) -> Option<A::Item> {
+    match self {
+      TinyDeque::Inline(q) => q.pop_front(),
+      TinyDeque::Heap(v) => v.pop_front(),
+    }
+  }
+
+  /// Place an element onto the back of the deque.
+  ///
+  /// If the deque is inline and full it moves to the heap first.
+  #[inline(always)]
+  pub fn push_back(&mut self, val: A::Item) {
+    match self {
+      TinyDeque::Inline(q) => {
+        if let Err(val) = q.try_push_back(val) {
+          self.move_to_the_heap();
+          self.push_back(val)
+        }
+      }
+      TinyDeque::Heap(v) => v.push_back(val),
+    }
+  }
+
+  /// Place an element onto the front of the deque.
+  ///
+  /// If the deque is inline and full it moves to the heap first.
+  #[inline(always)]
+  pub fn push_front(&mut self, val: A::Item) {
+    match self {
+      TinyDeque::Inline(q) => {
+        if let Err(val) = q.try_push_front(val) {
+          self.move_to_the_heap();
+          self.push_front(val)
+        }
+      }
+      TinyDeque::Heap(v) => v.push_front(val),
+    }
+  }
+
+  /// Rotates the deque `n` places to the left.
+  ///
+  /// ## Panics
+  /// * If `n` > `len`
+  #[inline]
+  pub fn rotate_left(&mut self, n: usize) {
+    match self {
+      TinyDeque::Inline(q) => q.rotate_left(n),
+      TinyDeque::Heap(v) => v.rotate_left(n),
+    }
+  }
+
+  /// Rotates the deque `n` places to the right.
+  ///
+  /// ## Panics
+  /// * If `n` > `len`
+  #[inline]
+  pub fn rotate_right(&mut self, n: usize) {
+    match self {
+      TinyDeque::Inline(q) => q.rotate_right(n),
+      TinyDeque::Heap(v) => v.rotate_right(n),
+    }
+  }
+
+  /// Swaps the elements at the two logical indexes given.
+  ///
+  /// ## Panics
+  /// * If either index is out of bounds.
+  #[inline]
+  pub fn swap(&mut self, a: usize, b: usize) {
+    match self {
+      TinyDeque::Inline(q) => q.swap(a, b),
+      TinyDeque::Heap(v) => v.swap(a, b),
+    }
+  }
+
+  /// Reduces the deque's length to the given value, dropping from the back.
+  ///
+  /// If the deque is already shorter than the input, nothing happens.
+  #[inline]
+  pub fn truncate(&mut self, new_len: usize) {
+    match self {
+      TinyDeque::Inline(q) => q.truncate(new_len),
+      TinyDeque::Heap(v) => v.truncate(new_len),
+    }
+  }
+}
+
+/// Draining iterator for `TinyDeque`
+///
+/// See [`TinyDeque::drain`](TinyDeque::<A>::drain)
+pub enum TinyDequeDrain<'p, A: Array> {
+  #[allow(missing_docs)]
+  Inline(ArrayDequeDrain<'p, A>),
+  #[allow(missing_docs)]
+  Heap(vec_deque::Drain<'p, A::Item>),
+}
+impl<'p, A: Array> Iterator for TinyDequeDrain<'p, A> {
+  type Item = A::Item;
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    match self {
+      TinyDequeDrain::Inline(d) => d.next(),
+      TinyDequeDrain::Heap(d) => d.next(),
+    }
+  }
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    match self {
+      TinyDequeDrain::Inline(d) => d.size_hint(),
+      TinyDequeDrain::Heap(d) => d.size_hint(),
+    }
+  }
+}
+impl<'p, A: Array> DoubleEndedIterator for TinyDequeDrain<'p, A> {
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    match self {
+      TinyDequeDrain::Inline(d) => d.next_back(),
+      TinyDequeDrain::Heap(d) => d.next_back(),
+    }
+  }
+}
+impl<'p, A: Array> ExactSizeIterator for TinyDequeDrain<'p, A> {}
+impl<'p, A: Array> FusedIterator for TinyDequeDrain<'p, A> {}
+
+/// Iterator for consuming a `TinyDeque` and returning owned elements.
+pub enum TinyDequeIterator<A: Array> {
+  #[allow(missing_docs)]
+  Inline(ArrayDequeIterator<A>),
+  #[allow(missing_docs)]
+  Heap(vec_deque::IntoIter<A::Item>),
+}
+impl<A: Array> Iterator for TinyDequeIterator<A> {
+  type Item = A::Item;
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    match self {
+      TinyDequeIterator::Inline(i) => i.next(),
+      TinyDequeIterator::Heap(i) => i.next(),
+    }
+  }
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    match self {
+      TinyDequeIterator::Inline(i) => i.size_hint(),
+      TinyDequeIterator::Heap(i) => i.size_hint(),
+    }
+  }
+}
+impl<A: Array> DoubleEndedIterator for TinyDequeIterator<A> {
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    match self {
+      TinyDequeIterator::Inline(i) => i.next_back(),
+      TinyDequeIterator::Heap(i) => i.next_back(),
+    }
+  }
+}
+impl<A: Array> ExactSizeIterator for TinyDequeIterator<A> {}
+impl<A: Array> FusedIterator for TinyDequeIterator<A> {}
+
+/// Iterator over shared references to the elements of a `TinyDeque`.
+pub enum TinyDequeIter<'a, T> {
+  #[allow(missing_docs)]
+  Inline(ArrayDequeIter<'a, T>),
+  #[allow(missing_docs)]
+  Heap(vec_deque::Iter<'a, T>),
+}
+impl<'a, T> Iterator for TinyDequeIter<'a, T> {
+  type Item = &'a T;
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    match self {
+      TinyDequeIter::Inline(i) => i.next(),
+      TinyDequeIter::Heap(i) => i.next(),
+    }
+  }
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    match self {
+      TinyDequeIter::Inline(i) => i.size_hint(),
+      TinyDequeIter::Heap(i) => i.size_hint(),
+    }
+  }
+}
+impl<'a, T> DoubleEndedIterator for TinyDequeIter<'a, T> {
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    match self {
+      TinyDequeIter::Inline(i) => i.next_back(),
+      TinyDequeIter::Heap(i) => i.next_back(),
+    }
+  }
+}
+impl<'a, T> ExactSizeIterator for TinyDequeIter<'a, T> {}
+impl<'a, T> FusedIterator for TinyDequeIter<'a, T> {}
+
+/// Iterator over unique references to the elements of a `TinyDeque`.
+pub enum TinyDequeIterMut<'a, T> {
+  #[allow(missing_docs)]
+  Inline(ArrayDequeIterMut<'a, T>),
+  #[allow(missing_docs)]
+  Heap(vec_deque::IterMut<'a, T>),
+}
+impl<'a, T> Iterator for TinyDequeIterMut<'a, T> {
+  type Item = &'a mut T;
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    match self {
+      TinyDequeIterMut::Inline(i) => i.next(),
+      TinyDequeIterMut::Heap(i) => i.next(),
+    }
+  }
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    match self {
+      TinyDequeIterMut::Inline(i) => i.size_hint(),
+      TinyDequeIterMut::Heap(i) => i.size_hint(),
+    }
+  }
+}
+impl<'a, T> DoubleEndedIterator for TinyDequeIterMut<'a, T> {
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    match self {
+      TinyDequeIterMut::Inline(i) => i.next_back(),
+      TinyDequeIterMut::Heap(i) => i.next_back(),
+    }
+  }
+}
+impl<'a, T> ExactSizeIterator for TinyDequeIterMut<'a, T> {}
+impl<'a, T> FusedIterator for TinyDequeIterMut<'a, T> {}
+
+impl<A: Array> Index<usize> for TinyDeque<A> {
+  type Output = A::Item;
+  #[inline]
+  fn index(&self, index: usize) -> &Self::Output {
+    match self {
+      TinyDeque::Inline(q) => &q[index],
+      TinyDeque::Heap(v) => &v[index],
+    }
+  }
+}
+
+impl<A: Array> IndexMut<usize> for TinyDeque<A> {
+  #[inline]
+  fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+    match self {
+      TinyDeque::Inline(q) => &mut q[index],
+      TinyDeque::Heap(v) => &mut v[index],
+    }
+  }
+}
+
+impl<A: Array> Extend<A::Item> for TinyDeque<A> {
+  #[inline]
+  fn extend<T: IntoIterator<Item = A::Item>>(&mut self, iter: T) {
+    for t in iter {
+      self.push_back(t)
+    }
+  }
+}
+
+impl<A: Array> From<ArrayDeque<A>> for TinyDeque<A> {
+  #[inline(always)]
+  fn from(q: ArrayDeque<A>) -> Self {
+    TinyDeque::Inline(q)
+  }
+}
+
+impl<A: Array> From<VecDeque<A::Item>> for TinyDeque<A> {
+  #[inline(always)]
+  fn from(v: VecDeque<A::Item>) -> Self {
+    TinyDeque::Heap(v)
+  }
+}
+
+impl<A: Array> FromIterator<A::Item> for TinyDeque<A> {
+  #[inline]
+  fn from_iter<T: IntoIterator<Item = A::Item>>(iter: T) -> Self {
+    let mut q = Self::default();
+    for i in iter {
+      q.push_back(i)
+    }
+    q
+  }
+}
+
+impl<A: Array> IntoIterator for TinyDeque<A> {
+  type Item = A::Item;
+  type IntoIter = TinyDequeIterator<A>;
+  #[inline(always)]
+  fn into_iter(self) -> Self::IntoIter {
+    match self {
+      TinyDeque::Inline(q) => TinyDequeIterator::Inline(q.into_iter()),
+      TinyDeque::Heap(v) => TinyDequeIterator::Heap(v.into_iter()),
+    }
+  }
+}
+
+impl<'a, A: Array> IntoIterator for &'a TinyDeque<A> {
+  type Item = &'a A::Item;
+  type IntoIter = TinyDequeIter<'a, A::Item>;
+  #[inline(always)]
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+
+impl<'a, A: Array> IntoIterator for &'a mut TinyDeque<A> {
+  type Item = &'a mut A::Item;
+  type IntoIter = TinyDequeIterMut<'a, A::Item>;
+  #[inline(always)]
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter_mut()
+  }
+}
+
+impl<A: Array> PartialEq for TinyDeque<A>
+where
+  A::Item: PartialEq,
+{
+  #[inline]
+  fn eq(&self, other: &Self) -> bool {
+    self.len() == other.len() && self.iter().eq(other.iter())
+  }
+}
+impl<A: Array> Eq for TinyDeque<A> where A::Item: Eq {}
+
+impl<A: Array> PartialOrd for TinyDeque<A>
+where
+  A::Item: PartialOrd,
+{
+  #[inline]
+  fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+    self.iter().partial_cmp(other.iter())
+  }
+}
+impl<A: Array> Ord for TinyDeque<A>
+where
+  A::Item: Ord,
+{
+  #[inline]
+  fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+    self.iter().cmp(other.iter())
+  }
+}
+
+impl<A: Array> PartialEq<&[A::Item]> for TinyDeque<A>
+where
+  A::Item: PartialEq,
+{
+  #[inline]
+  fn eq(&self, other: &&[A::Item]) -> bool {
+    self.len() == other.len() && self.iter().eq(other.iter())
+  }
+}
+
+// //
+// Formatting impls
+// //
+
+impl<A: Array> Binary for TinyDeque<A>
+where
+  A::Item: Binary,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      Binary::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<A: Array> Debug for TinyDeque<A>
+where
+  A::Item: Debug,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      Debug::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<A: Array> Display for TinyDeque<A>
+where
+  A::Item: Display,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      Display::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<A: Array> LowerExp for TinyDeque<A>
+where
+  A::Item: LowerExp,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      LowerExp::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<A: Array> LowerHex for TinyDeque<A>
+where
+  A::Item: LowerHex,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      LowerHex::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<A: Array> Octal for TinyDeque<A>
+where
+  A::Item: Octal,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      Octal::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<A: Array> Pointer for TinyDeque<A>
+where
+  A::Item: Pointer,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      Pointer::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<A: Array> UpperExp for TinyDeque<A>
+where
+  A::Item: UpperExp,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      UpperExp::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<A: Array> UpperHex for TinyDeque<A>
+where
+  A::Item: UpperHex,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      UpperHex::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
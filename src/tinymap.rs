@@ -0,0 +1,594 @@
+#![cfg(feature = "alloc")]
+
+use super::*;
+
+use alloc::collections::{btree_map, BTreeMap};
+use core::ops::Bound;
+
+/// A sorted map that starts inline, but can automatically move to the heap.
+///
+/// * Requires the `alloc` feature
+///
+/// This is the `BTreeMap` counterpart of [`TinyVec`]: while it's `Inline`
+/// it's an [`ArrayMap`], and if you try to insert a new key past that
+/// capacity it'll quietly move all of its pairs into a `BTreeMap` and continue
+/// operation. Either way, iteration is in key order.
+///
+/// ```rust
+/// use tinyvec::*;
+///
+/// let mut m: TinyMap<[(u32, &str); 2]> = TinyMap::new();
+/// m.insert(2, "b");
+/// m.insert(1, "a");
+/// assert!(matches!(m, TinyMap::Inline(_)));
+/// m.insert(3, "c");
+/// assert!(matches!(m, TinyMap::Heap(_)));
+/// assert_eq!(m.values().copied().collect::<Vec<_>>(), ["a", "b", "c"]);
+/// ```
+pub enum TinyMap<A: Array>
+where
+  A::Item: KeyValue,
+{
+  #[allow(missing_docs)]
+  Inline(ArrayMap<A>),
+  #[allow(missing_docs)]
+  Heap(BTreeMap<<A::Item as KeyValue>::Key, <A::Item as KeyValue>::Value>),
+}
+
+impl<K, V, A: Array<Item = (K, V)>> Clone for TinyMap<A>
+where
+  ArrayMap<A>: Clone,
+  BTreeMap<K, V>: Clone,
+{
+  #[inline]
+  fn clone(&self) -> Self {
+    match self {
+      TinyMap::Inline(m) => TinyMap::Inline(m.clone()),
+      TinyMap::Heap(b) => TinyMap::Heap(b.clone()),
+    }
+  }
+}
+
+impl<K, V, A: Array<Item = (K, V)>> Default for TinyMap<A> {
+  #[inline]
+  fn default() -> Self {
+    TinyMap::Inline(ArrayMap::default())
+  }
+}
+
+impl<K: Ord, V, A: Array<Item = (K, V)>> TinyMap<A> {
+  /// Removes all pairs from the map.
+  ///
+  /// A map on the heap stays on the heap.
+  #[inline]
+  pub fn clear(&mut self) {
+    match self {
+      TinyMap::Inline(m) => m.clear(),
+      TinyMap::Heap(b) => b.clear(),
+    }
+  }
+
+  /// If the map has a value for the key.
+  #[inline]
+  #[must_use]
+  pub fn contains_key<Q>(&self, key: &Q) -> bool
+  where
+    K: Borrow<Q>,
+    Q: Ord + ?Sized,
+  {
+    match self {
+      TinyMap::Inline(m) => m.contains_key(key),
+      TinyMap::Heap(b) => b.contains_key(key),
+    }
+  }
+
+  /// Gets the entry for the key, to inspect or change it in place.
+  ///
+  /// If the key is new and the inline array is full, this moves the map to
+  /// the heap first, so inserting through the entry can't fail.
+  #[inline]
+  pub fn entry(&mut self, key: K) -> TinyMapEntry<'_, A> {
+    if let TinyMap::Inline(m) = self {
+      if m.len() == m.capacity() && !m.contains_key(&key) {
+        self.move_to_the_heap();
+      }
+    }
+    match self {
+      TinyMap::Inline(m) => TinyMapEntry::Inline(m.entry(key)),
+      TinyMap::Heap(b) => TinyMapEntry::Heap(b.entry(key)),
+    }
+  }
+
+  /// The pair with the smallest key, if any.
+  #[inline]
+  #[must_use]
+  pub fn first_key_value(&self) -> Option<(&K, &V)> {
+    match self {
+      TinyMap::Inline(m) => m.first_key_value(),
+      TinyMap::Heap(b) => b.iter().next(),
+    }
+  }
+
+  /// The value for the key, if any.
+  #[inline]
+  #[must_use]
+  pub fn get<'a, Q>(&'a self, key: &Q) -> Option<&'a V>
+  where
+    K: 'a + Borrow<Q>,
+    Q: Ord + ?Sized,
+  {
+    match self {
+      TinyMap::Inline(m) => m.get(key),
+      TinyMap::Heap(b) => b.get(key),
+    }
+  }
+
+  /// The stored key and the value for the key, if any.
+  #[inline]
+  #[must_use]
+  pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+  where
+    K: Borrow<Q>,
+    Q: Ord + ?Sized,
+  {
+    match self {
+      TinyMap::Inline(m) => m.get_key_value(key),
+      TinyMap::Heap(b) => {
+        b.range::<Q, _>((Bound::Included(key), Bound::Included(key))).next()
+      }
+    }
+  }
+
+  /// The value for the key, if any.
+  #[inline]
+  #[must_use]
+  pub fn get_mut<'a, Q>(&'a mut self, key: &Q) -> Option<&'a mut V>
+  where
+    K: 'a + Borrow<Q>,
+    Q: Ord + ?Sized,
+  {
+    match self {
+      TinyMap::Inline(m) => m.get_mut(key),
+      TinyMap::Heap(b) => b.get_mut(key),
+    }
+  }
+
+  /// Inserts a pair into the map, moving to the heap if the key is new and
+  /// the inline array is full.
+  ///
+  /// If the key was already present the old value is replaced (the old key is
+  /// kept) and returned.
+  #[inline]
+  pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+    match self {
+      TinyMap::Inline(m) => match m.try_insert(key, value) {
+        Ok(old) => old,
+        Err(e) => {
+          let (key, value) = e.element();
+          self.move_to_the_heap();
+          self.insert(key, value)
+        }
+      },
+      TinyMap::Heap(b) => b.insert(key, value),
+    }
+  }
+
+  /// If the map is empty.
+  #[inline]
+  #[must_use]
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Iterates over the pairs, in key order.
+  #[inline]
+  #[must_use]
+  pub fn iter(&self) -> TinyMapIter<'_, K, V> {
+    match self {
+      TinyMap::Inline(m) => TinyMapIter::Inline(m.iter()),
+      TinyMap::Heap(b) => TinyMapIter::Heap(b.range::<K, _>(..)),
+    }
+  }
+
+  /// Iterates over the pairs in key order, with unique access to the values.
+  #[inline]
+  #[must_use]
+  pub fn iter_mut(&mut self) -> TinyMapIterMut<'_, K, V> {
+    match self {
+      TinyMap::Inline(m) => TinyMapIterMut::Inline(m.iter_mut()),
+      TinyMap::Heap(b) => TinyMapIterMut::Heap(b.range_mut::<K, _>(..)),
+    }
+  }
+
+  /// Iterates over the keys, in order.
+  #[inline]
+  pub fn keys(&self) -> MapKeys<TinyMapIter<'_, K, V>> {
+    MapKeys::new(self.iter())
+  }
+
+  /// The pair with the largest key, if any.
+  #[inline]
+  #[must_use]
+  pub fn last_key_value(&self) -> Option<(&K, &V)> {
+    match self {
+      TinyMap::Inline(m) => m.last_key_value(),
+      TinyMap::Heap(b) => b.iter().next_back(),
+    }
+  }
+
+  /// The number of pairs in the map.
+  #[inline]
+  #[must_use]
+  pub fn len(&self) -> usize {
+    match self {
+      TinyMap::Inline(m) => m.len(),
+      TinyMap::Heap(b) => b.len(),
+    }
+  }
+
+  /// Moves the content of the TinyMap to the heap, if it's inline.
+  #[allow(clippy::missing_inline_in_public_items)]
+  pub fn move_to_the_heap(&mut self) {
+    if let TinyMap::Inline(m) = self {
+      let b: BTreeMap<K, V> = replace(m, ArrayMap::new()).into_iter().collect();
+      *self = TinyMap::Heap(b);
+    }
+  }
+
+  /// Makes a new, empty map.
+  #[inline(always)]
+  #[must_use]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Iterates over the pairs with keys in the range, in key order.
+  ///
+  /// ## Panics
+  /// * If the range's start is after its end, or if the start and end are
+  ///   equal and both excluded.
+  #[inline]
+  pub fn range<Q, R>(&self, range: R) -> TinyMapIter<'_, K, V>
+  where
+    K: Borrow<Q>,
+    Q: Ord + ?Sized,
+    R: RangeBounds<Q>,
+  {
+    match self {
+      TinyMap::Inline(m) => TinyMapIter::Inline(m.range(range)),
+      TinyMap::Heap(b) => TinyMapIter::Heap(b.range(range)),
+    }
+  }
+
+  /// Iterates over the pairs with keys in the range in key order, with unique
+  /// access to the values.
+  ///
+  /// ## Panics
+  /// * The same as [`range`](TinyMap::range).
+  #[inline]
+  pub fn range_mut<Q, R>(&mut self, range: R) -> TinyMapIterMut<'_, K, V>
+  where
+    K: Borrow<Q>,
+    Q: Ord + ?Sized,
+    R: RangeBounds<Q>,
+  {
+    match self {
+      TinyMap::Inline(m) => TinyMapIterMut::Inline(m.range_mut(range)),
+      TinyMap::Heap(b) => TinyMapIterMut::Heap(b.range_mut(range)),
+    }
+  }
+
+  /// Removes the key from the map, returning its value if it was present.
+  #[inline]
+  pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+  where
+    K: Borrow<Q>,
+    Q: Ord + ?Sized,
+  {
+    match self {
+      TinyMap::Inline(m) => m.remove(key),
+      TinyMap::Heap(b) => b.remove(key),
+    }
+  }
+
+  /// Keeps only the pairs that pass the predicate given.
+  #[inline]
+  pub fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut acceptable: F) {
+    match self {
+      TinyMap::Inline(m) => m.retain(acceptable),
+      TinyMap::Heap(b) => {
+        // `mem::take` needs Rust 1.40, and `BTreeMap::retain` needs 1.53.
+        #[allow(clippy::mem_replace_with_default)]
+        let old = replace(b, BTreeMap::new());
+        for (k, mut v) in old {
+          if acceptable(&k, &mut v) {
+            b.insert(k, v);
+          }
+        }
+      }
+    }
+  }
+
+  /// Iterates over the values, in key order.
+  #[inline]
+  pub fn values(&self) -> MapValues<TinyMapIter<'_, K, V>> {
+    MapValues::new(self.iter())
+  }
+
+  /// Iterates over unique references to the values, in key order.
+  #[inline]
+  pub fn values_mut(&mut self) -> MapValues<TinyMapIterMut<'_, K, V>> {
+    MapValues::new(self.iter_mut())
+  }
+}
+
+/// A view into a single entry of a [`TinyMap`], which is either occupied or
+/// vacant.
+///
+/// See [`TinyMap::entry`]
+pub enum TinyMapEntry<'a, A: Array>
+where
+  A::Item: KeyValue,
+{
+  #[allow(missing_docs)]
+  Inline(ArrayMapEntry<'a, A>),
+  #[allow(missing_docs)]
+  Heap(
+    btree_map::Entry<
+      'a,
+      <A::Item as KeyValue>::Key,
+      <A::Item as KeyValue>::Value,
+    >,
+  ),
+}
+
+impl<'a, K: 'a + Ord, V: 'a, A: Array<Item = (K, V)>> TinyMapEntry<'a, A> {
+  /// Calls the function on the value if the entry is occupied.
+  #[inline]
+  pub fn and_modify<F: FnOnce(&mut V)>(self, f: F) -> Self {
+    match self {
+      TinyMapEntry::Inline(e) => TinyMapEntry::Inline(e.and_modify(f)),
+      TinyMapEntry::Heap(e) => TinyMapEntry::Heap(e.and_modify(f)),
+    }
+  }
+
+  /// The key of the entry.
+  #[inline]
+  #[must_use]
+  pub fn key(&self) -> &K {
+    match self {
+      TinyMapEntry::Inline(e) => e.key(),
+      TinyMapEntry::Heap(e) => e.key(),
+    }
+  }
+
+  /// The value of the entry, after inserting `V::default()` if it's vacant.
+  #[inline]
+  pub fn or_default(self) -> &'a mut V
+  where
+    V: Default,
+  {
+    self.or_insert_with(V::default)
+  }
+
+  /// The value of the entry, after inserting `default` if it's vacant.
+  #[inline]
+  pub fn or_insert(self, default: V) -> &'a mut V {
+    self.or_insert_with(|| default)
+  }
+
+  /// The value of the entry, after inserting the output of the function if
+  /// it's vacant.
+  #[inline]
+  pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+    match self {
+      TinyMapEntry::Inline(e) => e.or_insert_with(default),
+      TinyMapEntry::Heap(e) => e.or_insert_with(default),
+    }
+  }
+}
+
+/// Iterator over the pairs of a [`TinyMap`], in key order.
+pub enum TinyMapIter<'a, K, V> {
+  #[allow(missing_docs)]
+  Inline(ArrayMapIter<'a, K, V>),
+  #[allow(missing_docs)]
+  Heap(btree_map::Range<'a, K, V>),
+}
+impl<'a, K, V> Iterator for TinyMapIter<'a, K, V> {
+  type Item = (&'a K, &'a V);
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    match self {
+      TinyMapIter::Inline(i) => i.next(),
+      TinyMapIter::Heap(i) => i.next(),
+    }
+  }
+  #[inline]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    match self {
+      TinyMapIter::Inline(i) => i.size_hint(),
+      TinyMapIter::Heap(i) => i.size_hint(),
+    }
+  }
+}
+impl<'a, K, V> DoubleEndedIterator for TinyMapIter<'a, K, V> {
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    match self {
+      TinyMapIter::Inline(i) => i.next_back(),
+      TinyMapIter::Heap(i) => i.next_back(),
+    }
+  }
+}
+impl<'a, K, V> FusedIterator for TinyMapIter<'a, K, V> {}
+impl<'a, K, V> Clone for TinyMapIter<'a, K, V> {
+  #[inline]
+  fn clone(&self) -> Self {
+    match self {
+      TinyMapIter::Inline(i) => TinyMapIter::Inline(i.clone()),
+      TinyMapIter::Heap(i) => TinyMapIter::Heap(i.clone()),
+    }
+  }
+}
+
+/// Iterator over the pairs of a [`TinyMap`] in key order, with unique access
+/// to the values.
+pub enum TinyMapIterMut<'a, K, V> {
+  #[allow(missing_docs)]
+  Inline(ArrayMapIterMut<'a, K, V>),
+  #[allow(missing_docs)]
+  Heap(btree_map::RangeMut<'a, K, V>),
+}
+impl<'a, K, V> Iterator for TinyMapIterMut<'a, K, V> {
+  type Item = (&'a K, &'a mut V);
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    match self {
+      TinyMapIterMut::Inline(i) => i.next(),
+      TinyMapIterMut::Heap(i) => i.next(),
+    }
+  }
+  #[inline]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    match self {
+      TinyMapIterMut::Inline(i) => i.size_hint(),
+      TinyMapIterMut::Heap(i) => i.size_hint(),
+    }
+  }
+}
+impl<'a, K, V> DoubleEndedIterator for TinyMapIterMut<'a, K, V> {
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    match self {
+      TinyMapIterMut::Inline(i) => i.next_back(),
+      TinyMapIterMut::Heap(i) => i.next_back(),
+    }
+  }
+}
+impl<'a, K, V> FusedIterator for TinyMapIterMut<'a, K, V> {}
+
+/// Iterator for consuming a `TinyMap` and returning owned pairs, in key order.
+pub enum TinyMapIterator<A: Array>
+where
+  A::Item: KeyValue,
+{
+  #[allow(missing_docs)]
+  Inline(ArrayVecIterator<A>),
+  #[allow(missing_docs)]
+  Heap(
+    btree_map::IntoIter<
+      <A::Item as KeyValue>::Key,
+      <A::Item as KeyValue>::Value,
+    >,
+  ),
+}
+impl<K, V, A: Array<Item = (K, V)>> Iterator for TinyMapIterator<A> {
+  type Item = (K, V);
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    match self {
+      TinyMapIterator::Inline(i) => i.next(),
+      TinyMapIterator::Heap(i) => i.next(),
+    }
+  }
+  #[inline]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    match self {
+      TinyMapIterator::Inline(i) => i.size_hint(),
+      TinyMapIterator::Heap(i) => i.size_hint(),
+    }
+  }
+}
+impl<K, V, A: Array<Item = (K, V)>> DoubleEndedIterator for TinyMapIterator<A> {
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    match self {
+      TinyMapIterator::Inline(i) => i.next_back(),
+      TinyMapIterator::Heap(i) => i.next_back(),
+    }
+  }
+}
+impl<K, V, A: Array<Item = (K, V)>> ExactSizeIterator for TinyMapIterator<A> {}
+impl<K, V, A: Array<Item = (K, V)>> FusedIterator for TinyMapIterator<A> {}
+
+impl<K: Ord, V, A: Array<Item = (K, V)>> Extend<(K, V)> for TinyMap<A> {
+  #[inline]
+  fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+    for (k, v) in iter {
+      self.insert(k, v);
+    }
+  }
+}
+
+impl<K: Ord, V, A: Array<Item = (K, V)>> From<ArrayMap<A>> for TinyMap<A> {
+  #[inline(always)]
+  fn from(map: ArrayMap<A>) -> Self {
+    TinyMap::Inline(map)
+  }
+}
+
+impl<K: Ord, V, A: Array<Item = (K, V)>> FromIterator<(K, V)> for TinyMap<A> {
+  #[inline]
+  fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+    let mut map = Self::default();
+    map.extend(iter);
+    map
+  }
+}
+
+impl<K: Ord, V, A: Array<Item = (K, V)>> IntoIterator for TinyMap<A> {
+  type Item = (K, V);
+  type IntoIter = TinyMapIterator<A>;
+  #[inline]
+  fn into_iter(self) -> Self::IntoIter {
+    match self {
+      TinyMap::Inline(m) => TinyMapIterator::Inline(m.into_iter()),
+      TinyMap::Heap(b) => TinyMapIterator::Heap(b.into_iter()),
+    }
+  }
+}
+
+impl<'a, K: 'a + Ord, V: 'a, A: Array<Item = (K, V)>> IntoIterator
+  for &'a TinyMap<A>
+{
+  type Item = (&'a K, &'a V);
+  type IntoIter = TinyMapIter<'a, K, V>;
+  #[inline(always)]
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+
+impl<'a, K: 'a + Ord, V: 'a, A: Array<Item = (K, V)>> IntoIterator
+  for &'a mut TinyMap<A>
+{
+  type Item = (&'a K, &'a mut V);
+  type IntoIter = TinyMapIterMut<'a, K, V>;
+  #[inline(always)]
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter_mut()
+  }
+}
+
+impl<K: Ord, V, A: Array<Item = (K, V)>> PartialEq for TinyMap<A>
+where
+  V: PartialEq,
+{
+  #[inline]
+  fn eq(&self, other: &Self) -> bool {
+    self.len() == other.len() && self.iter().eq(other.iter())
+  }
+}
+impl<K: Ord, V, A: Array<Item = (K, V)>> Eq for TinyMap<A> where V: Eq {}
+
+impl<K: Ord, V, A: Array<Item = (K, V)>> Debug for TinyMap<A>
+where
+  K: Debug,
+  V: Debug,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    f.debug_map().entries(self.iter()).finish()
+  }
+}
@@ -0,0 +1,704 @@
+#![cfg(feature = "alloc")]
+
+use super::*;
+
+/// A vector of elements without a `Default` that starts inline, but can
+/// automatically move to the heap.
+///
+/// * Requires the `alloc` feature
+///
+/// This is to [`OptionArrayVec`] what [`TinyVec`] is to [`ArrayVec`]: the
+/// slots are stored as `Option<T>` in a `TinyVec`, so it moves to the heap
+/// (following the [`Sticky`] spill policy) when the inline array is full.
+///
+/// ```rust
+/// use tinyvec::*;
+///
+/// struct Handle(u32);
+///
+/// let mut handles: TinyOptionVec<[Option<Handle>; 2]> = TinyOptionVec::new();
+/// handles.push(Handle(1));
+/// handles.push(Handle(2));
+/// assert!(handles.is_inline());
+/// handles.push(Handle(3));
+/// assert!(!handles.is_inline());
+/// assert_eq!(handles.iter().map(|h| h.0).sum::<u32>(), 6);
+/// ```
+#[repr(transparent)]
+pub struct TinyOptionVec<A: Array> {
+  vec: TinyVec<A>,
+}
+
+impl<A: Array> Clone for TinyOptionVec<A>
+where
+  TinyVec<A>: Clone,
+{
+  #[inline]
+  fn clone(&self) -> Self {
+    Self { vec: self.vec.clone() }
+  }
+}
+
+impl<A: Array> Default for TinyOptionVec<A> {
+  #[inline]
+  fn default() -> Self {
+    Self { vec: TinyVec::default() }
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> TinyOptionVec<A> {
+  /// Move all values from `other` into this vec.
+  #[inline]
+  pub fn append(&mut self, other: &mut Self) {
+    self.vec.append(&mut other.vec)
+  }
+
+  /// The slots of the live part of the vec, which are all `Some`.
+  #[inline(always)]
+  #[must_use]
+  pub fn as_option_slice(&self) -> &[Option<T>] {
+    self.vec.as_slice()
+  }
+
+  /// The capacity of the vec.
+  #[inline(always)]
+  #[must_use]
+  pub fn capacity(&self) -> usize {
+    self.vec.capacity()
+  }
+
+  /// Removes all elements from the vec.
+  #[inline(always)]
+  pub fn clear(&mut self) {
+    self.vec.clear()
+  }
+
+  /// Creates a draining iterator that removes the specified range in the vector
+  /// and yields the removed items.
+  ///
+  /// ## Panics
+  /// * If the start is greater than the end
+  /// * If the end is past the edge of the vec.
+  #[inline]
+  pub fn drain<R: RangeBounds<usize>>(
+    &mut self,
+    range: R,
+  ) -> TinyOptionVecDrain<'_, A> {
+    TinyOptionVecDrain { inner: self.vec.drain(range) }
+  }
+
+  /// Clone each element of the slice into this vec.
+  #[inline]
+  pub fn extend_from_slice(&mut self, sli: &[T])
+  where
+    T: Clone,
+  {
+    self.reserve(sli.len());
+    for item in sli {
+      self.push(item.clone());
+    }
+  }
+
+  /// The first element, if any.
+  #[inline]
+  #[must_use]
+  pub fn first(&self) -> Option<&T> {
+    self.get(0)
+  }
+
+  /// The first element, if any.
+  #[inline]
+  #[must_use]
+  pub fn first_mut(&mut self) -> Option<&mut T> {
+    self.get_mut(0)
+  }
+
+  /// The element at the index, if it's in bounds.
+  #[inline]
+  #[must_use]
+  pub fn get(&self, index: usize) -> Option<&T> {
+    self.vec.get(index).and_then(Option::as_ref)
+  }
+
+  /// The element at the index, if it's in bounds.
+  #[inline]
+  #[must_use]
+  pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+    self.vec.get_mut(index).and_then(Option::as_mut)
+  }
+
+  /// Inserts an item at the position given, moving all following elements +1
+  /// index.
+  ///
+  /// ## Panics
+  /// * If `index` > `len`
+  #[inline]
+  pub fn insert(&mut self, index: usize, item: T) {
+    self.vec.insert(index, Some(item))
+  }
+
+  /// If the vec is empty.
+  #[inline(always)]
+  #[must_use]
+  pub fn is_empty(&self) -> bool {
+    self.vec.is_empty()
+  }
+
+  /// If the elements are still stored inline.
+  #[inline(always)]
+  #[must_use]
+  pub fn is_inline(&self) -> bool {
+    match self.vec {
+      TinyVec::Inline(_) => true,
+      TinyVec::Heap(_) => false,
+    }
+  }
+
+  /// Iterates over shared references to the elements.
+  #[inline]
+  #[must_use]
+  pub fn iter(&self) -> OptionIter<'_, T> {
+    OptionIter::new(self.vec.iter())
+  }
+
+  /// Iterates over unique references to the elements.
+  #[inline]
+  #[must_use]
+  pub fn iter_mut(&mut self) -> OptionIterMut<'_, T> {
+    OptionIterMut::new(self.vec.iter_mut())
+  }
+
+  /// The last element, if any.
+  #[inline]
+  #[must_use]
+  pub fn last(&self) -> Option<&T> {
+    self.vec.last().and_then(Option::as_ref)
+  }
+
+  /// The last element, if any.
+  #[inline]
+  #[must_use]
+  pub fn last_mut(&mut self) -> Option<&mut T> {
+    self.vec.last_mut().and_then(Option::as_mut)
+  }
+
+  /// The length of the vec (in elements).
+  #[inline(always)]
+  #[must_use]
+  pub fn len(&self) -> usize {
+    self.vec.len()
+  }
+
+  /// Moves the content to the heap, if it's inline.
+  ///
+  /// See [`TinyVec::move_to_the_heap`]
+  #[inline]
+  pub fn move_to_the_heap(&mut self) {
+    self.vec.move_to_the_heap()
+  }
+
+  /// Makes a new, empty vec.
+  #[inline(always)]
+  #[must_use]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Remove and return the last element of the vec, if there is one.
+  ///
+  /// ## Failure
+  /// * If the vec is empty you get `None`.
+  #[inline]
+  pub fn pop(&mut self) -> Option<T> {
+    self.vec.pop().map(unwrap_slot)
+  }
+
+  /// Place an element onto the end of the vec, moving to the heap if the
+  /// inline array is full.
+  #[inline]
+  pub fn push(&mut self, val: T) {
+    self.vec.push(Some(val))
+  }
+
+  /// Removes the item at `index`, shifting all others down by one index.
+  ///
+  /// Returns the removed element.
+  ///
+  /// ## Panics
+  /// * If the index is out of bounds.
+  #[inline]
+  pub fn remove(&mut self, index: usize) -> T {
+    unwrap_slot(self.vec.remove(index))
+  }
+
+  /// Reserves room for at least `n` more elements.
+  ///
+  /// See [`TinyVec::reserve`]
+  #[inline]
+  pub fn reserve(&mut self, n: usize) {
+    self.vec.reserve(n)
+  }
+
+  /// Resize the vec to the new length.
+  ///
+  /// If it needs to be longer, it's filled with repeated calls to the provided
+  /// function. If it needs to be shorter, it's truncated.
+  #[inline]
+  pub fn resize_with<F: FnMut() -> T>(&mut self, new_len: usize, mut f: F) {
+    self.vec.resize_with(new_len, || Some(f()))
+  }
+
+  /// Walk the vec and keep only the elements that pass the predicate given.
+  #[inline]
+  pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut acceptable: F) {
+    self.retain_mut(|x| acceptable(x))
+  }
+
+  /// Walk the vec and keep only the elements that pass the predicate given,
+  /// which can also change the elements.
+  #[inline]
+  pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut acceptable: F) {
+    self.vec.retain_mut(|slot| match slot {
+      Some(x) => acceptable(x),
+      None => false,
+    })
+  }
+
+  /// Shrinks the heap allocation to fit the length, or moves back inline if
+  /// the elements fit in the array.
+  ///
+  /// See [`TinyVec::shrink_to_fit`]
+  #[inline]
+  pub fn shrink_to_fit(&mut self) {
+    self.vec.shrink_to_fit()
+  }
+
+  /// Splits the collection at the point given.
+  ///
+  /// * `[0, at)` stays in this vec
+  /// * `[at, len)` ends up in the new vec.
+  ///
+  /// ## Panics
+  /// * if at > len
+  #[inline]
+  pub fn split_off(&mut self, at: usize) -> Self {
+    Self { vec: self.vec.split_off(at) }
+  }
+
+  /// Remove an element, swapping the end of the vec into its place.
+  ///
+  /// ## Panics
+  /// * If the index is out of bounds.
+  #[inline]
+  pub fn swap_remove(&mut self, index: usize) -> T {
+    unwrap_slot(self.vec.swap_remove(index))
+  }
+
+  /// Reduces the vec's length to the given value.
+  ///
+  /// If the vec is already shorter than the input, nothing happens.
+  #[inline]
+  pub fn truncate(&mut self, new_len: usize) {
+    self.vec.truncate(new_len)
+  }
+
+  /// Place an element onto the end of the vec, if the allocation (if any)
+  /// succeeds.
+  ///
+  /// * Requires the `rustc_1_57` feature
+  ///
+  /// ## Failure
+  ///
+  /// If the vec needs to grow and the allocation fails, the vec is unchanged
+  /// and you get the item back in the `Err`.
+  #[cfg(feature = "rustc_1_57")]
+  #[inline]
+  pub fn try_push(&mut self, val: T) -> Result<(), T> {
+    self.vec.try_push(Some(val)).map_err(unwrap_slot)
+  }
+
+  /// Remove an element, shifting all following elements down, if the index is
+  /// in bounds.
+  ///
+  /// ## Failure
+  /// * If the index is out of bounds you get `None` and the vec is unchanged.
+  #[inline]
+  pub fn try_remove(&mut self, index: usize) -> Option<T> {
+    self.vec.try_remove(index).map(unwrap_slot)
+  }
+
+  /// Remove an element, swapping the end of the vec into its place, if the
+  /// index is in bounds.
+  ///
+  /// ## Failure
+  /// * If the index is out of bounds you get `None` and the vec is unchanged.
+  #[inline]
+  pub fn try_swap_remove(&mut self, index: usize) -> Option<T> {
+    self.vec.try_swap_remove(index).map(unwrap_slot)
+  }
+
+  /// Makes a new, empty vec with room for at least `cap` elements.
+  ///
+  /// See [`TinyVec::with_capacity`]
+  #[inline]
+  #[must_use]
+  pub fn with_capacity(cap: usize) -> Self {
+    Self { vec: TinyVec::with_capacity(cap) }
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> From<OptionArrayVec<A>>
+  for TinyOptionVec<A>
+{
+  #[inline]
+  fn from(arr: OptionArrayVec<A>) -> Self {
+    arr.into_iter().collect()
+  }
+}
+
+/// Draining iterator for `TinyOptionVec`
+///
+/// See [`TinyOptionVec::drain`](TinyOptionVec::<A>::drain)
+pub struct TinyOptionVecDrain<'p, A: Array> {
+  inner: TinyVecDrain<'p, A>,
+}
+impl<'p, T, A: Array<Item = Option<T>>> Iterator for TinyOptionVecDrain<'p, A> {
+  type Item = T;
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    self.inner.next().map(unwrap_slot)
+  }
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.inner.size_hint()
+  }
+}
+impl<'p, T, A: Array<Item = Option<T>>> DoubleEndedIterator
+  for TinyOptionVecDrain<'p, A>
+{
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    self.inner.next_back().map(unwrap_slot)
+  }
+}
+impl<'p, T, A: Array<Item = Option<T>>> ExactSizeIterator
+  for TinyOptionVecDrain<'p, A>
+{
+}
+impl<'p, T, A: Array<Item = Option<T>>> FusedIterator
+  for TinyOptionVecDrain<'p, A>
+{
+}
+
+/// Iterator for consuming a `TinyOptionVec` and returning owned elements.
+pub struct TinyOptionVecIterator<A: Array> {
+  inner: TinyVecIterator<A>,
+}
+impl<A: Array> Clone for TinyOptionVecIterator<A>
+where
+  TinyVecIterator<A>: Clone,
+{
+  #[inline]
+  fn clone(&self) -> Self {
+    Self { inner: self.inner.clone() }
+  }
+}
+impl<T, A: Array<Item = Option<T>>> Iterator for TinyOptionVecIterator<A> {
+  type Item = T;
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    self.inner.next().map(unwrap_slot)
+  }
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.inner.size_hint()
+  }
+}
+impl<T, A: Array<Item = Option<T>>> DoubleEndedIterator
+  for TinyOptionVecIterator<A>
+{
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    self.inner.next_back().map(unwrap_slot)
+  }
+}
+impl<T, A: Array<Item = Option<T>>> ExactSizeIterator
+  for TinyOptionVecIterator<A>
+{
+}
+impl<T, A: Array<Item = Option<T>>> FusedIterator for TinyOptionVecIterator<A> {}
+
+impl<T, A: Array<Item = Option<T>>> Index<usize> for TinyOptionVec<A> {
+  type Output = T;
+  #[inline]
+  fn index(&self, index: usize) -> &Self::Output {
+    match self.get(index) {
+      Some(x) => x,
+      None => panic!(
+        "TinyOptionVec::index> index {} is out of bounds {}",
+        index,
+        self.len()
+      ),
+    }
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> IndexMut<usize> for TinyOptionVec<A> {
+  #[inline]
+  fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+    let len = self.len();
+    match self.get_mut(index) {
+      Some(x) => x,
+      None => panic!(
+        "TinyOptionVec::index_mut> index {} is out of bounds {}",
+        index, len
+      ),
+    }
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> Extend<T> for TinyOptionVec<A> {
+  #[inline]
+  fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+    for t in iter {
+      self.push(t)
+    }
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> FromIterator<T> for TinyOptionVec<A> {
+  #[inline]
+  fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+    let mut tv = Self::default();
+    tv.extend(iter);
+    tv
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> IntoIterator for TinyOptionVec<A> {
+  type Item = T;
+  type IntoIter = TinyOptionVecIterator<A>;
+  #[inline(always)]
+  fn into_iter(self) -> Self::IntoIter {
+    TinyOptionVecIterator { inner: self.vec.into_iter() }
+  }
+}
+
+impl<'a, T: 'a, A: Array<Item = Option<T>>> IntoIterator
+  for &'a TinyOptionVec<A>
+{
+  type Item = &'a T;
+  type IntoIter = OptionIter<'a, T>;
+  #[inline(always)]
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+
+impl<'a, T: 'a, A: Array<Item = Option<T>>> IntoIterator
+  for &'a mut TinyOptionVec<A>
+{
+  type Item = &'a mut T;
+  type IntoIter = OptionIterMut<'a, T>;
+  #[inline(always)]
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter_mut()
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> PartialEq for TinyOptionVec<A>
+where
+  T: PartialEq,
+{
+  #[inline]
+  fn eq(&self, other: &Self) -> bool {
+    self.len() == other.len() && self.iter().eq(other.iter())
+  }
+}
+impl<T, A: Array<Item = Option<T>>> Eq for TinyOptionVec<A> where T: Eq {}
+
+impl<T, A: Array<Item = Option<T>>> PartialOrd for TinyOptionVec<A>
+where
+  T: PartialOrd,
+{
+  #[inline]
+  fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+    self.iter().partial_cmp(other.iter())
+  }
+}
+impl<T, A: Array<Item = Option<T>>> Ord for TinyOptionVec<A>
+where
+  T: Ord,
+{
+  #[inline]
+  fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+    self.iter().cmp(other.iter())
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> PartialEq<&[T]> for TinyOptionVec<A>
+where
+  T: PartialEq,
+{
+  #[inline]
+  fn eq(&self, other: &&[T]) -> bool {
+    self.len() == other.len() && self.iter().eq(other.iter())
+  }
+}
+
+// //
+// Formatting impls
+// //
+
+impl<T, A: Array<Item = Option<T>>> Binary for TinyOptionVec<A>
+where
+  T: Binary,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      Binary::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> Debug for TinyOptionVec<A>
+where
+  T: Debug,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      Debug::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> Display for TinyOptionVec<A>
+where
+  T: Display,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      Display::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> LowerExp for TinyOptionVec<A>
+where
+  T: LowerExp,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      LowerExp::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> LowerHex for TinyOptionVec<A>
+where
+  T: LowerHex,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      LowerHex::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> Octal for TinyOptionVec<A>
+where
+  T: Octal,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      Octal::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> Pointer for TinyOptionVec<A>
+where
+  T: Pointer,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      Pointer::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> UpperExp for TinyOptionVec<A>
+where
+  T: UpperExp,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      UpperExp::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
+
+impl<T, A: Array<Item = Option<T>>> UpperHex for TinyOptionVec<A>
+where
+  T: UpperHex,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "[")?;
+    for (i, elem) in self.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      UpperHex::fmt(elem, f)?;
+    }
+    write!(f, "]")
+  }
+}
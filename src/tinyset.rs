@@ -0,0 +1,482 @@
+#![cfg(feature = "alloc")]
+
+use super::*;
+
+use alloc::collections::{btree_set, BTreeSet};
+use core::{
+  cmp::Ordering,
+  ops::{BitAnd, BitOr, BitXor, Sub},
+};
+
+/// A sorted set that starts inline, but can automatically move to the heap.
+///
+/// * Requires the `alloc` feature
+///
+/// This is the `BTreeSet` counterpart of [`TinyVec`]: while it's `Inline` it's
+/// an [`ArraySet`], and if you try to insert a new value past that capacity
+/// it'll quietly move all of its elements into a `BTreeSet` and continue
+/// operation. Either way, iteration is in order.
+///
+/// ```rust
+/// use tinyvec::*;
+///
+/// let mut s: TinySet<[u32; 2]> = TinySet::new();
+/// s.insert(2);
+/// s.insert(1);
+/// assert!(!s.insert(1));
+/// assert!(matches!(s, TinySet::Inline(_)));
+/// s.insert(3);
+/// assert!(matches!(s, TinySet::Heap(_)));
+/// assert!(s.iter().eq([1, 2, 3].iter()));
+/// ```
+pub enum TinySet<A: Array> {
+  #[allow(missing_docs)]
+  Inline(ArraySet<A>),
+  #[allow(missing_docs)]
+  Heap(BTreeSet<A::Item>),
+}
+
+impl<A: Array> Clone for TinySet<A>
+where
+  ArraySet<A>: Clone,
+  BTreeSet<A::Item>: Clone,
+{
+  #[inline]
+  fn clone(&self) -> Self {
+    match self {
+      TinySet::Inline(s) => TinySet::Inline(s.clone()),
+      TinySet::Heap(b) => TinySet::Heap(b.clone()),
+    }
+  }
+}
+
+impl<A: Array> Default for TinySet<A> {
+  #[inline]
+  fn default() -> Self {
+    TinySet::Inline(ArraySet::default())
+  }
+}
+
+impl<T: Ord, A: Array<Item = T>> TinySet<A> {
+  /// Removes all elements from the set.
+  ///
+  /// A set on the heap stays on the heap.
+  #[inline]
+  pub fn clear(&mut self) {
+    match self {
+      TinySet::Inline(s) => s.clear(),
+      TinySet::Heap(b) => b.clear(),
+    }
+  }
+
+  /// If the set has the value.
+  #[inline]
+  #[must_use]
+  pub fn contains<Q>(&self, value: &Q) -> bool
+  where
+    T: Borrow<Q>,
+    Q: Ord + ?Sized,
+  {
+    match self {
+      TinySet::Inline(s) => s.contains(value),
+      TinySet::Heap(b) => b.contains(value),
+    }
+  }
+
+  /// Iterates over the elements in `self` but not in `other`, in order.
+  #[inline]
+  pub fn difference<'a>(
+    &'a self,
+    other: &'a Self,
+  ) -> SetDifference<TinySetIter<'a, T>> {
+    SetDifference::new(self.iter(), other.iter())
+  }
+
+  /// The smallest element, if any.
+  #[inline]
+  #[must_use]
+  pub fn first(&self) -> Option<&T> {
+    self.iter().next()
+  }
+
+  /// The stored element equal to the value, if any.
+  #[inline]
+  #[must_use]
+  pub fn get<Q>(&self, value: &Q) -> Option<&T>
+  where
+    T: Borrow<Q>,
+    Q: Ord + ?Sized,
+  {
+    match self {
+      TinySet::Inline(s) => s.get(value),
+      TinySet::Heap(b) => b.get(value),
+    }
+  }
+
+  /// Adds the value to the set, moving to the heap if the value is new and
+  /// the inline array is full.
+  ///
+  /// Returns `true` if the value was new. If it was already present the set
+  /// is unchanged (the stored value is kept).
+  #[inline]
+  pub fn insert(&mut self, value: T) -> bool {
+    match self {
+      TinySet::Inline(s) => match s.try_insert(value) {
+        Ok(added) => added,
+        Err(e) => {
+          self.move_to_the_heap();
+          self.insert(e.element())
+        }
+      },
+      TinySet::Heap(b) => b.insert(value),
+    }
+  }
+
+  /// Iterates over the elements in both `self` and `other`, in order.
+  #[inline]
+  pub fn intersection<'a>(
+    &'a self,
+    other: &'a Self,
+  ) -> SetIntersection<TinySetIter<'a, T>> {
+    SetIntersection::new(self.iter(), other.iter())
+  }
+
+  /// If `self` and `other` have no elements in common.
+  #[inline]
+  #[must_use]
+  pub fn is_disjoint(&self, other: &Self) -> bool {
+    self.intersection(other).next().is_none()
+  }
+
+  /// If the set is empty.
+  #[inline]
+  #[must_use]
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// If every element of `self` is also in `other`.
+  #[inline]
+  #[must_use]
+  pub fn is_subset(&self, other: &Self) -> bool {
+    self.len() <= other.len() && self.difference(other).next().is_none()
+  }
+
+  /// If every element of `other` is also in `self`.
+  #[inline]
+  #[must_use]
+  pub fn is_superset(&self, other: &Self) -> bool {
+    other.is_subset(self)
+  }
+
+  /// Iterates over the elements, in order.
+  #[inline]
+  #[must_use]
+  pub fn iter(&self) -> TinySetIter<'_, T> {
+    match self {
+      TinySet::Inline(s) => TinySetIter::Inline(s.iter()),
+      TinySet::Heap(b) => TinySetIter::Heap(b.range::<T, _>(..)),
+    }
+  }
+
+  /// The largest element, if any.
+  #[inline]
+  #[must_use]
+  pub fn last(&self) -> Option<&T> {
+    self.iter().next_back()
+  }
+
+  /// The number of elements in the set.
+  #[inline]
+  #[must_use]
+  pub fn len(&self) -> usize {
+    match self {
+      TinySet::Inline(s) => s.len(),
+      TinySet::Heap(b) => b.len(),
+    }
+  }
+
+  /// Moves the content of the TinySet to the heap, if it's inline.
+  #[allow(clippy::missing_inline_in_public_items)]
+  pub fn move_to_the_heap(&mut self) {
+    if let TinySet::Inline(s) = self {
+      let b: BTreeSet<T> = replace(s, ArraySet::new()).into_iter().collect();
+      *self = TinySet::Heap(b);
+    }
+  }
+
+  /// Makes a new, empty set.
+  #[inline(always)]
+  #[must_use]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Iterates over the elements in the range, in order.
+  ///
+  /// ## Panics
+  /// * If the range's start is after its end, or if the start and end are
+  ///   equal and both excluded.
+  #[inline]
+  pub fn range<Q, R>(&self, range: R) -> TinySetIter<'_, T>
+  where
+    T: Borrow<Q>,
+    Q: Ord + ?Sized,
+    R: RangeBounds<Q>,
+  {
+    match self {
+      TinySet::Inline(s) => TinySetIter::Inline(s.range(range)),
+      TinySet::Heap(b) => TinySetIter::Heap(b.range(range)),
+    }
+  }
+
+  /// Removes the value from the set, returning if it was present.
+  #[inline]
+  pub fn remove<Q>(&mut self, value: &Q) -> bool
+  where
+    T: Borrow<Q>,
+    Q: Ord + ?Sized,
+  {
+    match self {
+      TinySet::Inline(s) => s.remove(value),
+      TinySet::Heap(b) => b.remove(value),
+    }
+  }
+
+  /// Keeps only the elements that pass the predicate given.
+  #[inline]
+  pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut acceptable: F) {
+    match self {
+      TinySet::Inline(s) => s.retain(acceptable),
+      TinySet::Heap(b) => {
+        // `mem::take` needs Rust 1.40, and `BTreeSet::retain` needs 1.53.
+        #[allow(clippy::mem_replace_with_default)]
+        let old = replace(b, BTreeSet::new());
+        b.extend(old.into_iter().filter(|t| acceptable(t)));
+      }
+    }
+  }
+
+  /// Iterates over the elements in exactly one of `self` and `other`, in
+  /// order.
+  #[inline]
+  pub fn symmetric_difference<'a>(
+    &'a self,
+    other: &'a Self,
+  ) -> SetSymmetricDifference<TinySetIter<'a, T>> {
+    SetSymmetricDifference::new(self.iter(), other.iter())
+  }
+
+  /// Removes the value from the set, returning the stored element if it was
+  /// present.
+  #[inline]
+  pub fn take<Q>(&mut self, value: &Q) -> Option<T>
+  where
+    T: Borrow<Q>,
+    Q: Ord + ?Sized,
+  {
+    match self {
+      TinySet::Inline(s) => s.take(value),
+      TinySet::Heap(b) => b.take(value),
+    }
+  }
+
+  /// Iterates over the elements in `self` or `other` (or both), in order.
+  #[inline]
+  pub fn union<'a>(&'a self, other: &'a Self) -> SetUnion<TinySetIter<'a, T>> {
+    SetUnion::new(self.iter(), other.iter())
+  }
+}
+
+/// Iterator over the elements of a [`TinySet`], in order.
+pub enum TinySetIter<'a, T> {
+  #[allow(missing_docs)]
+  Inline(core::slice::Iter<'a, T>),
+  #[allow(missing_docs)]
+  Heap(btree_set::Range<'a, T>),
+}
+impl<'a, T> Iterator for TinySetIter<'a, T> {
+  type Item = &'a T;
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    match self {
+      TinySetIter::Inline(i) => i.next(),
+      TinySetIter::Heap(i) => i.next(),
+    }
+  }
+  #[inline]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    match self {
+      TinySetIter::Inline(i) => i.size_hint(),
+      TinySetIter::Heap(i) => i.size_hint(),
+    }
+  }
+}
+impl<'a, T> DoubleEndedIterator for TinySetIter<'a, T> {
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    match self {
+      TinySetIter::Inline(i) => i.next_back(),
+      TinySetIter::Heap(i) => i.next_back(),
+    }
+  }
+}
+impl<'a, T> FusedIterator for TinySetIter<'a, T> {}
+impl<'a, T> Clone for TinySetIter<'a, T> {
+  #[inline]
+  fn clone(&self) -> Self {
+    match self {
+      TinySetIter::Inline(i) => TinySetIter::Inline(i.clone()),
+      TinySetIter::Heap(i) => TinySetIter::Heap(i.clone()),
+    }
+  }
+}
+
+/// Iterator for consuming a `TinySet` and returning owned elements, in order.
+pub enum TinySetIterator<A: Array> {
+  #[allow(missing_docs)]
+  Inline(ArrayVecIterator<A>),
+  #[allow(missing_docs)]
+  Heap(btree_set::IntoIter<A::Item>),
+}
+impl<A: Array> Iterator for TinySetIterator<A> {
+  type Item = A::Item;
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    match self {
+      TinySetIterator::Inline(i) => i.next(),
+      TinySetIterator::Heap(i) => i.next(),
+    }
+  }
+  #[inline]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    match self {
+      TinySetIterator::Inline(i) => i.size_hint(),
+      TinySetIterator::Heap(i) => i.size_hint(),
+    }
+  }
+}
+impl<A: Array> DoubleEndedIterator for TinySetIterator<A> {
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    match self {
+      TinySetIterator::Inline(i) => i.next_back(),
+      TinySetIterator::Heap(i) => i.next_back(),
+    }
+  }
+}
+impl<A: Array> ExactSizeIterator for TinySetIterator<A> {}
+impl<A: Array> FusedIterator for TinySetIterator<A> {}
+
+impl<T: Ord, A: Array<Item = T>> Extend<T> for TinySet<A> {
+  #[inline]
+  fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+    for t in iter {
+      self.insert(t);
+    }
+  }
+}
+
+impl<T: Ord, A: Array<Item = T>> From<ArraySet<A>> for TinySet<A> {
+  #[inline(always)]
+  fn from(set: ArraySet<A>) -> Self {
+    TinySet::Inline(set)
+  }
+}
+
+impl<T: Ord, A: Array<Item = T>> FromIterator<T> for TinySet<A> {
+  #[inline]
+  fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+    let mut set = Self::default();
+    set.extend(iter);
+    set
+  }
+}
+
+impl<T: Ord, A: Array<Item = T>> IntoIterator for TinySet<A> {
+  type Item = T;
+  type IntoIter = TinySetIterator<A>;
+  #[inline]
+  fn into_iter(self) -> Self::IntoIter {
+    match self {
+      TinySet::Inline(s) => TinySetIterator::Inline(s.into_iter()),
+      TinySet::Heap(b) => TinySetIterator::Heap(b.into_iter()),
+    }
+  }
+}
+
+impl<'a, T: 'a + Ord, A: Array<Item = T>> IntoIterator for &'a TinySet<A> {
+  type Item = &'a T;
+  type IntoIter = TinySetIter<'a, T>;
+  #[inline(always)]
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+
+impl<T: Ord + Clone, A: Array<Item = T>> BitAnd for &TinySet<A> {
+  type Output = TinySet<A>;
+  /// The intersection of the sets.
+  #[inline]
+  fn bitand(self, rhs: Self) -> TinySet<A> {
+    self.intersection(rhs).cloned().collect()
+  }
+}
+
+impl<T: Ord + Clone, A: Array<Item = T>> BitOr for &TinySet<A> {
+  type Output = TinySet<A>;
+  /// The union of the sets.
+  #[inline]
+  fn bitor(self, rhs: Self) -> TinySet<A> {
+    self.union(rhs).cloned().collect()
+  }
+}
+
+impl<T: Ord + Clone, A: Array<Item = T>> BitXor for &TinySet<A> {
+  type Output = TinySet<A>;
+  /// The symmetric difference of the sets.
+  #[inline]
+  fn bitxor(self, rhs: Self) -> TinySet<A> {
+    self.symmetric_difference(rhs).cloned().collect()
+  }
+}
+
+impl<T: Ord + Clone, A: Array<Item = T>> Sub for &TinySet<A> {
+  type Output = TinySet<A>;
+  /// The difference of the sets.
+  #[inline]
+  fn sub(self, rhs: Self) -> TinySet<A> {
+    self.difference(rhs).cloned().collect()
+  }
+}
+
+impl<T: Ord, A: Array<Item = T>> PartialEq for TinySet<A> {
+  #[inline]
+  fn eq(&self, other: &Self) -> bool {
+    self.len() == other.len() && self.iter().eq(other.iter())
+  }
+}
+impl<T: Ord, A: Array<Item = T>> Eq for TinySet<A> {}
+
+impl<T: Ord, A: Array<Item = T>> PartialOrd for TinySet<A> {
+  #[inline]
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+impl<T: Ord, A: Array<Item = T>> Ord for TinySet<A> {
+  #[inline]
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.iter().cmp(other.iter())
+  }
+}
+
+impl<T: Ord, A: Array<Item = T>> Debug for TinySet<A>
+where
+  T: Debug,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    f.debug_set().entries(self.iter()).finish()
+  }
+}
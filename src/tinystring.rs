@@ -0,0 +1,402 @@
+#![cfg(feature = "alloc")]
+
+use super::*;
+
+use alloc::string::String;
+use core::{
+  convert::Infallible,
+  fmt::Write,
+  hash::{Hash, Hasher},
+  str::FromStr,
+};
+
+/// A string that starts inline, but can automatically move to the heap.
+///
+/// * Requires the `alloc` feature
+///
+/// This is to `String` what [`TinyVec`] is to `Vec`: while it's `Inline` the
+/// text lives in an [`ArrayString`], and if you try to grow it past that
+/// capacity it'll quietly move to a heap `String` and continue operation.
+///
+/// Equality, ordering and hashing all work on the text alone, the same as for
+/// `str`, so inline and heap values compare equal when they hold the same
+/// text, and a `TinyString` key can be looked up with a `&str`.
+///
+/// ```rust
+/// use tinyvec::*;
+///
+/// let mut s: TinyString<[u8; 4]> = TinyString::from("abc");
+/// assert!(matches!(s, TinyString::Inline(_)));
+/// s.push_str("def");
+/// assert!(matches!(s, TinyString::Heap(_)));
+/// assert_eq!(s, "abcdef");
+/// ```
+#[derive(Clone)]
+pub enum TinyString<A: Array<Item = u8>> {
+  #[allow(missing_docs)]
+  Inline(ArrayString<A>),
+  #[allow(missing_docs)]
+  Heap(String),
+}
+impl<A: Array<Item = u8>> Default for TinyString<A> {
+  #[inline]
+  fn default() -> Self {
+    TinyString::Inline(ArrayString::default())
+  }
+}
+impl<A: Array<Item = u8>> TinyString<A> {
+  /// Moves the content of the TinyString to the heap, if it's inline.
+  #[allow(clippy::missing_inline_in_public_items)]
+  pub fn move_to_the_heap(&mut self) {
+    match self {
+      TinyString::Inline(s) => {
+        let mut h = String::with_capacity(A::CAPACITY * 2);
+        h.push_str(s);
+        *self = TinyString::Heap(h);
+      }
+      TinyString::Heap(_) => (),
+    }
+  }
+}
+
+impl<A: Array<Item = u8>> Deref for TinyString<A> {
+  type Target = str;
+  #[inline(always)]
+  fn deref(&self) -> &Self::Target {
+    match self {
+      TinyString::Inline(s) => s.as_str(),
+      TinyString::Heap(s) => s.as_str(),
+    }
+  }
+}
+
+impl<A: Array<Item = u8>> DerefMut for TinyString<A> {
+  #[inline(always)]
+  fn deref_mut(&mut self) -> &mut Self::Target {
+    match self {
+      TinyString::Inline(s) => s.as_mut_str(),
+      TinyString::Heap(s) => s.as_mut_str(),
+    }
+  }
+}
+
+impl<A: Array<Item = u8>> TinyString<A> {
+  /// Helper for getting the mut `str`.
+  #[inline(always)]
+  #[must_use]
+  pub fn as_mut_str(&mut self) -> &mut str {
+    self.deref_mut()
+  }
+
+  /// Helper for getting the shared `str`.
+  #[inline(always)]
+  #[must_use]
+  pub fn as_str(&self) -> &str {
+    self.deref()
+  }
+
+  /// The capacity of the `TinyString` (in bytes).
+  ///
+  /// While inline this is fixed based on the array type.
+  #[inline(always)]
+  #[must_use]
+  pub fn capacity(&self) -> usize {
+    match self {
+      TinyString::Inline(s) => s.capacity(),
+      TinyString::Heap(s) => s.capacity(),
+    }
+  }
+
+  /// Removes all of the text.
+  #[inline(always)]
+  pub fn clear(&mut self) {
+    match self {
+      TinyString::Inline(s) => s.clear(),
+      TinyString::Heap(s) => s.clear(),
+    }
+  }
+
+  /// Inserts a `char` at the byte index given, moving all following text
+  /// forward.
+  ///
+  /// ## Panics
+  /// * If `index` > `len` or isn't on a `char` boundary.
+  #[inline]
+  pub fn insert(&mut self, index: usize, ch: char) {
+    let mut buf = [0; 4];
+    self.insert_str(index, ch.encode_utf8(&mut buf))
+  }
+
+  /// Inserts a string slice at the byte index given, moving all following
+  /// text forward.
+  ///
+  /// ## Panics
+  /// * If `index` > `len` or isn't on a `char` boundary.
+  #[inline]
+  pub fn insert_str(&mut self, index: usize, text: &str) {
+    assert!(
+      self.is_char_boundary(index),
+      "TinyString::insert_str> index {} is not a char boundary",
+      index
+    );
+    match self {
+      TinyString::Inline(s) => {
+        if s.len() + text.len() > A::CAPACITY {
+          self.move_to_the_heap();
+          self.insert_str(index, text)
+        } else {
+          s.insert_str(index, text)
+        }
+      }
+      TinyString::Heap(s) => s.insert_str(index, text),
+    }
+  }
+
+  /// Makes a new, empty string.
+  #[inline(always)]
+  #[must_use]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Remove and return the last `char`, if there is one.
+  ///
+  /// ## Failure
+  /// * If the string is empty you get `None`.
+  #[inline]
+  pub fn pop(&mut self) -> Option<char> {
+    match self {
+      TinyString::Inline(s) => s.pop(),
+      TinyString::Heap(s) => s.pop(),
+    }
+  }
+
+  /// Place a `char` onto the end of the string.
+  #[inline]
+  pub fn push(&mut self, ch: char) {
+    match self {
+      TinyString::Inline(s) => {
+        if s.try_push(ch).is_err() {
+          self.move_to_the_heap();
+          self.push(ch)
+        }
+      }
+      TinyString::Heap(s) => s.push(ch),
+    }
+  }
+
+  /// Place a string slice onto the end of the string.
+  #[inline]
+  pub fn push_str(&mut self, text: &str) {
+    match self {
+      TinyString::Inline(s) => {
+        if s.try_push_str(text).is_err() {
+          self.move_to_the_heap();
+          self.push_str(text)
+        }
+      }
+      TinyString::Heap(s) => s.push_str(text),
+    }
+  }
+
+  /// Removes the `char` at the byte index given, moving all following text
+  /// back.
+  ///
+  /// ## Panics
+  /// * If `index` isn't the start of a `char` in the string.
+  #[inline]
+  pub fn remove(&mut self, index: usize) -> char {
+    match self {
+      TinyString::Inline(s) => s.remove(index),
+      TinyString::Heap(s) => s.remove(index),
+    }
+  }
+
+  /// Reduces the string's length to the given byte length.
+  ///
+  /// If the string is already shorter than the input, nothing happens.
+  ///
+  /// ## Panics
+  /// * If `new_len` isn't on a `char` boundary.
+  #[inline]
+  pub fn truncate(&mut self, new_len: usize) {
+    match self {
+      TinyString::Inline(s) => s.truncate(new_len),
+      TinyString::Heap(s) => s.truncate(new_len),
+    }
+  }
+}
+
+impl<A: Array<Item = u8>> Write for TinyString<A> {
+  #[inline]
+  fn write_str(&mut self, s: &str) -> core::fmt::Result {
+    self.push_str(s);
+    Ok(())
+  }
+  #[inline]
+  fn write_char(&mut self, c: char) -> core::fmt::Result {
+    self.push(c);
+    Ok(())
+  }
+}
+
+impl<A: Array<Item = u8>> FromStr for TinyString<A> {
+  type Err = Infallible;
+  #[inline]
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Ok(Self::from(s))
+  }
+}
+
+impl<A: Array<Item = u8>> AsRef<str> for TinyString<A> {
+  #[inline(always)]
+  fn as_ref(&self) -> &str {
+    self.as_str()
+  }
+}
+
+impl<A: Array<Item = u8>> AsRef<[u8]> for TinyString<A> {
+  #[inline(always)]
+  fn as_ref(&self) -> &[u8] {
+    self.as_bytes()
+  }
+}
+
+impl<A: Array<Item = u8>> Borrow<str> for TinyString<A> {
+  #[inline(always)]
+  fn borrow(&self) -> &str {
+    self.as_str()
+  }
+}
+
+impl<A: Array<Item = u8>> Extend<char> for TinyString<A> {
+  #[inline]
+  fn extend<T: IntoIterator<Item = char>>(&mut self, iter: T) {
+    for ch in iter {
+      self.push(ch)
+    }
+  }
+}
+
+impl<'a, A: Array<Item = u8>> Extend<&'a str> for TinyString<A> {
+  #[inline]
+  fn extend<T: IntoIterator<Item = &'a str>>(&mut self, iter: T) {
+    for s in iter {
+      self.push_str(s)
+    }
+  }
+}
+
+impl<A: Array<Item = u8>> FromIterator<char> for TinyString<A> {
+  #[inline]
+  fn from_iter<T: IntoIterator<Item = char>>(iter: T) -> Self {
+    let mut out = Self::default();
+    out.extend(iter);
+    out
+  }
+}
+
+impl<A: Array<Item = u8>> From<ArrayString<A>> for TinyString<A> {
+  #[inline(always)]
+  fn from(s: ArrayString<A>) -> Self {
+    TinyString::Inline(s)
+  }
+}
+
+impl<A: Array<Item = u8>> From<&str> for TinyString<A> {
+  /// Stays inline if the text fits, otherwise allocates.
+  #[inline]
+  fn from(text: &str) -> Self {
+    let mut s = ArrayString::default();
+    match s.try_push_str(text) {
+      Ok(()) => TinyString::Inline(s),
+      Err(text) => TinyString::Heap(String::from(text)),
+    }
+  }
+}
+
+impl<A: Array<Item = u8>> From<String> for TinyString<A> {
+  /// Keeps the `String` allocation as the heap storage, without copying.
+  #[inline(always)]
+  fn from(s: String) -> Self {
+    TinyString::Heap(s)
+  }
+}
+
+impl<A: Array<Item = u8>> From<TinyString<A>> for String {
+  /// Reuses the heap buffer if there is one, otherwise allocates.
+  #[inline]
+  fn from(s: TinyString<A>) -> Self {
+    match s {
+      TinyString::Inline(s) => String::from(s.as_str()),
+      TinyString::Heap(s) => s,
+    }
+  }
+}
+
+impl<A: Array<Item = u8>> Hash for TinyString<A> {
+  #[inline]
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.as_str().hash(state)
+  }
+}
+
+impl<A: Array<Item = u8>> PartialEq for TinyString<A> {
+  #[inline]
+  fn eq(&self, other: &Self) -> bool {
+    self.as_str() == other.as_str()
+  }
+}
+impl<A: Array<Item = u8>> Eq for TinyString<A> {}
+
+impl<A: Array<Item = u8>> PartialOrd for TinyString<A> {
+  #[inline]
+  fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+    Some(self.cmp(other))
+  }
+}
+impl<A: Array<Item = u8>> Ord for TinyString<A> {
+  #[inline]
+  fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+    self.as_str().cmp(other.as_str())
+  }
+}
+
+impl<A: Array<Item = u8>> PartialEq<str> for TinyString<A> {
+  #[inline]
+  fn eq(&self, other: &str) -> bool {
+    self.as_str() == other
+  }
+}
+
+impl<A: Array<Item = u8>> PartialEq<&str> for TinyString<A> {
+  #[inline]
+  fn eq(&self, other: &&str) -> bool {
+    self.as_str() == *other
+  }
+}
+
+impl<A: Array<Item = u8>> PartialEq<String> for TinyString<A> {
+  #[inline]
+  fn eq(&self, other: &String) -> bool {
+    self.as_str() == other.as_str()
+  }
+}
+
+// //
+// Formatting impls
+// //
+
+impl<A: Array<Item = u8>> Debug for TinyString<A> {
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    Debug::fmt(self.as_str(), f)
+  }
+}
+
+impl<A: Array<Item = u8>> Display for TinyString<A> {
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    Display::fmt(self.as_str(), f)
+  }
+}
@@ -3,33 +3,72 @@
 use super::*;
 
 use alloc::vec::Vec;
+#[cfg(feature = "rustc_1_57")]
+use alloc::collections::TryReserveError;
+#[cfg(feature = "serde")]
+use core::marker::PhantomData;
+#[cfg(feature = "serde")]
+use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
+#[cfg(feature = "serde")]
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, Zeroizing};
 
 /// Helper to make a `TinyVec`.
 ///
 /// You specify the backing array type, and optionally give all the elements you
-/// want to initially place into the array.
-///
-/// As an unfortunate restriction, the backing array type must support `Default`
-/// for it to work with this macro.
+/// want to initially place into the array. Use `_` as the element type to have
+/// it inferred from the elements. If there are more elements than fit inline,
+/// the vec starts on the heap with exactly enough capacity for them.
 ///
 /// ```rust
 /// use tinyvec::*;
-/// 
-/// let empty_av = tiny_vec!([u8; 16]);
-/// 
+///
+/// let empty_tv = tiny_vec!([u8; 16]);
+///
 /// let some_ints = tiny_vec!([i32; 4], 1, 2, 3);
+///
+/// let many_ints = tiny_vec!([_; 2], 1_i32, 2, 3);
+/// assert!(matches!(many_ints, TinyVec::Heap(_)));
+/// assert_eq!(many_ints.capacity(), 3);
+/// ```
+///
+/// You can also fill the vec with some number of clones of one element, like
+/// with `vec!`.
+///
+/// ```rust
+/// use tinyvec::*;
+///
+/// let zeroes = tiny_vec!([u8; 16] => 0; 100);
+/// assert_eq!(zeroes.as_slice(), &[0; 100][..]);
 /// ```
+///
+/// With the `rustc_1_61` feature you can also put `const` in front of the type
+/// to build the vec in a const context, the same as with
+/// [`array_vec!`](crate::array_vec!).
 #[macro_export]
 macro_rules! tiny_vec {
-  ($array_type:ty) => {
+  (const $array_type:ty $(, $elem:expr)* $(,)?) => {
+    $crate::TinyVec::<$array_type>::Inline(
+      $crate::array_vec!(const $array_type $(, $elem)*)
+    )
+  };
+  ($array_type:ty => $elem:expr; $n:expr) => {
     {
-      let mut tv: TinyVec<$array_type> = Default::default();
+      let n = $n;
+      let mut tv: $crate::TinyVec<$array_type> =
+        $crate::TinyVec::with_capacity(n);
+      tv.resize(n, $elem);
       tv
     }
   };
-  ($array_type:ty, $($elem:expr),*) => {
+  ($array_type:ty $(,)?) => {
+    $crate::TinyVec::<$array_type>::new()
+  };
+  ($array_type:ty, $($elem:expr),* $(,)?) => {
     {
-      let mut tv: TinyVec<$array_type> = Default::default();
+      let mut tv: $crate::TinyVec<$array_type> =
+        $crate::TinyVec::with_capacity($crate::__tinyvec_count!($($elem),*));
       $( tv.push($elem); )*
       tv
     }
@@ -46,27 +85,56 @@ macro_rules! tiny_vec {
 /// internals" since no safety invariants are on the line. It's kinda wild how
 /// much you can just let people poke at stuff without worry when it's 100% safe
 /// code.
-#[derive(Clone)]
-pub enum TinyVec<A: Array> {
+///
+/// The second type parameter is the [`SpillPolicy`], which decides how the vec
+/// moves to the heap and whether it ever comes back. It defaults to
+/// [`Sticky`], which is the classic behavior.
+///
+/// ```rust
+/// use tinyvec::*;
+///
+/// let mut tv: TinyVec<[u8; 4], Exact> = TinyVec::new();
+/// tv.extend_from_slice(&[1, 2, 3, 4, 5]);
+/// assert_eq!(tv.capacity(), 5);
+/// ```
+pub enum TinyVec<A: Array, P: SpillPolicy = Sticky> {
   #[allow(missing_docs)]
   Inline(ArrayVec<A>),
-  #[allow(missing_docs)]
-  Heap(Vec<A::Item>)
+  /// This is just a `Vec<A::Item>`, the item type is only written this way
+  /// so that the enum can carry the policy type.
+  Heap(Vec<<A as PolicyItem<P>>::Item>),
+}
+impl<A, P> Clone for TinyVec<A, P>
+where
+  A: Array + Clone,
+  A::Item: Clone,
+  P: SpillPolicy,
+{
+  #[inline]
+  fn clone(&self) -> Self {
+    match self {
+      TinyVec::Inline(a) => TinyVec::Inline(a.clone()),
+      TinyVec::Heap(v) => TinyVec::Heap(v.clone()),
+    }
+  }
 }
-impl<A: Array + Default> Default for TinyVec<A> {
+impl<A: Array, P: SpillPolicy> Default for TinyVec<A, P> {
   #[inline]
   #[must_use]
   fn default() -> Self {
     TinyVec::Inline(ArrayVec::default())
   }
 }
-impl<A: Array> TinyVec<A> {
+impl<A: Array, P: SpillPolicy> TinyVec<A, P> {
   /// Moves the content of the TinyVec to the heap, if it's inline.
+  ///
+  /// The heap capacity is picked by the vec's [`SpillPolicy`].
   #[allow(clippy::missing_inline_in_public_items)]
   pub fn move_to_the_heap(&mut self) {
     match self {
       TinyVec::Inline(ref mut arr) => {
-        let mut v = Vec::with_capacity(A::CAPACITY * 2);
+        Self::note_spill(arr.len(), 0);
+        let mut v = Vec::with_capacity(Self::spill_capacity(arr.len(), 0));
         for item in arr.drain(..) {
           v.push(item);
         }
@@ -75,9 +143,180 @@ impl<A: Array> TinyVec<A> {
       TinyVec::Heap(_) => (),
     }
   }
+
+  /// Moves the content of the TinyVec to the heap if it's inline, with room
+  /// for at least `n` more elements.
+  ///
+  /// The heap capacity is picked by the vec's [`SpillPolicy`]. If it's
+  /// already on the heap this is the same as `Vec::reserve` (or
+  /// `Vec::reserve_exact` with an exact growth policy).
+  #[inline]
+  pub fn move_to_the_heap_and_reserve(&mut self, n: usize) {
+    match self {
+      TinyVec::Inline(arr) => {
+        Self::note_spill(arr.len(), n);
+        let mut v = Vec::with_capacity(Self::spill_capacity(arr.len(), n));
+        v.extend(arr.drain(..));
+        *self = TinyVec::Heap(v);
+      }
+      TinyVec::Heap(v) => Self::grow_heap(v, n),
+    }
+  }
+
+  /// Moves the content of the TinyVec to the heap if it's inline, with room
+  /// for exactly `n` more elements.
+  ///
+  /// If it's already on the heap this is the same as `Vec::reserve_exact`.
+  #[inline]
+  pub fn move_to_the_heap_and_reserve_exact(&mut self, n: usize) {
+    match self {
+      TinyVec::Inline(arr) => {
+        let total = arr.len().checked_add(n).expect("capacity overflow");
+        Self::note_spill(arr.len(), n);
+        let mut v = Vec::with_capacity(total);
+        v.extend(arr.drain(..));
+        *self = TinyVec::Heap(v);
+      }
+      TinyVec::Heap(v) => v.reserve_exact(n),
+    }
+  }
+
+  /// Moves the content of the TinyVec to the heap if it's inline, with room
+  /// for at least `n` more elements, reporting allocation failure.
+  ///
+  /// * Requires the `rustc_1_57` feature
+  ///
+  /// ## Failure
+  ///
+  /// If the allocation fails the vec is unchanged and you get the error.
+  #[cfg(feature = "rustc_1_57")]
+  #[inline]
+  pub fn try_move_to_the_heap_and_reserve(
+    &mut self,
+    n: usize,
+  ) -> Result<(), TryReserveError> {
+    match self {
+      TinyVec::Inline(arr) => {
+        let mut v = Vec::new();
+        match arr.len().checked_add(n) {
+          Some(_) => v.try_reserve_exact(Self::spill_capacity(arr.len(), n))?,
+          // let the `Vec` report the overflow, and put things back after
+          None => {
+            v.try_reserve_exact(arr.len())?;
+            v.extend(arr.drain(..));
+            if let Err(e) = v.try_reserve(n) {
+              arr.extend(v.drain(..));
+              return Err(e);
+            }
+          }
+        }
+        Self::note_spill(arr.len(), n);
+        v.extend(arr.drain(..));
+        *self = TinyVec::Heap(v);
+        Ok(())
+      }
+      TinyVec::Heap(v) => v.try_reserve(n),
+    }
+  }
+
+  /// Moves the content of the TinyVec to the heap if it's inline, with room
+  /// for exactly `n` more elements, reporting allocation failure.
+  ///
+  /// * Requires the `rustc_1_57` feature
+  ///
+  /// ## Failure
+  ///
+  /// If the allocation fails the vec is unchanged and you get the error.
+  #[cfg(feature = "rustc_1_57")]
+  #[inline]
+  pub fn try_move_to_the_heap_and_reserve_exact(
+    &mut self,
+    n: usize,
+  ) -> Result<(), TryReserveError> {
+    match self {
+      TinyVec::Inline(arr) => {
+        let mut v = Vec::new();
+        match arr.len().checked_add(n) {
+          Some(total) => v.try_reserve_exact(total)?,
+          // let the `Vec` report the overflow, and put things back after
+          None => {
+            v.try_reserve_exact(arr.len())?;
+            v.extend(arr.drain(..));
+            if let Err(e) = v.try_reserve_exact(n) {
+              arr.extend(v.drain(..));
+              return Err(e);
+            }
+          }
+        }
+        Self::note_spill(arr.len(), n);
+        v.extend(arr.drain(..));
+        *self = TinyVec::Heap(v);
+        Ok(())
+      }
+      TinyVec::Heap(v) => v.try_reserve_exact(n),
+    }
+  }
+
+  /// The heap capacity to use when spilling `len` elements with room for
+  /// `additional` more, as the policy asks, but never too small.
+  #[inline]
+  fn spill_capacity(len: usize, additional: usize) -> usize {
+    let needed = len.checked_add(additional).expect("capacity overflow");
+    P::heap_capacity(A::CAPACITY, len, additional).max(needed)
+  }
+
+  /// Makes room for `n` more elements on the heap up front, if the policy
+  /// wants exact growth. Otherwise `Vec` grows as it normally would.
+  #[inline(always)]
+  fn grow_heap(v: &mut Vec<A::Item>, n: usize) {
+    if P::EXACT_GROWTH {
+      v.reserve_exact(n)
+    }
+  }
+
+  /// Records a move to the heap by a vec holding `len` elements that needed
+  /// room for `additional` more, with the `spill_stats` feature.
+  ///
+  /// This is the only place the stats are touched, so pushing to an inline vec
+  /// (or a heap vec) doesn't have to count anything.
+  #[inline(always)]
+  fn note_spill(_len: usize, _additional: usize) {
+    #[cfg(feature = "spill_stats")]
+    P::stats().record_spill(_len.saturating_add(_additional));
+  }
+
+  /// Resets the heap slots from the length up to `old_len` back to the
+  /// default value, with the `wipe_vacated` feature.
+  #[inline(always)]
+  fn wipe_vacated(_v: &mut Vec<A::Item>, _old_len: usize) {
+    #[cfg(feature = "wipe_vacated")]
+    {
+      let len = _v.len();
+      if _old_len > len {
+        _v.resize_with(_old_len, A::Item::default);
+        _v.truncate(len);
+      }
+    }
+  }
+
+  /// Moves a heap vec back inline, if the policy says it's short enough.
+  #[inline]
+  fn demote_if_short(&mut self) {
+    if let TinyVec::Heap(v) = self {
+      if let Some(demote_len) = P::demote_len(A::CAPACITY) {
+        if v.len() <= demote_len.min(A::CAPACITY) {
+          let old_len = v.len();
+          let mut a = ArrayVec::default();
+          a.extend(v.drain(..));
+          Self::wipe_vacated(v, old_len);
+          *self = TinyVec::Inline(a);
+        }
+      }
+    }
+  }
 }
 
-impl<A: Array> Deref for TinyVec<A> {
+impl<A: Array, P: SpillPolicy> Deref for TinyVec<A, P> {
   type Target = [A::Item];
   #[inline(always)]
   #[must_use]
@@ -89,7 +328,7 @@ impl<A: Array> Deref for TinyVec<A> {
   }
 }
 
-impl<A: Array> DerefMut for TinyVec<A> {
+impl<A: Array, P: SpillPolicy> DerefMut for TinyVec<A, P> {
   #[inline(always)]
   #[must_use]
   fn deref_mut(&mut self) -> &mut Self::Target {
@@ -100,7 +339,9 @@ impl<A: Array> DerefMut for TinyVec<A> {
   }
 }
 
-impl<A: Array, I: SliceIndex<[A::Item]>> Index<I> for TinyVec<A> {
+impl<A: Array, P: SpillPolicy, I: SliceIndex<[A::Item]>> Index<I>
+  for TinyVec<A, P>
+{
   type Output = <I as SliceIndex<[A::Item]>>::Output;
   #[inline(always)]
   #[must_use]
@@ -109,7 +350,9 @@ impl<A: Array, I: SliceIndex<[A::Item]>> Index<I> for TinyVec<A> {
   }
 }
 
-impl<A: Array, I: SliceIndex<[A::Item]>> IndexMut<I> for TinyVec<A> {
+impl<A: Array, P: SpillPolicy, I: SliceIndex<[A::Item]>> IndexMut<I>
+  for TinyVec<A, P>
+{
   #[inline(always)]
   #[must_use]
   fn index_mut(&mut self, index: I) -> &mut Self::Output {
@@ -117,7 +360,7 @@ impl<A: Array, I: SliceIndex<[A::Item]>> IndexMut<I> for TinyVec<A> {
   }
 }
 
-impl<A: Array> TinyVec<A> {
+impl<A: Array, P: SpillPolicy> TinyVec<A, P> {
   /// Move all values from `other` into this vec.
   #[inline]
   pub fn append(&mut self, other: &mut Self) {
@@ -169,12 +412,16 @@ impl<A: Array> TinyVec<A> {
   }
 
   /// The capacity of the `TinyVec`.
-  /// 
-  /// This is fixed based on the array type.
+  ///
+  /// While inline this is fixed based on the array type, once on the heap
+  /// it's the capacity of the `Vec`.
   #[inline(always)]
   #[must_use]
   pub fn capacity(&self) -> usize {
-    A::CAPACITY
+    match self {
+      TinyVec::Inline(a) => a.capacity(),
+      TinyVec::Heap(v) => v.capacity(),
+    }
   }
 
   /// Removes all elements from the vec.
@@ -183,8 +430,15 @@ impl<A: Array> TinyVec<A> {
     self.truncate(0)
   }
   
-  /// De-duplicates the vec.
-  #[cfg(feature = "nightly_slice_partition_dedup")]
+  /// De-duplicates the vec, removing consecutive repeated elements.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut tv = tiny_vec!([i32; 8], 1, 1, 2, 3, 3, 3, 1);
+  /// tv.dedup();
+  /// assert_eq!(tv.as_slice(), &[1, 2, 3, 1][..]);
+  /// ```
   #[inline(always)]
   pub fn dedup(&mut self)
   where
@@ -194,21 +448,24 @@ impl<A: Array> TinyVec<A> {
   }
 
   /// De-duplicates the vec according to the predicate given.
-  #[cfg(feature = "nightly_slice_partition_dedup")]
-  #[inline(always)]
+  ///
+  /// See [`ArrayVec::dedup_by`]
+  #[inline]
   pub fn dedup_by<F>(&mut self, same_bucket: F)
   where
     F: FnMut(&mut A::Item, &mut A::Item) -> bool,
   {
-    let len = {
-      let (dedup, _) = self.as_mut_slice().partition_dedup_by(same_bucket);
-      dedup.len()
-    };
-    self.truncate(len);
+    match self {
+      TinyVec::Inline(a) => a.dedup_by(same_bucket),
+      TinyVec::Heap(v) => {
+        let old_len = v.len();
+        v.dedup_by(same_bucket);
+        Self::wipe_vacated(v, old_len);
+      }
+    }
   }
 
   /// De-duplicates the vec according to the key selector given.
-  #[cfg(feature = "nightly_slice_partition_dedup")]
   #[inline(always)]
   pub fn dedup_by_key<F, K>(&mut self, mut key: F)
   where
@@ -240,7 +497,7 @@ impl<A: Array> TinyVec<A> {
   pub fn drain<R: RangeBounds<usize>>(
     &mut self,
     range: R,
-  ) -> TinyVecDrain<'_, A> {
+  ) -> TinyVecDrain<'_, A, P> {
     use core::ops::Bound;
     let start = match range.start_bound() {
       Bound::Included(x) => *x,
@@ -264,26 +521,116 @@ impl<A: Array> TinyVec<A> {
       end,
       self.len()
     );
-    TinyVecDrain {
-      parent: self,
-      target_index: start,
-      target_count: end - start,
-    }
+    TinyVecDrain { parent: self, start, front: start, back: end, end }
   }
 
-  // LATER(Vec): drain_filter #nightly https://github.com/rust-lang/rust/issues/43244
-
   /// Clone each element of the slice into this vec.
   #[inline]
   pub fn extend_from_slice(&mut self, sli: &[A::Item])
   where
     A::Item: Clone,
   {
-    for i in sli {
-      self.push(i.clone())
+    match self {
+      TinyVec::Inline(a) => {
+        if a.len() + sli.len() > A::CAPACITY {
+          self.move_to_the_heap_and_reserve(sli.len());
+          self.extend_from_slice(sli)
+        } else {
+          a.extend_from_slice(sli);
+        }
+      }
+      TinyVec::Heap(v) => {
+        Self::grow_heap(v, sli.len());
+        v.extend_from_slice(sli)
+      }
     }
   }
 
+  /// Creates an iterator that removes and yields the elements in the range
+  /// for which the predicate returns `true`.
+  ///
+  /// Elements for which the predicate returns `false` stay in the vec, in
+  /// their original order. The whole thing takes one pass over the vec, even
+  /// if the iterator is dropped early (any elements it didn't get to are
+  /// kept).
+  ///
+  /// ## Panics
+  /// * If the start is greater than the end
+  /// * If the end is past the edge of the vec.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut tv = tiny_vec!([i32; 8], 1, 2, 3, 4, 5, 6);
+  /// let evens: Vec<i32> = tv.extract_if(.., |x| *x % 2 == 0).collect();
+  /// assert_eq!(evens, vec![2, 4, 6]);
+  /// assert_eq!(tv.as_slice(), &[1, 3, 5][..]);
+  /// ```
+  #[inline]
+  pub fn extract_if<R, F>(
+    &mut self,
+    range: R,
+    pred: F,
+  ) -> TinyVecExtractIf<'_, A, F, P>
+  where
+    R: RangeBounds<usize>,
+    F: FnMut(&mut A::Item) -> bool,
+  {
+    use core::ops::Bound;
+    let start = match range.start_bound() {
+      Bound::Included(x) => *x,
+      Bound::Excluded(x) => x + 1,
+      Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+      Bound::Included(x) => x + 1,
+      Bound::Excluded(x) => *x,
+      Bound::Unbounded => self.len(),
+    };
+    assert!(
+      start <= end,
+      "TinyVec::extract_if> Illegal range, {} to {}",
+      start,
+      end
+    );
+    assert!(
+      end <= self.len(),
+      "TinyVec::extract_if> Range ends at {} but length is only {}!",
+      end,
+      self.len()
+    );
+    TinyVecExtractIf { parent: self, write: start, index: start, end, pred }
+  }
+
+  /// Wraps up an array as an empty, inline vec.
+  ///
+  /// With the `rustc_1_61` feature this is a `const fn`.
+  #[cfg(not(feature = "rustc_1_61"))]
+  #[inline(always)]
+  #[must_use]
+  pub fn from_array_empty(data: A) -> Self {
+    TinyVec::Inline(ArrayVec::from_array_empty(data))
+  }
+
+  /// Wraps up an array as an empty, inline vec.
+  ///
+  /// With the `rustc_1_61` feature this is a `const fn`.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// const EMPTY: TinyVec<[u8; 10]> = TinyVec::from_array_empty([0; 10]);
+  /// let mut tv = EMPTY;
+  /// tv.push(1);
+  /// assert_eq!(tv.as_slice(), &[1]);
+  /// ```
+  #[cfg(feature = "rustc_1_61")]
+  #[inline(always)]
+  #[must_use]
+  pub const fn from_array_empty(data: A) -> Self {
+    TinyVec::Inline(ArrayVec::from_array_empty(data))
+  }
+
   /// Wraps up an array and uses the given length as the initial length.
   ///
   /// Note that the `From` impl for arrays assumes the full length is used.
@@ -291,6 +638,7 @@ impl<A: Array> TinyVec<A> {
   /// ## Panics
   ///
   /// The length must be less than or equal to the capacity of the array.
+  #[cfg(not(feature = "rustc_1_61"))]
   #[inline]
   #[must_use]
   #[allow(clippy::match_wild_err_arm)]
@@ -303,6 +651,22 @@ impl<A: Array> TinyVec<A> {
     }
   }
 
+  /// Wraps up an array and uses the given length as the initial length.
+  ///
+  /// Note that the `From` impl for arrays assumes the full length is used.
+  /// With the `rustc_1_61` feature this is a `const fn`, so a bad length in a
+  /// const context is a compile error.
+  ///
+  /// ## Panics
+  ///
+  /// The length must be less than or equal to the capacity of the array.
+  #[cfg(feature = "rustc_1_61")]
+  #[inline]
+  #[must_use]
+  pub const fn from_array_len(data: A, len: usize) -> Self {
+    TinyVec::Inline(ArrayVec::from_array_len(data, len))
+  }
+
   /// Inserts an item at the position given, moving all following elements +1
   /// index.
   ///
@@ -320,14 +684,57 @@ impl<A: Array> TinyVec<A> {
   /// ```
   #[inline]
   pub fn insert(&mut self, index: usize, item: A::Item) {
+    assert!(
+      index <= self.len(),
+      "TinyVec::insert> index {} is out of bounds {}",
+      index,
+      self.len()
+    );
     match self {
       TinyVec::Inline(a) => if a.len() == A::CAPACITY {
-        self.move_to_the_heap();
+        self.move_to_the_heap_and_reserve(1);
         self.insert(index, item)
       } else {
         a.insert(index, item);
       },
-      TinyVec::Heap(v) => v.insert(index, item),
+      TinyVec::Heap(v) => {
+        Self::grow_heap(v, 1);
+        v.insert(index, item)
+      }
+    }
+  }
+
+  /// Clones a slice into the vec at the position given, moving all following
+  /// elements up by the length of the slice.
+  ///
+  /// ## Panics
+  /// * If `index` > `len`
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut tv = tiny_vec!([u8; 4], 1, 2, 3);
+  /// tv.insert_from_slice(1, &[7, 8, 9]);
+  /// assert_eq!(tv.as_slice(), &[1, 7, 8, 9, 2, 3][..]);
+  /// ```
+  #[inline]
+  pub fn insert_from_slice(&mut self, index: usize, sli: &[A::Item])
+  where
+    A::Item: Clone,
+  {
+    match self {
+      TinyVec::Inline(a) => {
+        if index <= a.len() && a.len() + sli.len() > A::CAPACITY {
+          self.move_to_the_heap_and_reserve(sli.len());
+          self.insert_from_slice(index, sli)
+        } else {
+          a.insert_from_slice(index, sli);
+        }
+      }
+      TinyVec::Heap(v) => {
+        Self::grow_heap(v, sli.len());
+        v.splice(index..index, sli.iter().cloned());
+      }
     }
   }
 
@@ -351,10 +758,7 @@ impl<A: Array> TinyVec<A> {
   /// Makes a new, empty vec.
   #[inline(always)]
   #[must_use]
-  pub fn new() -> Self
-  where
-    A: Default,
-  {
+  pub fn new() -> Self {
     Self::default()
   }
 
@@ -366,7 +770,13 @@ impl<A: Array> TinyVec<A> {
   pub fn pop(&mut self) -> Option<A::Item> {
     match self {
       TinyVec::Inline(a) => a.pop(),
-      TinyVec::Heap(v) => v.pop(),
+      TinyVec::Heap(v) => {
+        let old_len = v.len();
+        let out = v.pop();
+        Self::wipe_vacated(v, old_len);
+        self.demote_if_short();
+        out
+      }
     }
   }
 
@@ -379,12 +789,15 @@ impl<A: Array> TinyVec<A> {
   pub fn push(&mut self, val: A::Item) {
     match self {
       TinyVec::Inline(a) => if a.len() == A::CAPACITY {
-        self.move_to_the_heap();
+        self.move_to_the_heap_and_reserve(1);
         self.push(val)
       } else {
         a.push(val);
       },
-      TinyVec::Heap(v) => v.push(val),
+      TinyVec::Heap(v) => {
+        Self::grow_heap(v, 1);
+        v.push(val)
+      }
     }
   }
 
@@ -408,12 +821,82 @@ impl<A: Array> TinyVec<A> {
   pub fn remove(&mut self, index: usize) -> A::Item {
     match self {
       TinyVec::Inline(a) => a.remove(index),
-      TinyVec::Heap(v) => v.remove(index),
+      TinyVec::Heap(v) => {
+        let old_len = v.len();
+        let out = v.remove(index);
+        Self::wipe_vacated(v, old_len);
+        out
+      }
+    }
+  }
+
+  /// Removes all the elements in the range, moving all following elements
+  /// down to fill the gap.
+  ///
+  /// ## Panics
+  /// * If the start is greater than the end
+  /// * If the end is past the edge of the vec.
+  #[inline]
+  pub fn remove_range<R: RangeBounds<usize>>(&mut self, range: R) {
+    match self {
+      TinyVec::Inline(a) => a.remove_range(range),
+      TinyVec::Heap(v) => {
+        let old_len = v.len();
+        v.drain(range);
+        Self::wipe_vacated(v, old_len);
+      }
     }
   }
 
   // NIGHTLY: remove_item, https://github.com/rust-lang/rust/issues/40062
 
+  /// Makes sure there's room for at least `additional` more elements.
+  ///
+  /// An inline vec stays inline if the room is already there, otherwise it
+  /// moves to the heap.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut tv = tiny_vec!([i32; 4], 1, 2);
+  /// tv.reserve(2);
+  /// assert!(matches!(tv, TinyVec::Inline(_)));
+  /// tv.reserve(3);
+  /// assert!(matches!(tv, TinyVec::Heap(_)));
+  /// assert!(tv.capacity() >= 5);
+  /// ```
+  #[inline]
+  pub fn reserve(&mut self, additional: usize) {
+    match self {
+      TinyVec::Inline(a) => {
+        if additional > A::CAPACITY - a.len() {
+          self.move_to_the_heap_and_reserve(additional)
+        }
+      }
+      TinyVec::Heap(v) => {
+        Self::grow_heap(v, additional);
+        v.reserve(additional)
+      }
+    }
+  }
+
+  /// Makes sure there's room for exactly `additional` more elements, without
+  /// the extra space `reserve` might add to avoid frequent reallocations.
+  ///
+  /// An inline vec stays inline if the room is already there, otherwise it
+  /// moves to the heap.
+  #[inline]
+  pub fn reserve_exact(&mut self, additional: usize) {
+    match self {
+      TinyVec::Inline(a) => {
+        if additional > A::CAPACITY - a.len() {
+          self.move_to_the_heap_and_reserve_exact(additional)
+        }
+      }
+      TinyVec::Heap(v) => v.reserve_exact(additional),
+    }
+  }
+
   /// Resize the vec to the new length.
   ///
   /// If it needs to be longer, it's filled with clones of the provided value.
@@ -439,12 +922,18 @@ impl<A: Array> TinyVec<A> {
   {
     match self {
       TinyVec::Inline(a) => if new_len > A::CAPACITY {
-        self.move_to_the_heap();
+        let additional = new_len - a.len();
+        self.move_to_the_heap_and_reserve(additional);
         self.resize(new_len, new_val);
       } else {
         a.resize(new_len, new_val);
       },
-      TinyVec::Heap(v) => v.resize(new_len, new_val),
+      TinyVec::Heap(v) => {
+        Self::grow_heap(v, new_len.saturating_sub(v.len()));
+        let old_len = v.len();
+        v.resize(new_len, new_val);
+        Self::wipe_vacated(v, old_len);
+      }
     }
   }
 
@@ -477,8 +966,21 @@ impl<A: Array> TinyVec<A> {
     f: F,
   ) {
     match self {
-      TinyVec::Inline(a) => a.resize_with(new_len, f),
-      TinyVec::Heap(v) => v.resize_with(new_len, f),
+      TinyVec::Inline(a) => {
+        if new_len > A::CAPACITY {
+          let additional = new_len - a.len();
+          self.move_to_the_heap_and_reserve(additional);
+          self.resize_with(new_len, f)
+        } else {
+          a.resize_with(new_len, f)
+        }
+      }
+      TinyVec::Heap(v) => {
+        Self::grow_heap(v, new_len.saturating_sub(v.len()));
+        let old_len = v.len();
+        v.resize_with(new_len, f);
+        Self::wipe_vacated(v, old_len);
+      }
     }
   }
 
@@ -497,19 +999,154 @@ impl<A: Array> TinyVec<A> {
   pub fn retain<F: FnMut(&A::Item) -> bool>(&mut self, acceptable: F) {
     match self {
       TinyVec::Inline(a) => a.retain(acceptable),
-      TinyVec::Heap(v) => v.retain(acceptable),
+      TinyVec::Heap(v) => {
+        let old_len = v.len();
+        v.retain(acceptable);
+        Self::wipe_vacated(v, old_len);
+        self.demote_if_short()
+      }
     }
   }
 
-  // LATER(Vec): splice
+  /// Walk the vec and keep only the elements that pass the predicate given,
+  /// which can also modify them.
+  ///
+  /// See [`ArrayVec::retain_mut`]
+  #[inline]
+  pub fn retain_mut<F: FnMut(&mut A::Item) -> bool>(
+    &mut self,
+    mut acceptable: F,
+  ) {
+    match self {
+      TinyVec::Inline(a) => a.retain_mut(acceptable),
+      TinyVec::Heap(_) => {
+        self.extract_if(.., |x| !acceptable(x)).for_each(drop);
+        self.demote_if_short()
+      }
+    }
+  }
 
-  /// Splits the collection at the point given.
+  /// Reduces the capacity to at least `min_capacity`, while keeping room for
+  /// the current length.
   ///
-  /// * `[0, at)` stays in this vec
-  /// * `[at, len)` ends up in the new vec.
+  /// A heap vec moves back inline if both of those fit in the inline
+  /// capacity.
   ///
-  /// ## Panics
-  /// * if at > len
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut tv: TinyVec<[i32; 4]> = TinyVec::with_capacity(100);
+  /// tv.extend_from_slice(&[1, 2, 3]);
+  /// tv.shrink_to(10);
+  /// assert!(tv.capacity() >= 10 && tv.capacity() < 100);
+  /// tv.shrink_to(2);
+  /// assert!(matches!(tv, TinyVec::Inline(_)));
+  /// ```
+  #[inline]
+  pub fn shrink_to(&mut self, min_capacity: usize) {
+    match self {
+      TinyVec::Inline(_) => (),
+      TinyVec::Heap(v) => {
+        let old_len = v.len();
+        let target = old_len.max(min_capacity);
+        if target <= A::CAPACITY {
+          let mut a = ArrayVec::default();
+          a.extend(v.drain(..));
+          Self::wipe_vacated(v, old_len);
+          *self = TinyVec::Inline(a);
+        } else if target < v.capacity() {
+          let mut new_v = Vec::with_capacity(target);
+          new_v.append(v);
+          Self::wipe_vacated(v, old_len);
+          *v = new_v;
+        }
+      }
+    }
+  }
+
+  /// Reduces the capacity as much as possible.
+  ///
+  /// A heap vec moves back inline if the elements fit in the inline capacity.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut tv: TinyVec<[i32; 4]> = (0..10).collect();
+  /// tv.truncate(4);
+  /// tv.shrink_to_fit();
+  /// assert!(matches!(tv, TinyVec::Inline(_)));
+  /// ```
+  #[inline]
+  pub fn shrink_to_fit(&mut self) {
+    match self {
+      TinyVec::Inline(_) => (),
+      TinyVec::Heap(v) => {
+        if v.len() <= A::CAPACITY {
+          self.shrink_to(0)
+        } else {
+          v.shrink_to_fit()
+        }
+      }
+    }
+  }
+
+  /// Replaces the elements in the range with the elements of `replacement`,
+  /// giving back the removed elements.
+  ///
+  /// If an inline vec doesn't have room for the replacement it moves to the
+  /// heap. While inline the replacement is done right away and the returned
+  /// iterator owns the removed elements.
+  ///
+  /// ## Panics
+  /// * If the start is greater than the end
+  /// * If the end is past the edge of the vec.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut tv = tiny_vec!([i32; 4], 1, 2, 3, 4);
+  /// let removed: Vec<i32> = tv.splice(1..3, vec![7, 8, 9]).collect();
+  /// assert_eq!(removed, vec![2, 3]);
+  /// assert_eq!(tv.as_slice(), &[1, 7, 8, 9, 4][..]);
+  /// assert!(matches!(tv, TinyVec::Heap(_)));
+  /// ```
+  #[inline]
+  pub fn splice<R, I>(&mut self, range: R, replacement: I) -> TinyVecIterator<A>
+  where
+    R: RangeBounds<usize>,
+    I: IntoIterator<Item = A::Item>,
+  {
+    match self {
+      TinyVec::Inline(a) => {
+        use core::ops::Bound;
+        let start = match range.start_bound() {
+          Bound::Included(x) => *x,
+          Bound::Excluded(x) => x + 1,
+          Bound::Unbounded => 0,
+        };
+        let removed: ArrayVec<A> = a.drain(range).collect();
+        let old_len = self.len();
+        self.extend(replacement);
+        let added = self.len() - old_len;
+        self.as_mut_slice()[start..].rotate_right(added);
+        TinyVecIterator::Inline(removed.into_iter())
+      }
+      TinyVec::Heap(v) => {
+        let old_len = v.len();
+        let removed: Vec<A::Item> = v.splice(range, replacement).collect();
+        Self::wipe_vacated(v, old_len);
+        TinyVecIterator::Heap(removed.into_iter())
+      }
+    }
+  }
+
+  /// Splits the collection at the point given.
+  ///
+  /// * `[0, at)` stays in this vec
+  /// * `[at, len)` ends up in the new vec.
+  ///
+  /// ## Panics
+  /// * if at > len
   ///
   /// ## Example
   ///
@@ -521,13 +1158,15 @@ impl<A: Array> TinyVec<A> {
   /// assert_eq!(tv2.as_slice(), &[2, 3][..]);
   /// ```
   #[inline]
-  pub fn split_off(&mut self, at: usize) -> Self
-  where
-    A: Default,
-  {
+  pub fn split_off(&mut self, at: usize) -> Self {
     match self {
       TinyVec::Inline(a) => TinyVec::Inline(a.split_off(at)),
-      TinyVec::Heap(v) => TinyVec::Heap(v.split_off(at)),
+      TinyVec::Heap(v) => {
+        let old_len = v.len();
+        let out = v.split_off(at);
+        Self::wipe_vacated(v, old_len);
+        TinyVec::Heap(out)
+      }
     }
   }
 
@@ -551,7 +1190,12 @@ impl<A: Array> TinyVec<A> {
   pub fn swap_remove(&mut self, index: usize) -> A::Item {
     match self {
       TinyVec::Inline(a) => a.swap_remove(index),
-      TinyVec::Heap(v) => v.swap_remove(index),
+      TinyVec::Heap(v) => {
+        let old_len = v.len();
+        let out = v.swap_remove(index);
+        Self::wipe_vacated(v, old_len);
+        out
+      }
     }
   }
 
@@ -562,7 +1206,52 @@ impl<A: Array> TinyVec<A> {
   pub fn truncate(&mut self, new_len: usize) {
     match self {
       TinyVec::Inline(a) => a.truncate(new_len),
-      TinyVec::Heap(v) => v.truncate(new_len),
+      TinyVec::Heap(v) => {
+        let old_len = v.len();
+        v.truncate(new_len);
+        Self::wipe_vacated(v, old_len);
+        self.demote_if_short()
+      }
+    }
+  }
+
+  /// Creates a draining iterator, if the range is valid for this vec.
+  ///
+  /// This is [`drain`](TinyVec::drain) without the panics.
+  ///
+  /// ## Failure
+  /// * If the start is greater than the end, or the end is past the edge of
+  ///   the vec, you get `None` and the vec is unchanged.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut tv = tiny_vec!([i32; 4], 1, 2, 3);
+  /// assert!(tv.try_drain(2..5).is_none());
+  /// let tv2: TinyVec<[i32; 4]> = tv.try_drain(1..).unwrap().collect();
+  /// assert_eq!(tv.as_slice(), &[1][..]);
+  /// assert_eq!(tv2.as_slice(), &[2, 3][..]);
+  /// ```
+  #[inline]
+  pub fn try_drain<R: RangeBounds<usize>>(
+    &mut self,
+    range: R,
+  ) -> Option<TinyVecDrain<'_, A, P>> {
+    use core::ops::Bound;
+    let start = match range.start_bound() {
+      Bound::Included(x) => *x,
+      Bound::Excluded(x) => x.checked_add(1)?,
+      Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+      Bound::Included(x) => x.checked_add(1)?,
+      Bound::Excluded(x) => *x,
+      Bound::Unbounded => self.len(),
+    };
+    if start <= end && end <= self.len() {
+      Some(TinyVecDrain { parent: self, start, front: start, back: end, end })
+    } else {
+      None
     }
   }
 
@@ -581,44 +1270,374 @@ impl<A: Array> TinyVec<A> {
     Ok(TinyVec::Inline(arr))
   }
 
-  // LATER: try_push ?
+  /// Pushes an item, reporting allocation failure instead of aborting.
+  ///
+  /// * Requires the `rustc_1_57` feature
+  ///
+  /// ## Failure
+  ///
+  /// If the vec needs to grow and the allocation fails, the vec is unchanged
+  /// and you get the item back in the `Err`.
+  #[cfg(feature = "rustc_1_57")]
+  #[inline]
+  pub fn try_push(&mut self, val: A::Item) -> Result<(), A::Item> {
+    if self.try_reserve(1).is_err() {
+      return Err(val);
+    }
+    self.push(val);
+    Ok(())
+  }
+
+  /// Inserts an item at the position given, if the vec can grow to fit it.
+  ///
+  /// * Requires the `rustc_1_57` feature
+  ///
+  /// ## Panics
+  /// * If `index` > `len`, the same as [`insert`](TinyVec::insert).
+  ///
+  /// ## Failure
+  ///
+  /// If the vec needs to grow and the allocation fails, the vec is unchanged
+  /// and you get the item back in the `Err`.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut tv = tiny_vec!([i32; 2], 1, 2);
+  /// assert_eq!(tv.try_insert(1, 9), Ok(()));
+  /// assert_eq!(tv.as_slice(), &[1, 9, 2][..]);
+  /// ```
+  #[cfg(feature = "rustc_1_57")]
+  #[inline]
+  pub fn try_insert(
+    &mut self,
+    index: usize,
+    item: A::Item,
+  ) -> Result<(), A::Item> {
+    assert!(
+      index <= self.len(),
+      "TinyVec::try_insert> index {} is out of bounds {}",
+      index,
+      self.len()
+    );
+    if self.try_reserve(1).is_err() {
+      return Err(item);
+    }
+    self.insert(index, item);
+    Ok(())
+  }
+
+  /// Remove an element, shifting all following elements down, if the index is
+  /// in bounds.
+  ///
+  /// ## Failure
+  /// * If the index is out of bounds you get `None` and the vec is unchanged.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut tv = tiny_vec!([i32; 4], 1, 2, 3);
+  /// assert_eq!(tv.try_remove(3), None);
+  /// assert_eq!(tv.try_remove(1), Some(2));
+  /// assert_eq!(tv.as_slice(), &[1, 3][..]);
+  /// ```
+  #[inline]
+  pub fn try_remove(&mut self, index: usize) -> Option<A::Item> {
+    if index < self.len() {
+      Some(self.remove(index))
+    } else {
+      None
+    }
+  }
+
+  /// Makes sure there's room for at least `additional` more elements,
+  /// reporting allocation failure instead of aborting.
+  ///
+  /// * Requires the `rustc_1_57` feature
+  ///
+  /// An inline vec stays inline if the room is already there.
+  ///
+  /// ## Failure
+  ///
+  /// If the allocation fails the vec is unchanged and you get the error.
+  #[cfg(feature = "rustc_1_57")]
+  #[inline]
+  pub fn try_reserve(
+    &mut self,
+    additional: usize,
+  ) -> Result<(), TryReserveError> {
+    match self {
+      TinyVec::Inline(a) => {
+        if additional > A::CAPACITY - a.len() {
+          self.try_move_to_the_heap_and_reserve(additional)
+        } else {
+          Ok(())
+        }
+      }
+      TinyVec::Heap(v) => v.try_reserve(additional),
+    }
+  }
+
+  /// Makes sure there's room for exactly `additional` more elements,
+  /// reporting allocation failure instead of aborting.
+  ///
+  /// * Requires the `rustc_1_57` feature
+  ///
+  /// An inline vec stays inline if the room is already there.
+  ///
+  /// ## Failure
+  ///
+  /// If the allocation fails the vec is unchanged and you get the error.
+  #[cfg(feature = "rustc_1_57")]
+  #[inline]
+  pub fn try_reserve_exact(
+    &mut self,
+    additional: usize,
+  ) -> Result<(), TryReserveError> {
+    match self {
+      TinyVec::Inline(a) => {
+        if additional > A::CAPACITY - a.len() {
+          self.try_move_to_the_heap_and_reserve_exact(additional)
+        } else {
+          Ok(())
+        }
+      }
+      TinyVec::Heap(v) => v.try_reserve_exact(additional),
+    }
+  }
+
+  /// Splits the collection at the point given, if it's in bounds.
+  ///
+  /// ## Failure
+  /// * If `at` > `len` you get `None` and the vec is unchanged.
+  #[inline]
+  pub fn try_split_off(&mut self, at: usize) -> Option<Self> {
+    if at <= self.len() {
+      Some(self.split_off(at))
+    } else {
+      None
+    }
+  }
+
+  /// Remove an element, swapping the end of the vec into its place, if the
+  /// index is in bounds.
+  ///
+  /// ## Failure
+  /// * If the index is out of bounds you get `None` and the vec is unchanged.
+  #[inline]
+  pub fn try_swap_remove(&mut self, index: usize) -> Option<A::Item> {
+    if index < self.len() {
+      Some(self.swap_remove(index))
+    } else {
+      None
+    }
+  }
+
+  /// Makes a new, empty vec with room for at least `cap` elements.
+  ///
+  /// If that fits in the inline capacity the vec starts out inline, otherwise
+  /// it starts on the heap with exactly that much room.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let tv: TinyVec<[u8; 16]> = TinyVec::with_capacity(10);
+  /// assert!(matches!(tv, TinyVec::Inline(_)));
+  /// let tv: TinyVec<[u8; 16]> = TinyVec::with_capacity(100);
+  /// assert!(matches!(tv, TinyVec::Heap(_)));
+  /// ```
+  #[inline]
+  #[must_use]
+  pub fn with_capacity(cap: usize) -> Self {
+    if cap <= A::CAPACITY {
+      TinyVec::Inline(ArrayVec::default())
+    } else {
+      TinyVec::Heap(Vec::with_capacity(cap))
+    }
+  }
+}
 
-  // LATER: try_insert ?
+/// Filtering iterator for `TinyVec`
+///
+/// See [`TinyVec::extract_if`](TinyVec::<A>::extract_if)
+pub struct TinyVecExtractIf<'p, A, F, P = Sticky>
+where
+  A: Array,
+  F: FnMut(&mut A::Item) -> bool,
+  P: SpillPolicy,
+{
+  parent: &'p mut TinyVec<A, P>,
+  write: usize,
+  index: usize,
+  end: usize,
+  pred: F,
+}
+impl<'p, A, F, P> Iterator for TinyVecExtractIf<'p, A, F, P>
+where
+  A: Array,
+  F: FnMut(&mut A::Item) -> bool,
+  P: SpillPolicy,
+{
+  type Item = A::Item;
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    while self.index < self.end {
+      let i = self.index;
+      let targets = self.parent.as_mut_slice();
+      if (self.pred)(&mut targets[i]) {
+        self.index += 1;
+        // `mem::take` needs Rust 1.40.
+        #[allow(clippy::mem_replace_with_default)]
+        let out = replace(&mut targets[i], A::Item::default());
+        return Some(out);
+      }
+      targets.swap(self.write, i);
+      self.write += 1;
+      self.index += 1;
+    }
+    None
+  }
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (0, Some(self.end - self.index))
+  }
+}
+impl<'p, A, F, P> Drop for TinyVecExtractIf<'p, A, F, P>
+where
+  A: Array,
+  F: FnMut(&mut A::Item) -> bool,
+  P: SpillPolicy,
+{
+  #[inline]
+  fn drop(&mut self) {
+    // Only the holes left by yielded elements get closed up here, the
+    // predicate isn't called again.
+    let removed = self.index - self.write;
+    if removed > 0 {
+      self.parent.as_mut_slice()[self.write..].rotate_left(removed);
+      let new_len = self.parent.len() - removed;
+      self.parent.truncate(new_len);
+    }
+  }
+}
 
-  // LATER: try_remove ?
+/// Draining iterator for `TinyVec`
+///
+/// See [`TinyVec::drain`](TinyVec::<A>::drain)
+pub struct TinyVecDrain<'p, A: Array, P: SpillPolicy = Sticky> {
+  parent: &'p mut TinyVec<A, P>,
+  start: usize,
+  front: usize,
+  back: usize,
+  end: usize,
 }
+impl<'p, A: Array, P: SpillPolicy> TinyVecDrain<'p, A, P> {
+  /// The elements that haven't been yielded yet, as a slice.
+  #[inline]
+  #[must_use]
+  pub fn as_slice(&self) -> &[A::Item] {
+    &self.parent[self.front..self.back]
+  }
 
-/// Draining iterator for `TinyVecDrain`
-/// 
-/// See [`TinyVecDrain::drain`](TinyVecDrain::<A>::drain)
-pub struct TinyVecDrain<'p, A: Array> {
-  parent: &'p mut TinyVec<A>,
-  target_index: usize,
-  target_count: usize,
+  /// Stops draining, and puts the elements that haven't been yielded yet
+  /// back into the vec where they were.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use tinyvec::*;
+  /// let mut v = tiny_vec!([i32; 4], 1, 2, 3, 4);
+  /// let mut d = v.drain(..);
+  /// assert_eq!(d.next(), Some(1));
+  /// assert_eq!(d.next_back(), Some(4));
+  /// d.keep_rest();
+  /// assert_eq!(v.as_slice(), &[2, 3][..]);
+  /// ```
+  #[inline]
+  pub fn keep_rest(mut self) {
+    self.close_gap();
+  }
+
+  /// Shifts the kept elements and the tail down over the yielded slots, all
+  /// in one pass, and leaves the drain empty.
+  #[inline]
+  fn close_gap(&mut self) {
+    let kept = self.back - self.front;
+    let removed = (self.end - self.start) - kept;
+    if removed > 0 {
+      let targets = &mut self.parent.as_mut_slice()[self.start..];
+      targets.rotate_left(self.front - self.start);
+      targets[kept..].rotate_left(self.end - self.back);
+      let new_len = self.parent.len() - removed;
+      self.parent.truncate(new_len);
+    }
+    self.front = self.start;
+    self.back = self.start;
+    self.end = self.start;
+  }
 }
-// GoodFirstIssue: this entire type is correct but slow.
-// NIGHTLY: vec_drain_as_slice, https://github.com/rust-lang/rust/issues/58957
-impl<'p, A: Array> Iterator for TinyVecDrain<'p, A> {
+impl<'p, A: Array, P: SpillPolicy> Iterator for TinyVecDrain<'p, A, P> {
   type Item = A::Item;
   #[inline]
   fn next(&mut self) -> Option<Self::Item> {
-    if self.target_count > 0 {
-      let out = self.parent.remove(self.target_index);
-      self.target_count -= 1;
+    if self.front < self.back {
+      // `mem::take` needs Rust 1.40.
+      #[allow(clippy::mem_replace_with_default)]
+      let out = replace(
+        &mut self.parent.as_mut_slice()[self.front],
+        A::Item::default(),
+      );
+      self.front += 1;
       Some(out)
     } else {
       None
     }
   }
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let s = self.back - self.front;
+    (s, Some(s))
+  }
 }
-impl<'p, A: Array> Drop for TinyVecDrain<'p, A> {
+impl<'p, A: Array, P: SpillPolicy> DoubleEndedIterator
+  for TinyVecDrain<'p, A, P>
+{
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    if self.front < self.back {
+      self.back -= 1;
+      // `mem::take` needs Rust 1.40.
+      #[allow(clippy::mem_replace_with_default)]
+      let out =
+        replace(&mut self.parent.as_mut_slice()[self.back], A::Item::default());
+      Some(out)
+    } else {
+      None
+    }
+  }
+}
+impl<'p, A: Array, P: SpillPolicy> ExactSizeIterator
+  for TinyVecDrain<'p, A, P>
+{
+}
+impl<'p, A: Array, P: SpillPolicy> FusedIterator for TinyVecDrain<'p, A, P> {}
+impl<'p, A: Array, P: SpillPolicy> Drop for TinyVecDrain<'p, A, P> {
   #[inline]
   fn drop(&mut self) {
-    for _ in self {}
+    for _ in self.by_ref() {}
+    self.close_gap();
+  }
+}
+impl<'p, A: Array, P: SpillPolicy> Debug for TinyVecDrain<'p, A, P>
+where
+  A::Item: Debug,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    f.debug_tuple("TinyVecDrain").field(&self.as_slice()).finish()
   }
 }
 
-impl<A: Array> AsMut<[A::Item]> for TinyVec<A> {
+impl<A: Array, P: SpillPolicy> AsMut<[A::Item]> for TinyVec<A, P> {
   #[inline(always)]
   #[must_use]
   fn as_mut(&mut self) -> &mut [A::Item] {
@@ -626,7 +1645,7 @@ impl<A: Array> AsMut<[A::Item]> for TinyVec<A> {
   }
 }
 
-impl<A: Array> AsRef<[A::Item]> for TinyVec<A> {
+impl<A: Array, P: SpillPolicy> AsRef<[A::Item]> for TinyVec<A, P> {
   #[inline(always)]
   #[must_use]
   fn as_ref(&self) -> &[A::Item] {
@@ -634,7 +1653,7 @@ impl<A: Array> AsRef<[A::Item]> for TinyVec<A> {
   }
 }
 
-impl<A: Array> Borrow<[A::Item]> for TinyVec<A> {
+impl<A: Array, P: SpillPolicy> Borrow<[A::Item]> for TinyVec<A, P> {
   #[inline(always)]
   #[must_use]
   fn borrow(&self) -> &[A::Item] {
@@ -642,7 +1661,7 @@ impl<A: Array> Borrow<[A::Item]> for TinyVec<A> {
   }
 }
 
-impl<A: Array> BorrowMut<[A::Item]> for TinyVec<A> {
+impl<A: Array, P: SpillPolicy> BorrowMut<[A::Item]> for TinyVec<A, P> {
   #[inline(always)]
   #[must_use]
   fn borrow_mut(&mut self) -> &mut [A::Item] {
@@ -650,7 +1669,7 @@ impl<A: Array> BorrowMut<[A::Item]> for TinyVec<A> {
   }
 }
 
-impl<A: Array> Extend<A::Item> for TinyVec<A> {
+impl<A: Array, P: SpillPolicy> Extend<A::Item> for TinyVec<A, P> {
   #[inline]
   fn extend<T: IntoIterator<Item = A::Item>>(&mut self, iter: T) {
     for t in iter {
@@ -659,7 +1678,7 @@ impl<A: Array> Extend<A::Item> for TinyVec<A> {
   }
 }
 
-impl<A: Array> From<ArrayVec<A>> for TinyVec<A> {
+impl<A: Array, P: SpillPolicy> From<ArrayVec<A>> for TinyVec<A, P> {
   #[inline(always)]
   #[must_use]
   fn from(arr: ArrayVec<A>) -> Self {
@@ -667,7 +1686,7 @@ impl<A: Array> From<ArrayVec<A>> for TinyVec<A> {
   }
 }
 
-impl<A: Array + Default> FromIterator<A::Item> for TinyVec<A> {
+impl<A: Array, P: SpillPolicy> FromIterator<A::Item> for TinyVec<A, P> {
   #[inline]
   #[must_use]
   fn from_iter<T: IntoIterator<Item = A::Item>>(iter: T) -> Self {
@@ -680,11 +1699,33 @@ impl<A: Array + Default> FromIterator<A::Item> for TinyVec<A> {
 }
 
 /// Iterator for consuming an `TinyVec` and returning owned elements.
+#[derive(Clone)]
 pub enum TinyVecIterator<A: Array> {
   #[allow(missing_docs)]
   Inline(ArrayVecIterator<A>),
   #[allow(missing_docs)]
-  Heap(alloc::vec::IntoIter<A::Item>)
+  Heap(alloc::vec::IntoIter<A::Item>),
+}
+impl<A: Array> TinyVecIterator<A> {
+  /// The elements that haven't been yielded yet, as a slice.
+  #[inline]
+  #[must_use]
+  pub fn as_slice(&self) -> &[A::Item] {
+    match self {
+      TinyVecIterator::Inline(a) => a.as_slice(),
+      TinyVecIterator::Heap(v) => v.as_slice(),
+    }
+  }
+
+  /// The elements that haven't been yielded yet, as a mutable slice.
+  #[inline]
+  #[must_use]
+  pub fn as_mut_slice(&mut self) -> &mut [A::Item] {
+    match self {
+      TinyVecIterator::Inline(a) => a.as_mut_slice(),
+      TinyVecIterator::Heap(v) => v.as_mut_slice(),
+    }
+  }
 }
 impl<A: Array> Iterator for TinyVecIterator<A> {
   type Item = A::Item;
@@ -725,8 +1766,35 @@ impl<A: Array> Iterator for TinyVecIterator<A> {
     }
   }
 }
+impl<A: Array> DoubleEndedIterator for TinyVecIterator<A> {
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    match self {
+      TinyVecIterator::Inline(a) => a.next_back(),
+      TinyVecIterator::Heap(v) => v.next_back(),
+    }
+  }
+  #[inline]
+  fn nth_back(&mut self, n: usize) -> Option<A::Item> {
+    match self {
+      TinyVecIterator::Inline(a) => a.nth_back(n),
+      TinyVecIterator::Heap(v) => v.nth_back(n),
+    }
+  }
+}
+impl<A: Array> ExactSizeIterator for TinyVecIterator<A> {}
+impl<A: Array> FusedIterator for TinyVecIterator<A> {}
+impl<A: Array> Debug for TinyVecIterator<A>
+where
+  A::Item: Debug,
+{
+  #[allow(clippy::missing_inline_in_public_items)]
+  fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+    f.debug_tuple("TinyVecIterator").field(&self.as_slice()).finish()
+  }
+}
 
-impl<A: Array> IntoIterator for TinyVec<A> {
+impl<A: Array, P: SpillPolicy> IntoIterator for TinyVec<A, P> {
   type Item = A::Item;
   type IntoIter = TinyVecIterator<A>;
   #[inline(always)]
@@ -739,7 +1807,7 @@ impl<A: Array> IntoIterator for TinyVec<A> {
   }
 }
 
-impl<A: Array> PartialEq for TinyVec<A>
+impl<A: Array, P: SpillPolicy> PartialEq for TinyVec<A, P>
 where
   A::Item: PartialEq,
 {
@@ -749,9 +1817,9 @@ where
     self.deref().eq(other.deref())
   }
 }
-impl<A: Array> Eq for TinyVec<A> where A::Item: Eq {}
+impl<A: Array, P: SpillPolicy> Eq for TinyVec<A, P> where A::Item: Eq {}
 
-impl<A: Array> PartialOrd for TinyVec<A>
+impl<A: Array, P: SpillPolicy> PartialOrd for TinyVec<A, P>
 where
   A::Item: PartialOrd,
 {
@@ -761,7 +1829,7 @@ where
     self.deref().partial_cmp(other.deref())
   }
 }
-impl<A: Array> Ord for TinyVec<A>
+impl<A: Array, P: SpillPolicy> Ord for TinyVec<A, P>
 where
   A::Item: Ord,
 {
@@ -772,7 +1840,7 @@ where
   }
 }
 
-impl<A: Array> PartialEq<&A> for TinyVec<A>
+impl<A: Array, P: SpillPolicy> PartialEq<&A> for TinyVec<A, P>
 where
   A::Item: PartialEq,
 {
@@ -783,7 +1851,7 @@ where
   }
 }
 
-impl<A: Array> PartialEq<&[A::Item]> for TinyVec<A>
+impl<A: Array, P: SpillPolicy> PartialEq<&[A::Item]> for TinyVec<A, P>
 where
   A::Item: PartialEq,
 {
@@ -801,7 +1869,7 @@ I think, in retrospect, this is useless?
 The `&mut [A::Item]` should coerce to `&[A::Item]` and use the above impl.
 I'll leave it here for now though since we already had it written out..
 
-impl<A: Array> PartialEq<&mut [A::Item]> for TinyVec<A>
+impl<A: Array, P: SpillPolicy> PartialEq<&mut [A::Item]> for TinyVec<A, P>
 where
   A::Item: PartialEq,
 {
@@ -817,7 +1885,7 @@ where
 // Formatting impls
 // //
 
-impl<A: Array> Binary for TinyVec<A>
+impl<A: Array, P: SpillPolicy> Binary for TinyVec<A, P>
 where
   A::Item: Binary,
 {
@@ -834,7 +1902,7 @@ where
   }
 }
 
-impl<A: Array> Debug for TinyVec<A>
+impl<A: Array, P: SpillPolicy> Debug for TinyVec<A, P>
 where
   A::Item: Debug,
 {
@@ -851,7 +1919,7 @@ where
   }
 }
 
-impl<A: Array> Display for TinyVec<A>
+impl<A: Array, P: SpillPolicy> Display for TinyVec<A, P>
 where
   A::Item: Display,
 {
@@ -868,7 +1936,7 @@ where
   }
 }
 
-impl<A: Array> LowerExp for TinyVec<A>
+impl<A: Array, P: SpillPolicy> LowerExp for TinyVec<A, P>
 where
   A::Item: LowerExp,
 {
@@ -885,7 +1953,7 @@ where
   }
 }
 
-impl<A: Array> LowerHex for TinyVec<A>
+impl<A: Array, P: SpillPolicy> LowerHex for TinyVec<A, P>
 where
   A::Item: LowerHex,
 {
@@ -902,7 +1970,7 @@ where
   }
 }
 
-impl<A: Array> Octal for TinyVec<A>
+impl<A: Array, P: SpillPolicy> Octal for TinyVec<A, P>
 where
   A::Item: Octal,
 {
@@ -919,7 +1987,7 @@ where
   }
 }
 
-impl<A: Array> Pointer for TinyVec<A>
+impl<A: Array, P: SpillPolicy> Pointer for TinyVec<A, P>
 where
   A::Item: Pointer,
 {
@@ -936,7 +2004,7 @@ where
   }
 }
 
-impl<A: Array> UpperExp for TinyVec<A>
+impl<A: Array, P: SpillPolicy> UpperExp for TinyVec<A, P>
 where
   A::Item: UpperExp,
 {
@@ -953,7 +2021,7 @@ where
   }
 }
 
-impl<A: Array> UpperHex for TinyVec<A>
+impl<A: Array, P: SpillPolicy> UpperHex for TinyVec<A, P>
 where
   A::Item: UpperHex,
 {
@@ -969,3 +2037,161 @@ where
     write!(f, "]")
   }
 }
+
+#[cfg(feature = "serde")]
+impl<A: Array, P: SpillPolicy> Serialize for TinyVec<A, P>
+where
+  A::Item: Serialize,
+{
+  /// Serializes as a sequence of the elements.
+  #[inline]
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut seq = serializer.serialize_seq(Some(self.len()))?;
+    for element in self.iter() {
+      seq.serialize_element(element)?;
+    }
+    seq.end()
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, A: Array, P: SpillPolicy> Deserialize<'de> for TinyVec<A, P>
+where
+  A::Item: Deserialize<'de>,
+{
+  /// Deserializes from a sequence of elements.
+  ///
+  /// This goes straight to the heap if the sequence says up front that it's
+  /// too long to fit inline.
+  #[inline]
+  fn deserialize<D: Deserializer<'de>>(
+    deserializer: D,
+  ) -> Result<Self, D::Error> {
+    deserializer.deserialize_seq(TinyVecVisitor(PhantomData))
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<A: Array<Item = u8>, P: SpillPolicy> TinyVec<A, P> {
+  /// Serializes the vec as a byte string, for use with
+  /// `#[serde(serialize_with = "TinyVec::serialize_bytes")]`.
+  ///
+  /// * Requires the `serde` feature
+  ///
+  /// Binary formats can store bytes much more compactly than a sequence of
+  /// `u8`.
+  #[inline]
+  pub fn serialize_bytes<S: Serializer>(
+    &self,
+    serializer: S,
+  ) -> Result<S::Ok, S::Error> {
+    serializer.serialize_bytes(self.as_slice())
+  }
+
+  /// Deserializes the vec from a byte string (or a sequence of `u8`), for use
+  /// with `#[serde(deserialize_with = "TinyVec::deserialize_bytes")]`.
+  ///
+  /// * Requires the `serde` feature
+  #[inline]
+  pub fn deserialize_bytes<'de, D: Deserializer<'de>>(
+    deserializer: D,
+  ) -> Result<Self, D::Error> {
+    deserializer.deserialize_bytes(TinyVecBytesVisitor(PhantomData))
+  }
+}
+
+#[cfg(feature = "serde")]
+struct TinyVecVisitor<A, P>(PhantomData<(A, P)>);
+
+#[cfg(feature = "serde")]
+impl<'de, A: Array, P: SpillPolicy> Visitor<'de> for TinyVecVisitor<A, P>
+where
+  A::Item: Deserialize<'de>,
+{
+  type Value = TinyVec<A, P>;
+
+  fn expecting(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "a sequence")
+  }
+
+  fn visit_seq<S: SeqAccess<'de>>(
+    self,
+    mut seq: S,
+  ) -> Result<Self::Value, S::Error> {
+    // don't trust the size hint with a huge allocation up front
+    let cap = seq.size_hint().unwrap_or(0).min(4096);
+    let mut out = TinyVec::with_capacity(cap);
+    while let Some(element) = seq.next_element()? {
+      out.push(element);
+    }
+    Ok(out)
+  }
+}
+
+#[cfg(feature = "serde")]
+struct TinyVecBytesVisitor<A, P>(PhantomData<(A, P)>);
+
+#[cfg(feature = "serde")]
+impl<'de, A: Array<Item = u8>, P: SpillPolicy> Visitor<'de>
+  for TinyVecBytesVisitor<A, P>
+{
+  type Value = TinyVec<A, P>;
+
+  fn expecting(&self, f: &mut Formatter) -> core::fmt::Result {
+    write!(f, "bytes")
+  }
+
+  fn visit_bytes<E: serde::de::Error>(
+    self,
+    v: &[u8],
+  ) -> Result<Self::Value, E> {
+    if v.len() <= A::CAPACITY {
+      let mut out = ArrayVec::default();
+      out.extend_from_slice(v);
+      Ok(TinyVec::Inline(out))
+    } else {
+      Ok(TinyVec::Heap(v.to_vec()))
+    }
+  }
+
+  fn visit_seq<S: SeqAccess<'de>>(
+    self,
+    seq: S,
+  ) -> Result<Self::Value, S::Error> {
+    TinyVecVisitor(PhantomData).visit_seq(seq)
+  }
+}
+
+#[cfg(feature = "zeroize")]
+impl<A: Array, P: SpillPolicy> Zeroize for TinyVec<A, P>
+where
+  A::Item: Zeroize,
+{
+  /// Zeroizes all of the elements and all of the spare capacity, inline or
+  /// on the heap, and then sets the length to 0.
+  #[inline]
+  fn zeroize(&mut self) {
+    match self {
+      TinyVec::Inline(a) => a.zeroize(),
+      TinyVec::Heap(v) => {
+        // fill up the spare capacity so that every slot gets zeroized
+        let cap = v.capacity();
+        v.resize_with(cap, A::Item::default);
+        for x in v.iter_mut() {
+          x.zeroize();
+        }
+        v.clear();
+      }
+    }
+  }
+}
+
+/// A `TinyVec` that zeroizes all of its memory when it's dropped.
+///
+/// * Requires the `zeroize` feature
+///
+/// Memory that the vec has already let go of (an old heap buffer from before a
+/// reallocation) can't be reached any more, so it doesn't get zeroized. Reserve
+/// enough capacity up front if that matters.
+#[cfg(feature = "zeroize")]
+pub type ZeroizingTinyVec<A, P = Sticky> = Zeroizing<TinyVec<A, P>>;
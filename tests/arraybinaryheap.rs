@@ -0,0 +1,75 @@
+#![allow(bad_style)]
+
+use core::cmp::Reverse;
+use tinyvec::*;
+
+#[test]
+fn ArrayBinaryHeap_push_pop_peek() {
+  let mut heap: ArrayBinaryHeap<[i32; 8]> = ArrayBinaryHeap::new();
+  assert!(heap.is_empty());
+  assert_eq!(heap.peek(), None);
+  assert_eq!(heap.pop(), None);
+  for x in &[5, 1, 8, 3, 9, 2, 8] {
+    heap.push(*x);
+  }
+  assert_eq!(heap.len(), 7);
+  assert_eq!(heap.peek(), Some(&9));
+  let mut out = Vec::new();
+  while let Some(x) = heap.pop() {
+    out.push(x);
+  }
+  assert_eq!(out, [9, 8, 8, 5, 3, 2, 1]);
+}
+
+#[test]
+fn ArrayBinaryHeap_try_push_when_full() {
+  let mut heap: ArrayBinaryHeap<[u8; 2]> = ArrayBinaryHeap::new();
+  assert_eq!(heap.try_push(1), Ok(()));
+  assert_eq!(heap.try_push(2), Ok(()));
+  assert_eq!(heap.try_push(3), Err(3));
+  assert_eq!(heap.len(), 2);
+  assert_eq!(heap.peek(), Some(&2));
+}
+
+#[test]
+#[should_panic]
+fn ArrayBinaryHeap_push_past_capacity() {
+  let mut heap: ArrayBinaryHeap<[u8; 1]> = ArrayBinaryHeap::new();
+  heap.push(1);
+  heap.push(2);
+}
+
+#[test]
+fn ArrayBinaryHeap_peek_mut_resifts() {
+  let mut heap: ArrayBinaryHeap<[i32; 8]> =
+    [4, 1, 8, 2, 6].iter().copied().collect();
+  *heap.peek_mut().unwrap() = 0;
+  assert_eq!(heap.peek(), Some(&6));
+  {
+    let mut top = heap.peek_mut().unwrap();
+    *top += 10;
+  }
+  assert_eq!(heap.peek(), Some(&16));
+  let top = heap.peek_mut().unwrap();
+  assert_eq!(ArrayBinaryHeapPeekMut::pop(top), 16);
+  assert_eq!(heap.into_sorted_vec().as_slice(), &[0, 1, 2, 4]);
+}
+
+#[test]
+fn ArrayBinaryHeap_from_vec_and_min_heap() {
+  let heap = ArrayBinaryHeap::from(array_vec!([u32; 8], 3, 9, 1, 7, 5, 2));
+  assert_eq!(heap.peek(), Some(&9));
+  assert_eq!(heap.into_sorted_vec().as_slice(), &[1, 2, 3, 5, 7, 9]);
+  assert_eq!(heap.into_vec().len(), 6);
+
+  let mut timers: ArrayBinaryHeap<[Reverse<u64>; 4]> =
+    [30, 10, 20].iter().map(|t| Reverse(*t)).collect();
+  assert_eq!(timers.pop(), Some(Reverse(10)));
+  assert_eq!(timers.peek(), Some(&Reverse(20)));
+
+  let mut heap: ArrayBinaryHeap<[u8; 4]> = [1, 2, 3].iter().copied().collect();
+  let mut drained: Vec<u8> = heap.drain().collect();
+  drained.sort_unstable();
+  assert_eq!(drained, [1, 2, 3]);
+  assert!(heap.is_empty());
+}
@@ -0,0 +1,147 @@
+#![allow(bad_style)]
+
+use tinyvec::*;
+use std::iter::FromIterator;
+
+#[test]
+fn ArrayDeque_push_pop() {
+  let mut q: ArrayDeque<[i32; 4]> = Default::default();
+  assert_eq!(q.len(), 0);
+  assert_eq!(q.pop_front(), None);
+  assert_eq!(q.pop_back(), None);
+
+  q.push_back(2);
+  q.push_back(3);
+  q.push_front(1);
+  q.push_front(0);
+  assert_eq!(q.len(), 4);
+  assert!(q.is_full());
+  assert_eq!(q.try_push_back(4), Err(4));
+  assert_eq!(q.try_push_front(-1), Err(-1));
+  assert_eq!(q.front(), Some(&0));
+  assert_eq!(q.back(), Some(&3));
+  assert_eq!(q[2], 2);
+
+  assert_eq!(q.pop_front(), Some(0));
+  assert_eq!(q.pop_back(), Some(3));
+  assert_eq!(q.pop_front(), Some(1));
+  assert_eq!(q.pop_front(), Some(2));
+  assert_eq!(q.pop_front(), None);
+}
+
+#[test]
+#[should_panic]
+fn ArrayDeque_push_overflow() {
+  let mut q: ArrayDeque<[i32; 0]> = Default::default();
+  q.push_back(7);
+}
+
+#[test]
+fn ArrayDeque_wrapping() {
+  let mut q: ArrayDeque<[i32; 4]> = Default::default();
+  for i in 0..100 {
+    q.push_back(i);
+    if q.len() == 3 {
+      assert_eq!(q.pop_front(), Some(i - 2));
+    }
+  }
+  assert_eq!(Vec::from_iter(q.iter().copied()), vec![98, 99]);
+
+  q.push_back(100);
+  q.push_back(101);
+  let (a, b) = q.as_slices();
+  assert_eq!(a.len() + b.len(), 4);
+  assert_eq!(q.make_contiguous(), &[98, 99, 100, 101]);
+  assert_eq!(q.as_slices(), (&[98, 99, 100, 101][..], &[][..]));
+}
+
+#[test]
+fn ArrayDeque_rotate() {
+  let base: ArrayDeque<[i32; 6]> = (0..5).collect();
+  for n in 0..=5 {
+    let mut expected = Vec::from_iter(0..5);
+    expected.rotate_left(n);
+    let mut q = base;
+    q.rotate_left(n);
+    assert_eq!(Vec::from_iter(q.iter().copied()), expected);
+
+    let mut expected = Vec::from_iter(0..5);
+    expected.rotate_right(n);
+    let mut q = base;
+    q.rotate_right(n);
+    assert_eq!(Vec::from_iter(q.iter().copied()), expected);
+  }
+
+  let mut q: ArrayDeque<[i32; 4]> = [1, 2, 3, 4].into();
+  q.rotate_left(1);
+  assert_eq!(Vec::from_iter(q), vec![2, 3, 4, 1]);
+}
+
+#[test]
+fn ArrayDeque_drain() {
+  let mut q: ArrayDeque<[i32; 8]> = Default::default();
+  // force the contents to wrap around the backing array
+  for i in 0..6 {
+    q.push_back(i);
+  }
+  for _ in 0..4 {
+    q.pop_front();
+  }
+  for i in 6..12 {
+    q.push_back(i);
+  }
+  let all = Vec::from_iter(q.iter().copied());
+  assert_eq!(all, vec![4, 5, 6, 7, 8, 9, 10, 11]);
+
+  for start in 0..=all.len() {
+    for end in start..=all.len() {
+      let mut q2 = q;
+      let drained = Vec::from_iter(q2.drain(start..end));
+      assert_eq!(&drained[..], &all[start..end]);
+      let mut rest = all.clone();
+      rest.drain(start..end);
+      assert_eq!(Vec::from_iter(q2.iter().copied()), rest);
+
+      // dropping a partially consumed drain still removes the range
+      let mut q3 = q;
+      q3.drain(start..end).next_back();
+      assert_eq!(Vec::from_iter(q3.iter().copied()), rest);
+    }
+  }
+}
+
+#[test]
+fn ArrayDeque_iteration() {
+  let mut q: ArrayDeque<[i32; 4]> = Default::default();
+  q.push_back(2);
+  q.push_back(3);
+  q.push_front(1);
+
+  assert_eq!(Vec::from_iter(q.iter().rev().copied()), vec![3, 2, 1]);
+  for x in q.iter_mut() {
+    *x *= 10;
+  }
+  assert_eq!(Vec::from_iter(&q), vec![&10, &20, &30]);
+  assert_eq!(q.iter().len(), 3);
+
+  let mut i = q.into_iter();
+  assert_eq!(i.next_back(), Some(30));
+  assert_eq!(i.next(), Some(10));
+  assert_eq!(i.next(), Some(20));
+  assert_eq!(i.next(), None);
+}
+
+#[test]
+fn ArrayDeque_eq_and_formatting() {
+  let mut a: ArrayDeque<[i32; 4]> = Default::default();
+  a.push_back(2);
+  a.push_front(1);
+  let b: ArrayDeque<[i32; 4]> = array_vec!([i32; 4], 1, 2).into();
+  assert_eq!(a, b);
+  assert_eq!(a, &[1, 2][..]);
+  assert_eq!(format!("{:?}", a), "[1, 2]");
+  assert_eq!(format!("{:x}", a), "[1, 2]");
+
+  a.extend(vec![3]);
+  assert!(a > b);
+}
@@ -0,0 +1,135 @@
+#![allow(bad_style)]
+
+use core::hash::{BuildHasherDefault, Hasher};
+use tinyvec::*;
+
+/// Sends every key to slot 0, so every lookup has to probe.
+#[derive(Default)]
+struct CollidingHasher;
+impl Hasher for CollidingHasher {
+  fn finish(&self) -> u64 {
+    0
+  }
+  fn write(&mut self, _: &[u8]) {}
+}
+type CollidingMap4 =
+  ArrayHashMap<[HashSlot<u32, u32>; 4], BuildHasherDefault<CollidingHasher>>;
+
+#[test]
+fn ArrayHashMap_insert_get_remove() {
+  let mut m: ArrayHashMap<[HashSlot<u32, &str>; 8]> = ArrayHashMap::new();
+  assert!(m.is_empty());
+  assert_eq!(m.insert(5, "five"), None);
+  assert_eq!(m.insert(1, "one"), None);
+  assert_eq!(m.insert(3, "three"), None);
+  assert_eq!(m.insert(3, "THREE"), Some("three"));
+  assert_eq!(m.len(), 3);
+  assert_eq!(m.get(&3), Some(&"THREE"));
+  assert_eq!(m.get(&4), None);
+  assert!(m.contains_key(&1));
+  *m.get_mut(&1).unwrap() = "uno";
+  assert_eq!(m.get_key_value(&1), Some((&1, &"uno")));
+  assert_eq!(m.remove(&3), Some("THREE"));
+  assert_eq!(m.remove(&3), None);
+  assert_eq!(m.remove_entry(&5), Some((5, "five")));
+  assert_eq!(m.len(), 1);
+  m.clear();
+  assert!(m.is_empty());
+  assert_eq!(m.get(&1), None);
+}
+
+#[test]
+fn ArrayHashMap_probing_past_tombstones() {
+  let mut m = CollidingMap4::default();
+  m.extend(vec![(1, 10), (2, 20), (3, 30)]);
+  // 2 sits between 1 and 3 on the probe path, so its tombstone must not
+  // stop the search for 3.
+  assert_eq!(m.remove(&2), Some(20));
+  assert_eq!(m.get(&3), Some(&30));
+  // The tombstone gets reused, and the key isn't duplicated.
+  assert_eq!(m.insert(3, 31), Some(30));
+  assert_eq!(m.insert(4, 40), None);
+  assert_eq!(m.insert(5, 50), None);
+  assert_eq!(m.len(), 4);
+  for k in 1..=5 {
+    m.remove(&k);
+  }
+  assert!(m.is_empty());
+  // Churn never runs the table out of empty slots for good.
+  for round in 0..10 {
+    assert_eq!(m.insert(round, round), None);
+    assert_eq!(m.get(&round), Some(&round));
+    assert_eq!(m.remove(&round), Some(round));
+  }
+}
+
+#[test]
+fn ArrayHashMap_try_insert_when_full() {
+  let mut m: ArrayHashMap<[HashSlot<u8, u8>; 2]> =
+    vec![(2, 20), (1, 10)].into_iter().collect();
+  assert_eq!(m.try_insert(1, 11), Ok(Some(10)));
+  let err = m.try_insert(3, 30).unwrap_err();
+  assert_eq!(err.element(), (3, 30));
+  assert_eq!(m.len(), 2);
+  match m.entry(9) {
+    ArrayHashMapEntry::Vacant(v) => {
+      assert_eq!(v.try_insert(90).unwrap_err().element(), (9, 90))
+    }
+    ArrayHashMapEntry::Occupied(_) => panic!("9 isn't in the map"),
+  }
+  assert_eq!(m.get(&1), Some(&11));
+  assert_eq!(m.get(&9), None);
+}
+
+#[test]
+#[should_panic]
+fn ArrayHashMap_insert_past_capacity() {
+  let mut m: ArrayHashMap<[HashSlot<u8, u8>; 1]> = ArrayHashMap::new();
+  m.insert(1, 1);
+  m.insert(2, 2);
+}
+
+#[test]
+fn ArrayHashMap_entry() {
+  let mut m = CollidingMap4::default();
+  *m.entry(1).or_insert(0) += 5;
+  *m.entry(1).or_insert(0) += 5;
+  m.entry(2).and_modify(|v| *v = 99).or_default();
+  assert_eq!(m.get(&1), Some(&10));
+  assert_eq!(m.get(&2), Some(&0));
+  match m.entry(1) {
+    ArrayHashMapEntry::Occupied(mut o) => {
+      assert_eq!(o.key(), &1);
+      assert_eq!(o.insert(11), 10);
+      assert_eq!(o.remove_entry(), (1, 11));
+    }
+    ArrayHashMapEntry::Vacant(_) => panic!("1 is in the map"),
+  }
+  assert_eq!(m.entry(2).key(), &2);
+  assert_eq!(m.len(), 1);
+}
+
+#[test]
+fn ArrayHashMap_iter_retain_eq() {
+  let mut m: ArrayHashMap<[HashSlot<u32, u32>; 16]> =
+    (0..10).map(|i| (i, i * 10)).collect();
+  let mut keys: Vec<u32> = m.keys().copied().collect();
+  keys.sort_unstable();
+  assert_eq!(keys, (0..10).collect::<Vec<_>>());
+  assert_eq!(m.iter().len(), 10);
+  assert_eq!(m.values().sum::<u32>(), 450);
+  for v in m.values_mut() {
+    *v += 1;
+  }
+  m.retain(|k, _| k % 2 == 0);
+  assert_eq!(m.len(), 5);
+  assert_eq!(m.get(&4), Some(&41));
+  assert_eq!(m.get(&5), None);
+
+  let other: ArrayHashMap<[HashSlot<u32, u32>; 16]> =
+    vec![(8, 81), (6, 61), (4, 41), (2, 21), (0, 1)].into_iter().collect();
+  assert_eq!(m, other);
+  let mut pairs: Vec<(u32, u32)> = m.into_iter().collect();
+  pairs.sort_unstable();
+  assert_eq!(pairs, vec![(0, 1), (2, 21), (4, 41), (6, 61), (8, 81)]);
+}
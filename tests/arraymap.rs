@@ -0,0 +1,116 @@
+#![allow(bad_style)]
+
+use tinyvec::*;
+
+#[test]
+fn ArrayMap_insert_get_remove() {
+  let mut m: ArrayMap<[(u32, &str); 4]> = ArrayMap::new();
+  assert!(m.is_empty());
+  assert_eq!(m.insert(5, "five"), None);
+  assert_eq!(m.insert(1, "one"), None);
+  assert_eq!(m.insert(3, "three"), None);
+  assert_eq!(m.insert(3, "THREE"), Some("three"));
+  assert_eq!(m.len(), 3);
+  assert_eq!(m.as_slice(), &[(1, "one"), (3, "THREE"), (5, "five")]);
+  assert_eq!(m.get(&3), Some(&"THREE"));
+  assert_eq!(m.get(&5), Some(&"five"));
+  assert_eq!(m.get(&4), None);
+  assert!(m.contains_key(&1));
+  *m.get_mut(&1).unwrap() = "uno";
+  assert_eq!(m.get_key_value(&1), Some((&1, &"uno")));
+  assert_eq!(m.remove(&3), Some("THREE"));
+  assert_eq!(m.remove(&3), None);
+  assert_eq!(m.first_key_value(), Some((&1, &"uno")));
+  assert_eq!(m.last_key_value(), Some((&5, &"five")));
+  assert_eq!(m.pop_last(), Some((5, "five")));
+  assert_eq!(m.pop_first(), Some((1, "uno")));
+  assert_eq!(m.pop_first(), None);
+}
+
+#[test]
+fn ArrayMap_try_insert_when_full() {
+  let mut m: ArrayMap<[(u8, u8); 2]> =
+    [(2, 20), (1, 10)].iter().copied().collect();
+  assert_eq!(m.try_insert(1, 11), Ok(Some(10)));
+  let err = m.try_insert(3, 30).unwrap_err();
+  assert_eq!(err.element(), (3, 30));
+  assert_eq!(m.as_slice(), &[(1, 11), (2, 20)]);
+  match m.entry(9) {
+    ArrayMapEntry::Vacant(v) => {
+      assert_eq!(v.try_insert(90).unwrap_err().element(), (9, 90))
+    }
+    ArrayMapEntry::Occupied(_) => panic!("9 isn't in the map"),
+  }
+}
+
+#[test]
+#[should_panic]
+fn ArrayMap_insert_past_capacity() {
+  let mut m: ArrayMap<[(u8, u8); 1]> = ArrayMap::new();
+  m.insert(1, 1);
+  m.insert(2, 2);
+}
+
+#[test]
+fn ArrayMap_entry() {
+  let mut m: ArrayMap<[(&str, u32); 4]> = ArrayMap::new();
+  for word in "a b a c a b".split(' ') {
+    m.entry(word).and_modify(|n| *n += 1).or_insert(1);
+  }
+  assert_eq!(m.as_slice(), &[("a", 3), ("b", 2), ("c", 1)]);
+  match m.entry("b") {
+    ArrayMapEntry::Occupied(mut o) => {
+      assert_eq!(o.key(), &"b");
+      assert_eq!(o.insert(7), 2);
+      assert_eq!(o.remove_entry(), ("b", 7));
+    }
+    ArrayMapEntry::Vacant(_) => panic!("b is in the map"),
+  }
+  assert_eq!(*m.entry("z").or_default(), 0);
+  assert_eq!(m.keys().copied().collect::<Vec<_>>(), ["a", "c", "z"]);
+}
+
+#[test]
+fn ArrayMap_range() {
+  use core::ops::Bound::*;
+  let mut m: ArrayMap<[(i32, i32); 8]> = (0..8).map(|k| (k * 10, k)).collect();
+  let keys = |it: ArrayMapIter<'_, i32, i32>| -> Vec<i32> {
+    it.map(|(k, _)| *k).collect()
+  };
+  assert_eq!(keys(m.range(15..45)), [20, 30, 40]);
+  assert_eq!(keys(m.range(20..=40)), [20, 30, 40]);
+  assert_eq!(keys(m.range(..10)), [0]);
+  assert_eq!(keys(m.range(65..)), [70]);
+  assert_eq!(keys(m.range((Excluded(20), Excluded(40)))), [30]);
+  assert_eq!(keys(m.range(100..)), Vec::<i32>::new());
+  assert_eq!(m.range(..).rev().count(), 8);
+  for (_, v) in m.range_mut(50..) {
+    *v = -1;
+  }
+  assert_eq!(m.values().filter(|v| **v < 0).count(), 3);
+}
+
+#[test]
+#[should_panic]
+fn ArrayMap_range_backwards() {
+  let m: ArrayMap<[(i32, i32); 2]> = ArrayMap::new();
+  let (start, end) = (5, 1);
+  let _ = m.range(start..end);
+}
+
+#[test]
+fn ArrayMap_iter_retain_fmt() {
+  let mut m: ArrayMap<[(u8, char); 6]> =
+    vec![(3, 'c'), (1, 'a'), (2, 'b'), (4, 'd')].into_iter().collect();
+  for (k, v) in &mut m {
+    if *k % 2 == 0 {
+      *v = v.to_ascii_uppercase();
+    }
+  }
+  m.retain(|k, _| *k != 3);
+  assert_eq!(format!("{:?}", m), "{1: 'a', 2: 'B', 4: 'D'}");
+  let owned: Vec<(u8, char)> = m.into_iter().collect();
+  assert_eq!(owned, [(1, 'a'), (2, 'B'), (4, 'D')]);
+  let other: ArrayMap<[(u8, char); 6]> = owned.iter().rev().copied().collect();
+  assert_eq!(other.as_slice(), &owned[..]);
+}
@@ -0,0 +1,99 @@
+#![allow(bad_style)]
+
+use tinyvec::*;
+
+#[test]
+fn ArraySet_insert_contains_remove() {
+  let mut s: ArraySet<[u32; 4]> = ArraySet::new();
+  assert!(s.insert(3));
+  assert!(s.insert(1));
+  assert!(!s.insert(3));
+  assert_eq!(s.try_insert(2), Ok(true));
+  assert_eq!(s.as_slice(), &[1, 2, 3]);
+  assert!(s.contains(&2));
+  assert!(!s.contains(&4));
+  assert_eq!(s.get(&3), Some(&3));
+  assert_eq!(s.first(), Some(&1));
+  assert_eq!(s.last(), Some(&3));
+  assert!(s.remove(&2));
+  assert!(!s.remove(&2));
+  assert_eq!(s.take(&1), Some(1));
+  assert_eq!(s.pop_last(), Some(3));
+  assert_eq!(s.pop_first(), None);
+  assert!(s.is_empty());
+}
+
+#[test]
+fn ArraySet_try_insert_when_full() {
+  let mut s: ArraySet<[u8; 2]> = [2, 1].iter().copied().collect();
+  assert_eq!(s.try_insert(1), Ok(false));
+  assert_eq!(s.try_insert(3).unwrap_err().element(), 3);
+  assert_eq!(s.as_slice(), &[1, 2]);
+}
+
+#[test]
+#[should_panic]
+fn ArraySet_insert_past_capacity() {
+  let mut s: ArraySet<[u8; 1]> = ArraySet::new();
+  s.insert(1);
+  s.insert(2);
+}
+
+#[test]
+fn ArraySet_from_array_vec() {
+  let s = ArraySet::from(array_vec!([i32; 8], 4, -1, 4, 7, -1, 0));
+  assert_eq!(s.as_slice(), &[-1, 0, 4, 7]);
+  s.range(0..).for_each(|x| assert!(*x >= 0));
+  assert!(s.range(..=0).eq([-1, 0].iter()));
+  assert_eq!(format!("{:?}", s), "{-1, 0, 4, 7}");
+}
+
+#[test]
+fn ArraySet_from_array_vec_drops_duplicates() {
+  use std::rc::Rc;
+  let rc = Rc::new(5);
+  let mut av: ArrayVec<[Rc<i32>; 4]> = ArrayVec::default();
+  av.extend((0..3).map(|_| rc.clone()));
+  let s = ArraySet::from(av);
+  assert_eq!(s.len(), 1);
+  assert_eq!(Rc::strong_count(&rc), 2);
+}
+
+#[test]
+fn ArraySet_algebra() {
+  let a: ArraySet<[u8; 6]> = [1, 2, 3, 5].iter().copied().collect();
+  let b: ArraySet<[u8; 6]> = [2, 4, 5, 6].iter().copied().collect();
+  let small: ArraySet<[u8; 2]> = [2, 5].iter().copied().collect();
+
+  assert!(a.union(&b).eq([1, 2, 3, 4, 5, 6].iter()));
+  assert!(a.intersection(&b).eq([2, 5].iter()));
+  assert!(a.difference(&b).eq([1, 3].iter()));
+  assert!(b.difference(&a).eq([4, 6].iter()));
+  assert!(a.symmetric_difference(&b).eq([1, 3, 4, 6].iter()));
+  assert!(a.intersection(&small).eq(small.iter()));
+
+  assert!(small.is_subset(&a));
+  assert!(a.is_superset(&small));
+  assert!(!a.is_subset(&b));
+  assert!(!a.is_disjoint(&b));
+  assert!(a.difference(&b).next().is_some());
+
+  assert_eq!((&a & &b).as_slice(), &[2, 5]);
+  assert_eq!((&a - &b).as_slice(), &[1, 3]);
+  assert_eq!((&a | &b).as_slice(), &[1, 2, 3, 4, 5, 6]);
+  assert_eq!((&a ^ &b).as_slice(), &[1, 3, 4, 6]);
+  assert_eq!(a.try_union(&b).unwrap(), &a | &b);
+
+  let full: ArraySet<[u8; 6]> = (10..16).collect();
+  assert!(a.try_union(&full).is_err());
+  assert!(full.try_symmetric_difference(&a).is_err());
+  assert_eq!(full.try_symmetric_difference(&full).unwrap().len(), 0);
+}
+
+#[test]
+#[should_panic]
+fn ArraySet_bitor_past_capacity() {
+  let a: ArraySet<[u8; 2]> = [1, 2].iter().copied().collect();
+  let b: ArraySet<[u8; 2]> = [3].iter().copied().collect();
+  let _ = &a | &b;
+}
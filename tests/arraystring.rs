@@ -0,0 +1,82 @@
+#![allow(bad_style)]
+
+use tinyvec::*;
+use std::{collections::HashSet, fmt::Write};
+
+#[test]
+fn ArrayString_push_pop() {
+  let mut s: ArrayString<[u8; 8]> = Default::default();
+  assert_eq!(s.pop(), None);
+  s.push('a');
+  s.push('é');
+  s.push_str("z!");
+  assert_eq!(s, "aéz!");
+  assert_eq!(s.len(), 5);
+  assert_eq!(s.try_push('😀'), Err('😀'));
+  assert_eq!(s.try_push_str("xyzw"), Err("xyzw"));
+  assert_eq!(s, "aéz!");
+  assert_eq!(s.pop(), Some('!'));
+  assert_eq!(s.pop(), Some('z'));
+  assert_eq!(s.pop(), Some('é'));
+  assert_eq!(s, "a");
+}
+
+#[test]
+#[should_panic]
+fn ArrayString_push_overflow() {
+  let mut s: ArrayString<[u8; 1]> = Default::default();
+  s.push('é');
+}
+
+#[test]
+#[should_panic]
+fn ArrayString_truncate_mid_char() {
+  let mut s: ArrayString<[u8; 8]> = "aé".parse().unwrap();
+  s.truncate(2);
+}
+
+#[test]
+fn ArrayString_insert_remove() {
+  let mut s: ArrayString<[u8; 16]> = "hllo".parse().unwrap();
+  s.insert(1, 'e');
+  s.insert_str(0, "¡");
+  assert_eq!(s, "¡hello");
+  assert_eq!(s.remove(0), '¡');
+  assert_eq!(s.remove(4), 'o');
+  assert_eq!(s, "hell");
+  s.truncate(2);
+  assert_eq!(s, "he");
+}
+
+#[test]
+fn ArrayString_fmt() {
+  let mut s: ArrayString<[u8; 8]> = Default::default();
+  write!(s, "{}+{}", 1, 2).unwrap();
+  assert_eq!(s, "1+2");
+  assert!(write!(s, "{}", 123456).is_err());
+  assert_eq!(format!("{}", s), "1+2");
+  assert_eq!(format!("{:?}", s), "\"1+2\"");
+
+  let full = array_format!([u8; 8], "{}", "abc").unwrap();
+  assert_eq!(full, "abc");
+  let cut = array_format!([u8; 4], "x{}", "ééé").unwrap_err();
+  assert_eq!(cut, "xé");
+
+  assert!("too long for this".parse::<ArrayString<[u8; 4]>>().is_err());
+}
+
+#[test]
+fn ArrayString_hash_ord_borrow() {
+  let a: ArrayString<[u8; 8]> = "apple".parse().unwrap();
+  let b: ArrayString<[u8; 8]> = "banana".parse().unwrap();
+  assert!(a < b);
+  let mut set = HashSet::new();
+  set.insert(a);
+  set.insert(b);
+  assert!(set.contains("apple"));
+  assert!(!set.contains("cherry"));
+
+  assert!(ArrayString::from_utf8(array_vec!([u8; 4], 0xFF)).is_err());
+  let ok = ArrayString::from_utf8(array_vec!([u8; 4], b'o', b'k')).unwrap();
+  assert_eq!(ok.into_bytes(), array_vec!([u8; 4], b'o', b'k'));
+}
@@ -3,6 +3,11 @@
 use tinyvec::*;
 use std::iter::FromIterator;
 
+#[cfg(feature = "serde")]
+use serde_test::{
+  assert_de_tokens, assert_de_tokens_error, assert_tokens, Token,
+};
+
 #[test]
 fn test_a_vec() {
   let mut expected: ArrayVec<[i32; 4]> = Default::default();
@@ -48,6 +53,13 @@ fn ArrayVec_push_pop() {
   assert_eq!(av.pop(), None);
 }
 
+#[test]
+#[should_panic]
+fn ArrayVec_try_insert_past_len() {
+  let mut av = array_vec!([i32; 4], 1, 2);
+  let _ = av.try_insert(3, 9);
+}
+
 #[test]
 #[should_panic]
 fn ArrayVec_push_overflow() {
@@ -168,3 +180,402 @@ fn ArrayVec_drain() {
   assert_eq!(Vec::from_iter(av.clone().drain(1..=1)), vec![2]);
   assert_eq!(Vec::from_iter(av.clone().drain(1..=2)), vec![2, 3]);
 }
+
+#[test]
+fn ArrayVec_big_arrays() {
+  let mut av: ArrayVec<[u8; 4096]> = ArrayVec::new();
+  av.push(1);
+  assert_eq!(av.capacity(), 4096);
+
+  let av = array_vec!([String; 64], String::from("hello"));
+  assert_eq!(av.as_slice(), &["hello"][..]);
+}
+
+#[cfg(feature = "const_generics")]
+#[test]
+fn ArrayVec_any_length() {
+  let mut av: ArrayVec<[i32; 48]> = ArrayVec::new();
+  av.extend(0..48);
+  assert_eq!(av.len(), 48);
+  assert_eq!(av.try_push(48), Err(48));
+}
+
+#[test]
+fn ArrayVec_try_growing() {
+  let mut av = array_vec!([i32; 4], 1, 2);
+  av.try_insert(1, 5).unwrap();
+  let err = av.try_extend_from_slice(&[7, 8]).unwrap_err();
+  assert_eq!(err.element(), &[7, 8]);
+  assert_eq!(av.as_slice(), &[1, 5, 2][..]);
+  av.try_extend_from_slice(&[7]).unwrap();
+  assert_eq!(av.try_insert(0, 9).unwrap_err().element(), 9);
+  assert_eq!(av.try_resize(5, 0).unwrap_err().element(), 0);
+  assert!(av.try_resize_with(6, || panic!()).is_err());
+  av.try_resize(2, 0).unwrap();
+  assert_eq!(av.as_slice(), &[1, 5][..]);
+
+  let rest = av.try_extend(10..20).unwrap_err().element();
+  assert_eq!(av.as_slice(), &[1, 5, 10, 11][..]);
+  assert_eq!(Vec::from_iter(rest), (12..20).collect::<Vec<_>>());
+
+  assert!(ArrayVec::<[i32; 2]>::try_from_iter(0..3).is_err());
+  let av2 = ArrayVec::<[i32; 2]>::try_from_iter(0..2).unwrap();
+  assert_eq!(av2.as_slice(), &[0, 1][..]);
+
+  let mut av3 = array_vec!([i32; 4], 1);
+  let mut av4 = array_vec!([i32; 4], 2, 3, 4, 5);
+  assert!(av3.try_append(&mut av4).is_err());
+  assert_eq!(av4.len(), 4);
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn ArrayVec_try_index_ops() {
+  let mut av = array_vec!([i32; 4], 1, 2, 3, 4);
+  assert_eq!(av.try_remove(4), None);
+  assert_eq!(av.try_swap_remove(4), None);
+  assert!(av.try_split_off(5).is_none());
+  assert!(av.try_drain(3..2).is_none());
+  assert!(av.try_drain(..5).is_none());
+  assert!(av.try_drain(..=!0).is_none());
+  assert_eq!(av.as_slice(), &[1, 2, 3, 4][..]);
+
+  assert_eq!(av.try_swap_remove(0), Some(1));
+  assert_eq!(av.try_remove(0), Some(4));
+  assert_eq!(av.try_split_off(2).unwrap().as_slice(), &[][..]);
+  assert_eq!(Vec::from_iter(av.try_drain(..=0).unwrap()), vec![2]);
+  assert_eq!(av.as_slice(), &[3][..]);
+}
+
+#[test]
+fn ArrayVec_drain_both_ends() {
+  let mut av = array_vec!([i32; 8], 1, 2, 3, 4, 5, 6, 7);
+  let mut d = av.drain(1..6);
+  assert_eq!(d.len(), 5);
+  assert_eq!(d.next(), Some(2));
+  assert_eq!(d.next_back(), Some(6));
+  assert_eq!(d.as_slice(), &[3, 4, 5][..]);
+  assert_eq!(format!("{:?}", d), "ArrayVecDrain([3, 4, 5])");
+  drop(d);
+  assert_eq!(av.as_slice(), &[1, 7][..]);
+
+  let mut av = array_vec!([String; 4]);
+  av.extend(["a", "b", "c", "d"].iter().map(|s| s.to_string()));
+  let mut d = av.drain(..3);
+  assert_eq!(d.next_back().as_deref(), Some("c"));
+  d.keep_rest();
+  assert_eq!(av.as_slice(), &["a", "b", "d"][..]);
+
+  let rev: Vec<_> = av.drain(..).rev().collect();
+  assert_eq!(rev, vec!["d", "b", "a"]);
+  assert!(av.is_empty());
+}
+
+#[test]
+#[allow(clippy::iter_nth_zero)]
+fn ArrayVec_into_iter_both_ends() {
+  let av = array_vec!([i32; 8], 1, 2, 3, 4, 5, 6);
+  let mut it = av.into_iter();
+  assert_eq!(it.nth(0), Some(1));
+  assert_eq!(it.nth_back(1), Some(5));
+  assert_eq!(it.len(), 3);
+  assert_eq!(it.as_slice(), &[2, 3, 4][..]);
+  it.as_mut_slice()[0] = 20;
+  assert_eq!(format!("{:?}", it.clone()), "ArrayVecIterator([20, 3, 4])");
+  assert_eq!(it.clone().last(), Some(4));
+  assert_eq!(it.nth(5), None);
+  assert_eq!(it.next(), None);
+
+  let rev: Vec<i32> = av.into_iter().rev().collect();
+  assert_eq!(rev, vec![6, 5, 4, 3, 2, 1]);
+  assert_eq!(array_vec!([i32; 2]).into_iter().last(), None);
+}
+
+#[test]
+fn ArrayVec_splice() {
+  let mut av = array_vec!([i32; 6], 1, 2, 3, 4, 5);
+  let removed: Vec<i32> = av.splice(1..4, Some(9)).collect();
+  assert_eq!(removed, vec![2, 3, 4]);
+  assert_eq!(av.as_slice(), &[1, 9, 5][..]);
+
+  av.splice(3.., vec![6, 7]);
+  assert_eq!(av.as_slice(), &[1, 9, 5, 6, 7][..]);
+
+  let err = av.try_splice(..1, vec![0, 0, 0]).unwrap_err();
+  assert_eq!(err.element().len(), 3);
+  assert_eq!(av.as_slice(), &[1, 9, 5, 6, 7][..]);
+  let removed: Vec<i32> = av.try_splice(..1, vec![0, 0]).unwrap().collect();
+  assert_eq!(removed, vec![1]);
+  assert_eq!(av.as_slice(), &[0, 0, 9, 5, 6, 7][..]);
+}
+
+#[test]
+fn ArrayVec_splice_overflow_leaves_vec_unchanged() {
+  use std::panic::{catch_unwind, AssertUnwindSafe};
+  let mut av = array_vec!([i32; 4], 1, 2, 3);
+  let r = catch_unwind(AssertUnwindSafe(|| {
+    av.splice(1..2, vec![7, 8, 9]);
+  }));
+  assert!(r.is_err());
+  assert_eq!(av.as_slice(), &[1, 2, 3][..]);
+  av.splice(1..2, vec![7, 8]);
+  assert_eq!(av.as_slice(), &[1, 7, 8, 3][..]);
+}
+
+#[test]
+fn ArrayVec_extract_if() {
+  let mut av = array_vec!([i32; 10], 1, 2, 3, 4, 5, 6, 7, 8);
+  let mut it = av.extract_if(1..7, |x| *x % 3 == 0);
+  assert_eq!(it.next(), Some(3));
+  drop(it);
+  assert_eq!(av.as_slice(), &[1, 2, 4, 5, 6, 7, 8][..]);
+
+  let odds: Vec<i32> = av.extract_if(.., |x| *x % 2 == 1).collect();
+  assert_eq!(odds, vec![1, 5, 7]);
+  assert_eq!(av.as_slice(), &[2, 4, 6, 8][..]);
+}
+
+#[test]
+fn ArrayVec_retain_and_dedup() {
+  let mut av = array_vec!([i32; 8], 1, 3, 3, 4, 5, 5, 6);
+  av.retain(|&x| x % 2 == 0);
+  assert_eq!(av.as_slice(), &[4, 6][..]);
+
+  let mut av = array_vec!([i32; 8], 1, 2, 3, 4);
+  av.retain_mut(|x| {
+    *x += 1;
+    *x != 3
+  });
+  assert_eq!(av.as_slice(), &[2, 4, 5][..]);
+
+  let mut av = array_vec!([i32; 8], 1, 1, 2, 2, 2, 3, 1, 1);
+  av.dedup();
+  assert_eq!(av.as_slice(), &[1, 2, 3, 1][..]);
+  av.dedup_by_key(|x| *x / 2);
+  assert_eq!(av.as_slice(), &[1, 2, 1][..]);
+}
+
+#[test]
+fn ArrayVec_retain_panic_safety() {
+  use std::panic::{catch_unwind, AssertUnwindSafe};
+  let mut av = array_vec!([i32; 8], 1, 2, 3, 4, 5, 6);
+  let r = catch_unwind(AssertUnwindSafe(|| {
+    av.retain(|&x| if x == 4 { panic!() } else { x % 2 == 0 })
+  }));
+  assert!(r.is_err());
+  assert_eq!(av.as_slice(), &[2, 4, 5, 6][..]);
+
+  let mut av = array_vec!([i32; 8], 1, 1, 2, 2, 3, 3);
+  let r = catch_unwind(AssertUnwindSafe(|| {
+    av.dedup_by(|a, b| if *a == 3 { panic!() } else { a == b })
+  }));
+  assert!(r.is_err());
+  assert_eq!(av.as_slice(), &[1, 2, 3, 3][..]);
+}
+
+#[test]
+fn ArrayVec_dedup_drops_removed() {
+  use std::rc::Rc;
+  let rc = Rc::new(5);
+  let mut av: ArrayVec<[Rc<i32>; 4]> = ArrayVec::default();
+  av.extend((0..3).map(|_| rc.clone()));
+  assert_eq!(Rc::strong_count(&rc), 4);
+  av.dedup();
+  assert_eq!(av.len(), 1);
+  assert_eq!(Rc::strong_count(&rc), 2);
+  drop(av);
+  assert_eq!(Rc::strong_count(&rc), 1);
+
+  let mut av = array_vec!([i32; 6], 1, 1, 1, 2);
+  av.dedup();
+  assert_eq!(av.as_slice(), &[1, 2][..]);
+  assert_eq!(av.into_inner(), [1, 2, 0, 0, 0, 0]);
+}
+
+#[test]
+fn ArrayVec_bulk_moves() {
+  let mut av = array_vec!([u8; 8], 1, 2, 3);
+  av.extend_from_slice(&[4, 5]);
+  av.insert_from_slice(0, &[9, 9]);
+  av.insert_from_slice(7, &[]);
+  assert_eq!(av.as_slice(), &[9, 9, 1, 2, 3, 4, 5][..]);
+  av.remove_range(..2);
+  assert_eq!(av.as_slice(), &[1, 2, 3, 4, 5][..]);
+  assert_eq!(av.split_off(3).as_slice(), &[4, 5][..]);
+  av.insert(3, 0);
+  assert_eq!(av.remove(0), 1);
+  assert_eq!(av.as_slice(), &[2, 3, 0][..]);
+}
+
+#[test]
+#[should_panic]
+fn ArrayVec_remove_past_len() {
+  let mut av = array_vec!([i32; 4], 1, 2);
+  av.remove(2);
+}
+
+#[test]
+#[should_panic]
+fn ArrayVec_extend_from_slice_overflow() {
+  let mut av = array_vec!([i32; 4], 1, 2);
+  av.extend_from_slice(&[3, 4, 5]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn ArrayVec_ser_de() {
+  let av: ArrayVec<[i32; 4]> = array_vec!([i32; 4], 1, 2, 3);
+  assert_tokens(
+    &av,
+    &[
+      Token::Seq { len: Some(3) },
+      Token::I32(1),
+      Token::I32(2),
+      Token::I32(3),
+      Token::SeqEnd,
+    ],
+  );
+
+  // too long, by the size hint or by the elements
+  assert_de_tokens_error::<ArrayVec<[i32; 2]>>(
+    &[Token::Seq { len: Some(3) }],
+    "invalid length 3, expected a sequence of at most 2 elements",
+  );
+  assert_de_tokens_error::<ArrayVec<[i32; 2]>>(
+    &[
+      Token::Seq { len: None },
+      Token::I32(1),
+      Token::I32(2),
+      Token::I32(3),
+      Token::SeqEnd,
+    ],
+    "invalid length 3, expected a sequence of at most 2 elements",
+  );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn ArrayVec_ser_de_bytes() {
+  #[derive(Debug, PartialEq)]
+  struct Bytes(ArrayVec<[u8; 4]>);
+  impl serde::Serialize for Bytes {
+    fn serialize<S: serde::Serializer>(
+      &self,
+      s: S,
+    ) -> Result<S::Ok, S::Error> {
+      self.0.serialize_bytes(s)
+    }
+  }
+  impl<'de> serde::Deserialize<'de> for Bytes {
+    fn deserialize<D: serde::Deserializer<'de>>(
+      d: D,
+    ) -> Result<Self, D::Error> {
+      ArrayVec::deserialize_bytes(d).map(Bytes)
+    }
+  }
+
+  let b = Bytes(array_vec!([u8; 4], 1, 2, 3));
+  assert_tokens(&b, &[Token::Bytes(&[1, 2, 3])]);
+  assert_de_tokens(
+    &b,
+    &[
+      Token::Seq { len: Some(3) },
+      Token::U8(1),
+      Token::U8(2),
+      Token::U8(3),
+      Token::SeqEnd,
+    ],
+  );
+  assert_de_tokens_error::<Bytes>(
+    &[Token::Bytes(&[1, 2, 3, 4, 5])],
+    "invalid length 5, expected at most 4 bytes",
+  );
+}
+
+#[cfg(feature = "rustc_1_61")]
+#[test]
+fn ArrayVec_const_constructors() {
+  static EMPTY: ArrayVec<[u8; 4]> = ArrayVec::from_array_empty([0; 4]);
+  const TABLE: ArrayVec<[u16; 6]> =
+    ArrayVec::from_array_len([1, 2, 3, 0, 0, 0], 3);
+  static MACRO: ArrayVec<[u16; 6]> = array_vec!(const [u16; 6], 1, 2, 3);
+  assert!(EMPTY.is_empty());
+  assert_eq!(EMPTY.capacity(), 4);
+  assert_eq!(TABLE, MACRO);
+  assert_eq!(MACRO.capacity(), 6);
+
+  let mut av = MACRO;
+  av.push(4);
+  assert_eq!(av.as_slice(), &[1, 2, 3, 4]);
+
+  // the spare capacity holds the default, not copies of the elements
+  assert_eq!(MACRO.into_inner(), [1, 2, 3, 0, 0, 0]);
+  const NONE: ArrayVec<[u8; 2]> = array_vec!(const [u8; 2]);
+  assert!(NONE.is_empty());
+}
+
+#[cfg(feature = "rustc_1_61")]
+#[test]
+#[should_panic]
+fn ArrayVec_from_array_len_past_capacity() {
+  let len = 5;
+  let _ = ArrayVec::from_array_len([0_u8; 4], len);
+}
+
+#[test]
+fn ArrayVec_macro_forms() {
+  let av = array_vec!([u8; 8] => 7; 3);
+  assert_eq!(av.as_slice(), &[7, 7, 7]);
+  assert_eq!(av.capacity(), 8);
+
+  let av = array_vec!([_; 4], "a", "b",);
+  assert_eq!(av.as_slice(), &["a", "b"]);
+
+  let av = array_vec!([String; 4] => String::from("x"); 2);
+  assert_eq!(av.as_slice(), &["x", "x"]);
+
+  let mut av = array_vec!([_; 4]);
+  av.push(1_u64);
+  assert_eq!(av.len(), 1);
+}
+
+#[test]
+#[should_panic]
+fn ArrayVec_macro_repeat_past_capacity() {
+  let count = 5;
+  let _ = array_vec!([u8; 4] => 0; count);
+}
+
+#[test]
+fn ArrayVec_into_inner() {
+  let mut av = array_vec!([u8; 4], 1, 2, 3);
+  av.pop();
+  assert_eq!(av.into_inner(), [1, 2, 0, 0]);
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn ArrayVec_zeroize() {
+  use zeroize::Zeroize;
+  let mut av = array_vec!([u8; 8], 1, 2, 3, 4, 5);
+  av.truncate(2);
+  av.zeroize();
+  assert!(av.is_empty());
+  assert_eq!(av.into_inner(), [0; 8]);
+}
+
+#[cfg(feature = "wipe_vacated")]
+#[test]
+fn ArrayVec_wipe_vacated() {
+  let mut av = array_vec!([u8; 8], 1, 2, 3, 4, 5);
+  av.truncate(3);
+  assert_eq!(av.into_inner(), [1, 2, 3, 0, 0, 0, 0, 0]);
+  av.clear();
+  assert_eq!(av.into_inner(), [0; 8]);
+
+  let mut ad: ArrayDeque<[u8; 4]> = ArrayDeque::default();
+  ad.extend(1..=4);
+  ad.truncate(1);
+  ad.extend(5..=7);
+  assert_eq!(ad.iter().copied().collect::<Vec<_>>(), vec![1, 5, 6, 7]);
+}
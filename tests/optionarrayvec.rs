@@ -0,0 +1,117 @@
+#![allow(bad_style)]
+
+use core::num::NonZeroU32;
+use tinyvec::*;
+
+/// Deliberately has no `Default` impl.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct NoDefault(u32);
+
+#[test]
+fn OptionArrayVec_push_pop() {
+  let mut av: OptionArrayVec<[Option<NoDefault>; 3]> = OptionArrayVec::new();
+  assert!(av.is_empty());
+  av.push(NoDefault(1));
+  av.push(NoDefault(2));
+  assert_eq!(av.try_push(NoDefault(3)), Ok(()));
+  assert_eq!(av.try_push(NoDefault(4)), Err(NoDefault(4)));
+  assert_eq!(av.len(), 3);
+  assert_eq!(av.capacity(), 3);
+  assert_eq!(av[0], NoDefault(1));
+  assert_eq!(av.last(), Some(&NoDefault(3)));
+  assert_eq!(av.get(3), None);
+  assert_eq!(av.pop(), Some(NoDefault(3)));
+  assert_eq!(av.pop(), Some(NoDefault(2)));
+  assert_eq!(av.pop(), Some(NoDefault(1)));
+  assert_eq!(av.pop(), None);
+  assert!(av.as_option_slice().is_empty());
+}
+
+#[test]
+fn OptionArrayVec_insert_remove() {
+  let mut av: OptionArrayVec<[Option<NoDefault>; 4]> =
+    (1..=3).map(NoDefault).collect();
+  av.insert(1, NoDefault(9));
+  assert_eq!(
+    av.as_option_slice(),
+    &[
+      Some(NoDefault(1)),
+      Some(NoDefault(9)),
+      Some(NoDefault(2)),
+      Some(NoDefault(3))
+    ]
+  );
+  let err = av.try_insert(0, NoDefault(7)).unwrap_err();
+  assert_eq!(err.element(), NoDefault(7));
+  assert_eq!(av.remove(1), NoDefault(9));
+  assert_eq!(av.swap_remove(0), NoDefault(1));
+  assert_eq!(av.try_remove(2), None);
+  assert_eq!(av.try_swap_remove(1), Some(NoDefault(2)));
+  assert!(av.iter().eq([NoDefault(3)].iter()));
+}
+
+#[test]
+#[should_panic]
+fn OptionArrayVec_index_out_of_bounds() {
+  let av: OptionArrayVec<[Option<NoDefault>; 2]> =
+    Some(NoDefault(1)).into_iter().collect();
+  let _ = &av[1];
+}
+
+#[test]
+fn OptionArrayVec_drain_retain() {
+  let mut av: OptionArrayVec<[Option<NoDefault>; 6]> =
+    (1..=6).map(NoDefault).collect();
+  av.retain(|x| x.0 % 2 == 0);
+  assert_eq!(av.len(), 3);
+  let drained: Vec<NoDefault> = av.drain(..2).rev().collect();
+  assert_eq!(drained, vec![NoDefault(4), NoDefault(2)]);
+  assert!(av.iter().eq([NoDefault(6)].iter()));
+
+  let mut av: OptionArrayVec<[Option<NoDefault>; 6]> =
+    (1..=6).map(NoDefault).collect();
+  av.retain_mut(|x| {
+    x.0 *= 10;
+    x.0 > 30
+  });
+  let tail = av.split_off(1);
+  assert!(av.iter().eq([NoDefault(40)].iter()));
+  let owned: Vec<NoDefault> = tail.into_iter().collect();
+  assert_eq!(owned, vec![NoDefault(50), NoDefault(60)]);
+}
+
+#[test]
+fn OptionArrayVec_niche_and_refs() {
+  assert_eq!(
+    core::mem::size_of::<[Option<NonZeroU32>; 4]>(),
+    core::mem::size_of::<[u32; 4]>()
+  );
+  let mut ids: OptionArrayVec<[Option<NonZeroU32>; 4]> = OptionArrayVec::new();
+  ids.extend((1..=3).filter_map(NonZeroU32::new));
+  assert_eq!(ids.iter().map(|n| n.get()).sum::<u32>(), 6);
+
+  let mut a = 1;
+  let mut b = 2;
+  let mut refs: OptionArrayVec<[Option<&mut i32>; 2]> = OptionArrayVec::new();
+  refs.push(&mut a);
+  refs.push(&mut b);
+  for r in refs.iter_mut() {
+    **r += 10;
+  }
+  assert_eq!((a, b), (11, 12));
+}
+
+#[test]
+fn OptionArrayVec_cmp_and_fmt() {
+  let a: OptionArrayVec<[Option<u8>; 4]> = vec![1, 2].into_iter().collect();
+  let mut b = a;
+  assert_eq!(a, b);
+  b.push(0);
+  assert!(a < b);
+  b.truncate(2);
+  b[1] = 3;
+  assert!(a < b);
+  assert_eq!(format!("{:?}", a), "[1, 2]");
+  assert_eq!(format!("{:02x}", b), "[01, 03]");
+  assert_eq!(a, &[1, 2][..]);
+}
@@ -0,0 +1,90 @@
+#![allow(bad_style)]
+
+use tinyvec::*;
+use std::iter::FromIterator;
+
+#[test]
+fn SliceVec_push_pop() {
+  let mut arr = [0_i32; 4];
+  let mut sv = SliceVec::from_slice_len(&mut arr, 0);
+  assert_eq!(sv.pop(), None);
+  sv.push(10);
+  sv.push(11);
+  sv.push(12);
+  sv.push(13);
+  assert_eq!(sv.try_push(14), Err(14));
+  assert_eq!(sv.len(), 4);
+  assert_eq!(sv.pop(), Some(13));
+  assert_eq!(sv[..], [10, 11, 12]);
+  assert_eq!(arr, [10, 11, 12, 0]);
+}
+
+#[test]
+#[should_panic]
+fn SliceVec_push_overflow() {
+  let mut sv: SliceVec<'_, i32> = Default::default();
+  sv.push(7);
+}
+
+#[test]
+fn SliceVec_insert_remove() {
+  let mut arr = [0_i32; 6];
+  let mut sv = SliceVec::from_slice_len(&mut arr, 0);
+  sv.extend_from_slice(&[1, 2, 3]);
+  sv.insert(0, 0);
+  sv.insert(4, 4);
+  assert_eq!(sv.as_slice(), &[0, 1, 2, 3, 4]);
+  assert_eq!(sv.remove(2), 2);
+  assert_eq!(sv.swap_remove(0), 0);
+  assert_eq!(sv.as_slice(), &[4, 1, 3]);
+  sv.resize(5, 9);
+  assert_eq!(sv.as_slice(), &[4, 1, 3, 9, 9]);
+  sv.truncate(1);
+  assert_eq!(sv.as_slice(), &[4]);
+}
+
+#[test]
+fn SliceVec_retain() {
+  let mut arr = [1, 2, 2, 3, 4, 4, 5, 6];
+  let mut sv = SliceVec::from(&mut arr[..]);
+  sv.retain(|&x| x % 2 == 0);
+  assert_eq!(sv.as_slice(), &[2, 2, 4, 4, 6]);
+}
+
+#[test]
+fn SliceVec_drain() {
+  let all = [1, 2, 3, 4];
+  for start in 0..=all.len() {
+    for end in start..=all.len() {
+      let mut arr = all;
+      let mut sv = SliceVec::from(&mut arr[..]);
+      assert_eq!(Vec::from_iter(sv.drain(start..end)), &all[start..end]);
+      let mut rest = all.to_vec();
+      rest.drain(start..end);
+      assert_eq!(sv.as_slice(), &rest[..]);
+    }
+  }
+}
+
+#[test]
+fn SliceVec_split_off_and_append() {
+  let mut arr = [1, 2, 3, 4, 0, 0];
+  let mut sv = SliceVec::from_slice_len(&mut arr, 4);
+  let mut back = sv.split_off(2);
+  assert_eq!(sv.as_slice(), &[1, 2]);
+  assert_eq!(sv.capacity(), 2);
+  assert_eq!(back.as_slice(), &[3, 4]);
+  assert_eq!(back.capacity(), 4);
+  back.append(&mut sv);
+  assert_eq!(back.as_slice(), &[3, 4, 1, 2]);
+  assert!(sv.is_empty());
+}
+
+#[test]
+fn SliceVec_formatting() {
+  let mut arr = [10, 11, 12];
+  let sv = SliceVec::from(&mut arr);
+  assert_eq!(format!("{:?}", sv), "[10, 11, 12]");
+  assert_eq!(format!("{:x}", sv), "[a, b, c]");
+  assert_eq!(format!("{}", sv), "[10, 11, 12]");
+}
@@ -0,0 +1,36 @@
+#![allow(bad_style)]
+
+use core::cmp::Reverse;
+use tinyvec::*;
+
+#[test]
+fn TinyBinaryHeap_spills_to_the_heap() {
+  let mut heap: TinyBinaryHeap<[u32; 2]> = TinyBinaryHeap::new();
+  heap.push(4);
+  heap.push(1);
+  assert!(matches!(heap, TinyBinaryHeap::Inline(_)));
+  heap.push(7);
+  heap.push(3);
+  assert!(matches!(heap, TinyBinaryHeap::Heap(_)));
+  assert_eq!(heap.len(), 4);
+  assert_eq!(heap.peek(), Some(&7));
+  *heap.peek_mut().unwrap() = 0;
+  assert_eq!(heap.pop(), Some(4));
+  assert_eq!(heap.into_sorted_vec(), [0, 1, 3]);
+}
+
+#[test]
+fn TinyBinaryHeap_min_heap_and_drain() {
+  let mut timers: TinyBinaryHeap<[Reverse<u64>; 4]> =
+    [30, 10, 20].iter().map(|t| Reverse(*t)).collect();
+  assert!(matches!(timers, TinyBinaryHeap::Inline(_)));
+  let top = timers.peek_mut().unwrap();
+  assert_eq!(TinyBinaryHeapPeekMut::pop(top), Reverse(10));
+  assert_eq!(timers.iter().len(), 2);
+
+  let mut heap: TinyBinaryHeap<[u8; 1]> = [2, 3, 1].iter().copied().collect();
+  let mut drained: Vec<u8> = heap.drain().collect();
+  drained.sort_unstable();
+  assert_eq!(drained, [1, 2, 3]);
+  assert!(heap.is_empty());
+}
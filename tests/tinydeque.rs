@@ -0,0 +1,82 @@
+#![allow(bad_style)]
+
+use tinyvec::*;
+use std::{collections::VecDeque, iter::FromIterator};
+
+#[test]
+fn TinyDeque_push_pop() {
+  let mut q: TinyDeque<[i32; 4]> = Default::default();
+  assert_eq!(q.pop_front(), None);
+  q.push_back(2);
+  q.push_front(1);
+  assert_eq!(q.front(), Some(&1));
+  assert_eq!(q.back(), Some(&2));
+  assert_eq!(q.pop_back(), Some(2));
+  assert_eq!(q.pop_front(), Some(1));
+  assert!(q.is_empty());
+}
+
+#[test]
+fn TinyDeque_spill_preserves_order() {
+  let mut q: TinyDeque<[i32; 4]> = Default::default();
+  let mut model = VecDeque::new();
+  // wrap the inline ring before it spills
+  for i in 0..3 {
+    q.push_back(i);
+    model.push_back(i);
+  }
+  q.pop_front();
+  model.pop_front();
+  q.push_back(3);
+  model.push_back(3);
+  q.push_front(-1);
+  model.push_front(-1);
+  match q {
+    TinyDeque::Inline(_) => (),
+    TinyDeque::Heap(_) => panic!("should still be inline"),
+  }
+
+  q.push_front(-2);
+  model.push_front(-2);
+  match q {
+    TinyDeque::Inline(_) => panic!("should have moved to the heap"),
+    TinyDeque::Heap(_) => (),
+  }
+  assert_eq!(Vec::from_iter(q.iter()), Vec::from_iter(model.iter()));
+
+  q.push_back(100);
+  model.push_back(100);
+  assert_eq!(Vec::from_iter(q), Vec::from_iter(model));
+}
+
+#[test]
+fn TinyDeque_drain() {
+  let base: TinyDeque<[i32; 4]> = (0..4).collect();
+  let heap: TinyDeque<[i32; 4]> = (0..6).collect();
+  for q in [base, heap].iter() {
+    let all = Vec::from_iter(q.iter().copied());
+    for start in 0..=all.len() {
+      for end in start..=all.len() {
+        let mut q2 = q.clone();
+        assert_eq!(Vec::from_iter(q2.drain(start..end)), &all[start..end]);
+        let mut rest = all.clone();
+        rest.drain(start..end);
+        assert_eq!(Vec::from_iter(q2.iter().copied()), rest);
+      }
+    }
+  }
+}
+
+#[test]
+fn TinyDeque_rotate_and_slices() {
+  let mut q: TinyDeque<[i32; 8]> = (0..5).collect();
+  q.rotate_left(2);
+  assert_eq!(Vec::from_iter(q.iter().copied()), vec![2, 3, 4, 0, 1]);
+  q.rotate_right(2);
+  assert_eq!(q.make_contiguous(), &[0, 1, 2, 3, 4]);
+  let (a, b) = q.as_slices();
+  assert_eq!(a, &[0, 1, 2, 3, 4]);
+  assert!(b.is_empty());
+  assert_eq!(format!("{:?}", q), "[0, 1, 2, 3, 4]");
+  assert_eq!(Vec::from_iter(q.into_iter().rev()), vec![4, 3, 2, 1, 0]);
+}
@@ -0,0 +1,47 @@
+#![allow(bad_style)]
+
+use tinyvec::*;
+
+#[test]
+fn TinyMap_spills_to_the_heap() {
+  let mut m: TinyMap<[(u32, u32); 2]> = TinyMap::new();
+  m.insert(2, 20);
+  m.insert(1, 10);
+  assert_eq!(m.insert(1, 11), Some(10));
+  assert!(matches!(m, TinyMap::Inline(_)));
+  m.insert(0, 0);
+  assert!(matches!(m, TinyMap::Heap(_)));
+  assert_eq!(m.len(), 3);
+  assert_eq!(m.get(&1), Some(&11));
+  assert_eq!(m.get(&2), Some(&20));
+  assert_eq!(m.get_key_value(&0), Some((&0, &0)));
+  assert_eq!(m.first_key_value(), Some((&0, &0)));
+  assert_eq!(m.last_key_value(), Some((&2, &20)));
+  assert_eq!(m.keys().copied().collect::<Vec<_>>(), [0, 1, 2]);
+  assert_eq!(m.range(1..).count(), 2);
+  assert_eq!(m.remove(&0), Some(0));
+  m.retain(|k, _| *k != 1);
+  assert_eq!(format!("{:?}", m), "{2: 20}");
+}
+
+#[test]
+fn TinyMap_entry_moves_to_the_heap() {
+  let mut m: TinyMap<[(char, usize); 2]> = TinyMap::new();
+  for c in "mississippi".chars() {
+    *m.entry(c).or_insert(0) += 1;
+  }
+  assert!(matches!(m, TinyMap::Heap(_)));
+  let counts: Vec<(char, usize)> = m.clone().into_iter().collect();
+  assert_eq!(counts, [('i', 4), ('m', 1), ('p', 2), ('s', 4)]);
+
+  let inline: TinyMap<[(char, usize); 4]> = counts.iter().copied().collect();
+  assert!(matches!(inline, TinyMap::Inline(_)));
+  let heap: TinyMap<[(char, usize); 4]> = m.into_iter().collect();
+  let mut heap = heap;
+  heap.move_to_the_heap();
+  assert_eq!(inline, heap);
+  for v in heap.values_mut() {
+    *v = 0;
+  }
+  assert!(heap.iter().all(|(_, v)| *v == 0));
+}
@@ -0,0 +1,41 @@
+#![allow(bad_style)]
+
+use tinyvec::*;
+
+/// Deliberately has no `Default` impl.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct NoDefault(u32);
+
+#[test]
+fn TinyOptionVec_spills_to_the_heap() {
+  let mut tv: TinyOptionVec<[Option<NoDefault>; 2]> = TinyOptionVec::new();
+  tv.push(NoDefault(1));
+  tv.push(NoDefault(2));
+  assert!(tv.is_inline());
+  tv.insert(0, NoDefault(0));
+  assert!(!tv.is_inline());
+  assert!(tv.iter().eq([NoDefault(0), NoDefault(1), NoDefault(2)].iter()));
+  assert_eq!(tv[2], NoDefault(2));
+
+  assert_eq!(tv.remove(0), NoDefault(0));
+  tv.shrink_to_fit();
+  assert!(tv.is_inline());
+  assert_eq!(format!("{:?}", tv), "[NoDefault(1), NoDefault(2)]");
+}
+
+#[test]
+fn TinyOptionVec_drain_and_into_iter() {
+  let mut tv: TinyOptionVec<[Option<NoDefault>; 2]> =
+    (1..=5).map(NoDefault).collect();
+  let drained: Vec<NoDefault> = tv.drain(1..4).collect();
+  assert_eq!(drained, vec![NoDefault(2), NoDefault(3), NoDefault(4)]);
+  tv.retain(|x| x.0 != 5);
+  let owned: Vec<NoDefault> = tv.into_iter().rev().collect();
+  assert_eq!(owned, vec![NoDefault(1)]);
+
+  let av: OptionArrayVec<[Option<NoDefault>; 2]> =
+    (1..=2).map(NoDefault).collect();
+  let tv = TinyOptionVec::from(av);
+  assert!(tv.is_inline());
+  assert_eq!(tv.len(), 2);
+}
@@ -0,0 +1,45 @@
+#![allow(bad_style)]
+
+use tinyvec::*;
+
+#[test]
+fn TinySet_spills_to_the_heap() {
+  let mut s: TinySet<[u32; 2]> = TinySet::new();
+  assert!(s.insert(20));
+  assert!(s.insert(10));
+  assert!(!s.insert(10));
+  assert!(matches!(s, TinySet::Inline(_)));
+  assert!(s.insert(0));
+  assert!(matches!(s, TinySet::Heap(_)));
+  assert_eq!(s.len(), 3);
+  assert!(s.contains(&10));
+  assert_eq!(s.first(), Some(&0));
+  assert_eq!(s.last(), Some(&20));
+  assert!(s.range(5..).eq([10, 20].iter()));
+  assert!(s.remove(&10));
+  s.retain(|x| *x != 0);
+  assert_eq!(format!("{:?}", s), "{20}");
+  assert_eq!(s.into_iter().collect::<Vec<_>>(), [20]);
+}
+
+#[test]
+fn TinySet_algebra() {
+  let a: TinySet<[u8; 2]> = [1, 2, 3, 5].iter().copied().collect();
+  let b: TinySet<[u8; 2]> = [2, 4].iter().copied().collect();
+  assert!(matches!(a, TinySet::Heap(_)));
+  assert!(matches!(b, TinySet::Inline(_)));
+
+  assert!(a.union(&b).eq([1, 2, 3, 4, 5].iter()));
+  assert!(a.intersection(&b).eq([2].iter()));
+  assert!(a.difference(&b).eq([1, 3, 5].iter()));
+  assert!(a.symmetric_difference(&b).eq([1, 3, 4, 5].iter()));
+  assert!(!a.is_disjoint(&b));
+  assert!(!b.is_subset(&a));
+
+  let i = &a & &b;
+  assert!(matches!(i, TinySet::Inline(_)));
+  assert!(i.is_subset(&a) && i.is_subset(&b));
+  assert_eq!(&a | &b, [1, 2, 3, 4, 5].iter().copied().collect());
+  assert_eq!((&a - &b).len(), 3);
+  assert_eq!((&a ^ &b).len(), 4);
+}
@@ -0,0 +1,76 @@
+#![allow(bad_style)]
+
+use tinyvec::*;
+use std::{collections::HashMap, fmt::Write};
+
+#[test]
+fn TinyString_spill() {
+  let mut s: TinyString<[u8; 4]> = Default::default();
+  s.push_str("ab");
+  s.push('c');
+  match s {
+    TinyString::Inline(_) => (),
+    TinyString::Heap(_) => panic!("should still be inline"),
+  }
+  s.push('é');
+  match s {
+    TinyString::Inline(_) => panic!("should have moved to the heap"),
+    TinyString::Heap(_) => (),
+  }
+  assert_eq!(s, "abcé");
+  s.insert(0, '>');
+  assert_eq!(s.remove(1), 'a');
+  assert_eq!(s.pop(), Some('é'));
+  assert_eq!(s, ">bc");
+}
+
+#[test]
+fn TinyString_insert_spills() {
+  let mut s: TinyString<[u8; 4]> = TinyString::from("ad");
+  s.insert_str(1, "bc");
+  assert_eq!(s, "abcd");
+  s.insert_str(4, "ef");
+  assert_eq!(s, "abcdef");
+  write!(s, "{}", 1).unwrap();
+  assert_eq!(s.to_string(), "abcdef1");
+}
+
+#[test]
+fn TinyString_insert_bad_index_stays_inline() {
+  use std::panic::{catch_unwind, AssertUnwindSafe};
+
+  let mut s: TinyString<[u8; 4]> = TinyString::from("é");
+  let r = catch_unwind(AssertUnwindSafe(|| s.insert_str(3, "abc")));
+  assert!(r.is_err());
+  let r = catch_unwind(AssertUnwindSafe(|| s.insert_str(1, "abc")));
+  assert!(r.is_err());
+  assert!(matches!(s, TinyString::Inline(_)));
+  assert_eq!(s, "é");
+}
+
+#[test]
+fn TinyString_string_conversions() {
+  let owned = String::from("a rather long heap string");
+  let ptr = owned.as_ptr();
+  let s: TinyString<[u8; 4]> = owned.into();
+  let back: String = s.into();
+  assert_eq!(back.as_ptr(), ptr);
+
+  let inline: TinyString<[u8; 8]> = TinyString::from("hi");
+  let back: String = inline.into();
+  assert_eq!(back, "hi");
+}
+
+#[test]
+fn TinyString_map_key() {
+  let inline: TinyString<[u8; 8]> = TinyString::from("key");
+  let mut heap: TinyString<[u8; 8]> = TinyString::from("k");
+  heap.move_to_the_heap();
+  heap.push_str("ey");
+  assert_eq!(inline, heap);
+
+  let mut map = HashMap::new();
+  map.insert(inline, 1);
+  assert_eq!(map.get("key"), Some(&1));
+  assert_eq!(map.get(&heap), Some(&1));
+}
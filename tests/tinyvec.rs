@@ -4,6 +4,11 @@
 use tinyvec::*;
 use std::iter::FromIterator;
 
+#[cfg(feature = "serde")]
+use serde_test::{
+  assert_de_tokens, assert_de_tokens_error, assert_tokens, Token,
+};
+
 #[test]
 fn TinyVec_swap_remove() {
   let mut tv: TinyVec<[i32; 10]> = Default::default();
@@ -56,3 +61,471 @@ fn TinyVec_resize() {
   tv.resize(20, 5);
   assert_eq!(&tv[..], &[5; 20]);
 }
+
+#[test]
+fn TinyVec_try_index_ops() {
+  let mut tv: TinyVec<[i32; 2]> = TinyVec::from_iter(1..=4);
+  assert!(matches!(tv, TinyVec::Heap(_)));
+  assert_eq!(tv.try_remove(4), None);
+  assert_eq!(tv.try_swap_remove(4), None);
+  assert!(tv.try_split_off(5).is_none());
+  assert!(tv.try_drain(1..5).is_none());
+  assert_eq!(tv.as_slice(), &[1, 2, 3, 4][..]);
+
+  assert_eq!(tv.try_swap_remove(0), Some(1));
+  assert_eq!(tv.try_remove(0), Some(4));
+  assert_eq!(tv.try_split_off(1).unwrap().as_slice(), &[3][..]);
+  assert_eq!(Vec::from_iter(tv.try_drain(..).unwrap()), vec![2]);
+
+  let mut tv = tiny_vec!([i32; 4], 5, 6);
+  assert_eq!(tv.try_remove(2), None);
+  assert_eq!(tv.try_remove(1), Some(6));
+}
+
+#[test]
+fn TinyVec_drain_both_ends() {
+  let mut tv: TinyVec<[i32; 2]> = TinyVec::from_iter(1..=6);
+  let mut d = tv.drain(1..5);
+  assert_eq!(d.size_hint(), (4, Some(4)));
+  assert_eq!(d.next_back(), Some(5));
+  assert_eq!(d.next(), Some(2));
+  assert_eq!(d.as_slice(), &[3, 4][..]);
+  d.keep_rest();
+  assert_eq!(tv.as_slice(), &[1, 3, 4, 6][..]);
+
+  let mut d = tv.drain(1..);
+  assert_eq!(d.next(), Some(3));
+  drop(d);
+  assert_eq!(tv.as_slice(), &[1][..]);
+}
+
+#[test]
+fn TinyVec_into_iter_both_ends() {
+  let tv: TinyVec<[i32; 2]> = TinyVec::from_iter(1..=4);
+  let mut it = tv.clone().into_iter();
+  assert_eq!(it.next_back(), Some(4));
+  assert_eq!(it.nth(1), Some(2));
+  assert_eq!(it.as_slice(), &[3][..]);
+  assert_eq!(format!("{:?}", it), "TinyVecIterator([3])");
+
+  let rev: Vec<i32> = tv.into_iter().rev().collect();
+  assert_eq!(rev, vec![4, 3, 2, 1]);
+  let rev: Vec<i32> = tiny_vec!([i32; 4], 1, 2).into_iter().rev().collect();
+  assert_eq!(rev, vec![2, 1]);
+}
+
+#[test]
+fn TinyVec_splice_and_extract_if() {
+  let mut tv = tiny_vec!([i32; 4], 1, 2, 3);
+  let removed: Vec<i32> = tv.splice(..1, vec![7, 8]).collect();
+  assert_eq!(removed, vec![1]);
+  assert!(matches!(tv, TinyVec::Inline(_)));
+  let removed: Vec<i32> = tv.splice(4.., vec![9, 10]).collect();
+  assert!(removed.is_empty());
+  assert!(matches!(tv, TinyVec::Heap(_)));
+  assert_eq!(tv.as_slice(), &[7, 8, 2, 3, 9, 10][..]);
+
+  let big: Vec<i32> = tv.extract_if(.., |x| *x > 5).collect();
+  assert_eq!(big, vec![7, 8, 9, 10]);
+  assert_eq!(tv.as_slice(), &[2, 3][..]);
+}
+
+#[test]
+fn TinyVec_retain_and_dedup() {
+  let mut tv: TinyVec<[i32; 2]> = TinyVec::from_iter(vec![1, 1, 2, 3, 3, 4]);
+  tv.dedup();
+  assert_eq!(tv.as_slice(), &[1, 2, 3, 4][..]);
+  tv.retain_mut(|x| {
+    *x *= 2;
+    *x > 2
+  });
+  assert_eq!(tv.as_slice(), &[4, 6, 8][..]);
+
+  let mut tv = tiny_vec!([i32; 4], 5, 6, 6, 7);
+  tv.dedup_by_key(|x| *x / 2);
+  assert_eq!(tv.as_slice(), &[5, 6][..]);
+}
+
+#[test]
+fn TinyVec_dedup_drops_removed() {
+  use std::rc::Rc;
+  let rc = Rc::new(5);
+  let mut tv: TinyVec<[Rc<i32>; 4]> = TinyVec::default();
+  tv.extend((0..3).map(|_| rc.clone()));
+  assert!(matches!(tv, TinyVec::Inline(_)));
+  tv.dedup();
+  assert_eq!(tv.len(), 1);
+  assert_eq!(Rc::strong_count(&rc), 2);
+}
+
+#[test]
+fn TinyVec_bulk_moves() {
+  let mut tv = tiny_vec!([u8; 4], 1, 2);
+  tv.insert_from_slice(1, &[7, 8]);
+  assert!(matches!(tv, TinyVec::Inline(_)));
+  tv.insert_from_slice(4, &[9]);
+  assert!(matches!(tv, TinyVec::Heap(_)));
+  tv.extend_from_slice(&[10, 11]);
+  assert_eq!(tv.as_slice(), &[1, 7, 8, 2, 9, 10, 11][..]);
+  tv.remove_range(1..=4);
+  assert_eq!(tv.as_slice(), &[1, 10, 11][..]);
+}
+
+#[test]
+fn TinyVec_capacity_management() {
+  let mut tv: TinyVec<[i32; 4]> = TinyVec::with_capacity(4);
+  assert!(matches!(tv, TinyVec::Inline(_)));
+  tv.extend_from_slice(&[1, 2, 3]);
+  tv.reserve_exact(10);
+  assert!(matches!(tv, TinyVec::Heap(_)));
+  assert_eq!(tv.capacity(), 13);
+  for i in 4..=13 {
+    tv.push(i);
+  }
+  assert_eq!(tv.capacity(), 13);
+
+  tv.truncate(2);
+  tv.shrink_to_fit();
+  assert!(matches!(tv, TinyVec::Inline(_)));
+  assert_eq!(tv.as_slice(), &[1, 2][..]);
+  tv.shrink_to_fit();
+  assert_eq!(tv.capacity(), 4);
+}
+
+#[test]
+fn TinyVec_insert_past_len_stays_inline() {
+  use std::panic::{catch_unwind, AssertUnwindSafe};
+  let mut tv = tiny_vec!([i32; 2], 1, 2);
+  let r = catch_unwind(AssertUnwindSafe(|| tv.insert(3, 9)));
+  assert!(r.is_err());
+  assert!(matches!(tv, TinyVec::Inline(_)));
+  assert_eq!(tv.as_slice(), &[1, 2][..]);
+}
+
+#[cfg(feature = "rustc_1_57")]
+#[test]
+fn TinyVec_fallible_allocation() {
+  let mut tv = tiny_vec!([u8; 4], 1, 2, 3, 4);
+  assert!(tv.try_reserve(usize::MAX - 2).is_err());
+  assert!(matches!(tv, TinyVec::Inline(_)));
+  assert_eq!(tv.try_push(5), Ok(()));
+  assert!(matches!(tv, TinyVec::Heap(_)));
+  assert!(tv.try_reserve_exact(usize::MAX).is_err());
+  assert_eq!(tv.as_slice(), &[1, 2, 3, 4, 5][..]);
+  assert_eq!(tv.try_insert(0, 0), Ok(()));
+  assert_eq!(tv.as_slice(), &[0, 1, 2, 3, 4, 5][..]);
+}
+
+#[cfg(feature = "rustc_1_57")]
+#[test]
+fn TinyVec_try_insert_past_len_panics() {
+  use std::panic::{catch_unwind, AssertUnwindSafe};
+
+  let mut tv = tiny_vec!([i32; 2], 1, 2);
+  let r = catch_unwind(AssertUnwindSafe(|| tv.try_insert(3, 9)));
+  assert!(r.is_err());
+  assert!(matches!(tv, TinyVec::Inline(_)));
+  assert_eq!(tv.as_slice(), &[1, 2][..]);
+}
+
+#[test]
+fn TinyVec_spill_policies() {
+  let mut tv: TinyVec<[i32; 4]> = TinyVec::from_iter(0..5);
+  assert_eq!(tv.capacity(), 8);
+  tv.clear();
+  assert!(matches!(tv, TinyVec::Heap(_)));
+
+  let mut tv: TinyVec<[i32; 4], AutoDemote> = TinyVec::from_iter(0..5);
+  tv.pop();
+  tv.pop();
+  assert!(matches!(tv, TinyVec::Heap(_)));
+  tv.retain(|&x| x != 0);
+  assert!(matches!(tv, TinyVec::Inline(_)));
+  assert_eq!(tv.as_slice(), &[1, 2][..]);
+
+  let mut tv: TinyVec<[i32; 4], Exact> = TinyVec::from_iter(0..5);
+  assert_eq!(tv.capacity(), 5);
+  tv.push(5);
+  assert_eq!(tv.capacity(), 6);
+  tv.extend_from_slice(&[6, 7, 8]);
+  assert_eq!(tv.capacity(), 9);
+  tv.truncate(1);
+  assert!(matches!(tv, TinyVec::Heap(_)));
+
+  let mut tv: TinyVec<[u8; 2], Exact> = TinyVec::with_capacity(3);
+  tv.extend_from_slice(&[1, 2, 3]);
+  assert_eq!(tv.capacity(), 3);
+}
+
+#[cfg(feature = "spill_stats")]
+#[test]
+fn TinyVec_spill_stats() {
+  static STATS: SpillStats = SpillStats::new();
+  struct Counted;
+  impl SpillPolicy for Counted {
+    fn stats() -> &'static SpillStats {
+      &STATS
+    }
+  }
+  type Tv = TinyVec<[u8; 4], Counted>;
+
+  // peaks of 2, 3 and 6, and only the last one spills and gets counted
+  let _a: Tv = TinyVec::from_iter(0..2);
+  let _b: Tv = TinyVec::from_iter(0..3);
+  let mut c: Tv = TinyVec::from_iter(0..6);
+  assert_eq!(STATS.spills(), 1);
+  let spill_lens = STATS.spill_len_histogram();
+  assert_eq!(spill_lens[3], 1);
+  assert_eq!(spill_lens.iter().sum::<usize>(), 1);
+  let peaks = STATS.peak_len_histogram();
+  assert_eq!(&peaks[..5], &[0, 0, 0, 1, 0][..]);
+
+  // growing on the heap doesn't count the vec again
+  c.resize(20, 0);
+  assert_eq!(STATS.spills(), 1);
+  assert_eq!(&STATS.peak_len_histogram()[..6], &[0, 0, 0, 1, 0, 0][..]);
+
+  STATS.reset();
+  assert_eq!(STATS.spills(), 0);
+  assert!(STATS.peak_len_histogram().iter().all(|&n| n == 0));
+}
+
+#[cfg(feature = "spill_stats")]
+#[test]
+fn TinyVec_spill_stats_oscillating_len() {
+  static STATS: SpillStats = SpillStats::new();
+  struct Counted;
+  impl SpillPolicy for Counted {
+    fn stats() -> &'static SpillStats {
+      &STATS
+    }
+  }
+
+  // spills at length 5, then goes back and forth between 7 and 8, which are
+  // either side of the bucket 3 / bucket 4 boundary
+  let mut tv: TinyVec<[u8; 4], Counted> = TinyVec::from_iter(0..8);
+  for _ in 0..100 {
+    tv.pop();
+    tv.push(0);
+  }
+  assert_eq!(STATS.spills(), 1);
+  let peaks = STATS.peak_len_histogram();
+  assert_eq!(peaks.iter().sum::<usize>(), 1);
+  assert_eq!(peaks[3], 1);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn TinyVec_ser_de() {
+  let tokens = [
+    Token::Seq { len: Some(3) },
+    Token::I32(1),
+    Token::I32(2),
+    Token::I32(3),
+    Token::SeqEnd,
+  ];
+  let tv: TinyVec<[i32; 4]> = TinyVec::from_iter(1..=3);
+  assert_tokens(&tv, &tokens);
+  let tv: TinyVec<[i32; 2]> = TinyVec::from_iter(1..=3);
+  assert_tokens(&tv, &tokens);
+  assert_de_tokens(
+    &tv,
+    &[
+      Token::Seq { len: None },
+      Token::I32(1),
+      Token::I32(2),
+      Token::I32(3),
+      Token::SeqEnd,
+    ],
+  );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn TinyVec_ser_de_bytes() {
+  #[derive(Debug, PartialEq)]
+  struct Bytes(TinyVec<[u8; 4]>);
+  impl serde::Serialize for Bytes {
+    fn serialize<S: serde::Serializer>(
+      &self,
+      s: S,
+    ) -> Result<S::Ok, S::Error> {
+      self.0.serialize_bytes(s)
+    }
+  }
+  impl<'de> serde::Deserialize<'de> for Bytes {
+    fn deserialize<D: serde::Deserializer<'de>>(
+      d: D,
+    ) -> Result<Self, D::Error> {
+      TinyVec::deserialize_bytes(d).map(Bytes)
+    }
+  }
+
+  let b = Bytes(TinyVec::from_iter(1..=3));
+  assert_tokens(&b, &[Token::Bytes(&[1, 2, 3])]);
+  let b = Bytes(TinyVec::from_iter(1..=5));
+  assert_tokens(&b, &[Token::Bytes(&[1, 2, 3, 4, 5])]);
+  assert_de_tokens(
+    &b,
+    &[
+      Token::Seq { len: Some(5) },
+      Token::U8(1),
+      Token::U8(2),
+      Token::U8(3),
+      Token::U8(4),
+      Token::U8(5),
+      Token::SeqEnd,
+    ],
+  );
+}
+
+#[cfg(feature = "rustc_1_61")]
+#[test]
+fn TinyVec_const_constructors() {
+  const EMPTY: TinyVec<[u8; 4]> = TinyVec::from_array_empty([0; 4]);
+  const TABLE: TinyVec<[u8; 4]> = TinyVec::from_array_len([7, 8, 0, 0], 2);
+  const MACRO: TinyVec<[u8; 4]> = tiny_vec!(const [u8; 4], 7, 8);
+  assert!(EMPTY.is_empty());
+  assert_eq!(TABLE, MACRO);
+
+  let mut tv = MACRO;
+  tv.extend_from_slice(&[9, 10, 11]);
+  assert_eq!(tv.as_slice(), &[7, 8, 9, 10, 11]);
+}
+
+#[test]
+fn TinyVec_macro_forms() {
+  let tv = tiny_vec!([u8; 4] => 7; 3);
+  assert!(matches!(tv, TinyVec::Inline(_)));
+  assert_eq!(tv.as_slice(), &[7, 7, 7]);
+
+  let tv = tiny_vec!([u8; 4] => 7; 6);
+  assert!(matches!(tv, TinyVec::Heap(_)));
+  assert_eq!(tv.capacity(), 6);
+
+  let tv = tiny_vec!([_; 2], "a", "b", "c",);
+  assert!(matches!(tv, TinyVec::Heap(_)));
+  assert_eq!(tv.capacity(), 3);
+  assert_eq!(tv.as_slice(), &["a", "b", "c"]);
+
+  let tv = tiny_vec!([_; 2], 1_i32, 2);
+  assert!(matches!(tv, TinyVec::Inline(_)));
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn TinyVec_zeroize() {
+  use zeroize::Zeroize;
+  let mut tv: TinyVec<[u8; 4]> = TinyVec::from_iter(1..=3);
+  tv.zeroize();
+  assert!(tv.is_empty());
+  let mut tv: TinyVec<[u8; 4]> = TinyVec::from_iter(1..=10);
+  let cap = tv.capacity();
+  tv.zeroize();
+  assert!(tv.is_empty());
+  assert_eq!(tv.capacity(), cap);
+
+  let mut key: ZeroizingTinyVec<[u8; 4]> = ZeroizingTinyVec::new(tiny_vec!([u8; 4]));
+  key.extend_from_slice(b"secret");
+  assert_eq!(key.as_slice(), b"secret");
+}
+
+#[cfg(feature = "wipe_vacated")]
+#[test]
+fn TinyVec_wipe_vacated() {
+  let mut tv: TinyVec<[u8; 2]> = TinyVec::from_iter(0..10);
+  assert_eq!(tv.pop(), Some(9));
+  assert_eq!(tv.remove(0), 0);
+  assert_eq!(tv.swap_remove(0), 1);
+  tv.dedup();
+  let tail = tv.split_off(5);
+  assert_eq!(tail.as_slice(), &[6, 7]);
+  let removed: Vec<u8> = tv.splice(1..2, 20..24).collect();
+  assert_eq!(removed, vec![2]);
+  assert_eq!(tv.as_slice(), &[8, 20, 21, 22, 23, 3, 4, 5]);
+  tv.remove_range(1..5);
+  tv.truncate(3);
+  assert_eq!(tv.as_slice(), &[8, 3, 4]);
+  tv.shrink_to_fit();
+  assert!(matches!(tv, TinyVec::Heap(_)));
+  tv.truncate(1);
+  tv.shrink_to_fit();
+  assert!(matches!(tv, TinyVec::Inline(_)));
+  assert_eq!(tv.as_slice(), &[8]);
+}
+
+/// Runs `op` on an inline vec and on a heap vec, and checks that every slot it
+/// vacated was reset to zero.
+#[cfg(feature = "wipe_vacated")]
+fn assert_wipes(op: impl Fn(&mut TinyVec<[u8; 8]>)) {
+  let inline = tiny_vec!([u8; 8], 1, 2, 2, 3, 4, 5);
+  let heap: TinyVec<[u8; 8]> = TinyVec::Heap(vec![1, 2, 2, 3, 4, 5]);
+  for tv in &mut [inline, heap] {
+    let old_len = tv.len();
+    op(tv);
+    assert!(tv.len() < old_len);
+    // Safety: every slot below `old_len` was initialized before the op, and
+    // none of the ops reallocate.
+    let vacated: Vec<u8> =
+      (tv.len()..old_len).map(|i| unsafe { *tv.as_ptr().add(i) }).collect();
+    assert!(vacated.iter().all(|&x| x == 0), "{:?}", vacated);
+  }
+}
+
+#[cfg(feature = "wipe_vacated")]
+#[test]
+fn TinyVec_wipe_vacated_pop() {
+  assert_wipes(|tv| assert_eq!(tv.pop(), Some(5)));
+}
+
+#[cfg(feature = "wipe_vacated")]
+#[test]
+fn TinyVec_wipe_vacated_remove() {
+  assert_wipes(|tv| assert_eq!(tv.remove(1), 2));
+  assert_wipes(|tv| tv.remove_range(1..4));
+  assert_wipes(|tv| assert_eq!(tv.try_remove(1), Some(2)));
+}
+
+#[cfg(feature = "wipe_vacated")]
+#[test]
+fn TinyVec_wipe_vacated_swap_remove() {
+  assert_wipes(|tv| assert_eq!(tv.swap_remove(1), 2));
+  assert_wipes(|tv| assert_eq!(tv.try_swap_remove(1), Some(2)));
+}
+
+#[cfg(feature = "wipe_vacated")]
+#[test]
+fn TinyVec_wipe_vacated_split_off() {
+  assert_wipes(|tv| drop(tv.split_off(2)));
+  assert_wipes(|tv| assert!(tv.try_split_off(2).is_some()));
+}
+
+#[cfg(feature = "wipe_vacated")]
+#[test]
+fn TinyVec_wipe_vacated_truncate() {
+  assert_wipes(|tv| tv.truncate(2));
+  assert_wipes(|tv| tv.resize(2, 9));
+  assert_wipes(|tv| tv.resize_with(2, || 9));
+}
+
+#[cfg(feature = "wipe_vacated")]
+#[test]
+fn TinyVec_wipe_vacated_retain() {
+  assert_wipes(|tv| tv.retain(|&x| x % 2 == 1));
+  assert_wipes(|tv| tv.retain_mut(|x| *x % 2 == 1));
+}
+
+#[cfg(feature = "wipe_vacated")]
+#[test]
+fn TinyVec_wipe_vacated_dedup() {
+  assert_wipes(|tv| tv.dedup());
+}
+
+#[cfg(feature = "wipe_vacated")]
+#[test]
+fn TinyVec_wipe_vacated_drain() {
+  assert_wipes(|tv| drop(tv.drain(1..3)));
+  assert_wipes(|tv| drop(tv.try_drain(1..3)));
+  assert_wipes(|tv| tv.extract_if(.., |x| *x == 2).for_each(drop));
+  assert_wipes(|tv| tv.splice(1..4, vec![7]).for_each(drop));
+}